@@ -0,0 +1,57 @@
+// In this example we're reading newline-delimited messages from a
+// non-blocking TCP socket the way an event loop built on mio or
+// a similar readiness API would: whenever the socket is readable we
+// perform exactly one read, then extract every complete message that
+// is buffered before going back to waiting.
+
+use std::io::{self, ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+use read_buffer::DynReadBuffer;
+
+fn main() -> Result<(), io::Error> {
+	let listener = TcpListener::bind("127.0.0.1:0")?;
+	let address = listener.local_addr()?;
+	
+	// Send some messages in small pieces from another thread
+	let sender = thread::spawn(move || -> Result<(), io::Error> {
+		let mut stream = TcpStream::connect(address)?;
+		
+		for piece in ["Hello\nWo", "rld\n", "How are", " you?\nBye\n"] {
+			stream.write_all(piece.as_bytes())?;
+			thread::sleep(Duration::from_millis(20));
+		}
+		
+		Ok(())
+	});
+	
+	let (stream, _) = listener.accept()?;
+	stream.set_nonblocking(true)?;
+	
+	let mut buffer = DynReadBuffer::new(stream);
+	
+	loop {
+		// With mio this would be a call to Poll::poll waiting
+		// for the socket to become readable
+		match buffer.fill_once() {
+			Ok(0) => break,
+			Ok(_) => (),
+			Err(err) if err.kind() == ErrorKind::WouldBlock => {
+				thread::sleep(Duration::from_millis(5));
+				continue;
+			},
+			Err(err) => return Err(err),
+		}
+		
+		// Extract all complete messages without touching the socket again
+		while let Some(message) = buffer.next_until(b'\n') {
+			println!("Received message: {:?}", String::from_utf8_lossy(message));
+		}
+	}
+	
+	sender.join().expect("sender thread shouldn't panic")?;
+	
+	println!("Connection closed");
+	Ok(())
+}
\ No newline at end of file
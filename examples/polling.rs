@@ -0,0 +1,84 @@
+// In this example we're reading newline-delimited messages from a
+// non-blocking TCP socket using the polling crate: the socket is
+// registered with the poller, and whenever it's reported to be readable
+// we perform exactly one read using fill_once, then extract every
+// complete message that is buffered before waiting again.
+
+#[cfg(any(unix, windows))]
+fn main() -> Result<(), std::io::Error> {
+	use std::io::{self, ErrorKind, Write};
+	use std::net::{TcpListener, TcpStream};
+	use std::thread;
+	use std::time::Duration;
+	use polling::{Event, Events, Poller};
+	use read_buffer::DynReadBuffer;
+	
+	const KEY: usize = 7;
+	
+	let listener = TcpListener::bind("127.0.0.1:0")?;
+	let address = listener.local_addr()?;
+	
+	// Send some messages in small pieces from another thread
+	let sender = thread::spawn(move || -> Result<(), io::Error> {
+		let mut stream = TcpStream::connect(address)?;
+		
+		for piece in ["Hello\nWo", "rld\n", "How are", " you?\nBye\n"] {
+			stream.write_all(piece.as_bytes())?;
+			thread::sleep(Duration::from_millis(20));
+		}
+		
+		Ok(())
+	});
+	
+	let (stream, _) = listener.accept()?;
+	stream.set_nonblocking(true)?;
+	
+	let mut buffer = DynReadBuffer::new(stream);
+	let poller = Poller::new()?;
+	
+	// SAFETY: the socket is removed from the poller before it's dropped
+	unsafe {
+		poller.add(buffer.get_ref(), Event::readable(KEY))?;
+	}
+	
+	let mut events = Events::new();
+	
+	'event_loop: loop {
+		events.clear();
+		poller.wait(&mut events, None)?;
+		
+		for event in events.iter() {
+			if event.key != KEY {
+				continue;
+			}
+			
+			// Perform exactly one read, as the socket is known to be readable
+			match buffer.fill_once() {
+				Ok(0) => break 'event_loop,
+				Ok(_) => (),
+				// The readiness event may have been spurious
+				Err(err) if err.kind() == ErrorKind::WouldBlock => (),
+				Err(err) => return Err(err),
+			}
+			
+			// Extract all complete messages without touching the socket again
+			while let Some(message) = buffer.next_until(b'\n') {
+				println!("Received message: {:?}", String::from_utf8_lossy(message));
+			}
+			
+			// Events are oneshot, so interest has to be registered again
+			poller.modify(buffer.get_ref(), Event::readable(KEY))?;
+		}
+	}
+	
+	poller.delete(buffer.get_ref())?;
+	sender.join().expect("sender thread shouldn't panic")?;
+	
+	println!("Connection closed");
+	Ok(())
+}
+
+#[cfg(not(any(unix, windows)))]
+fn main() {
+	println!("The polling crate is only available on Unix and Windows");
+}
\ No newline at end of file
@@ -22,7 +22,7 @@ fn main() -> Result<(), io::Error> {
 		let read_data = buffer.read_from(&mut file)?;
 		
 		// A length of 0 indicates "end of file"
-		if read_data.len() == 0 {
+		if read_data.is_empty() {
 			println!("Reached end of file");
 			return Ok(());
 		}
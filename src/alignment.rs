@@ -0,0 +1,74 @@
+use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
+
+mod private {
+	pub trait Sealed {}
+}
+
+/// An alignment of the internal buffer of a [ReadBuffer],
+/// for sources which require reads into aligned memory,
+/// like block devices or DMA transfers.
+/// 
+/// Implemented by the zero-sized types [Align1] through [Align4096].
+/// 
+/// This trait is sealed and can't be implemented outside of this crate.
+/// 
+/// [ReadBuffer]: crate::ReadBuffer
+pub trait Alignment: Debug + private::Sealed {}
+
+macro_rules! alignment {
+	($name:ident, $alignment:literal) => {
+		#[doc = concat!("Aligns the internal buffer to ", stringify!($alignment), " bytes.")]
+		#[repr(align($alignment))]
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+		pub struct $name;
+		
+		impl private::Sealed for $name {}
+		impl Alignment for $name {}
+	}
+}
+
+alignment!(Align1, 1);
+alignment!(Align2, 2);
+alignment!(Align4, 4);
+alignment!(Align8, 8);
+alignment!(Align16, 16);
+alignment!(Align32, 32);
+alignment!(Align64, 64);
+alignment!(Align128, 128);
+alignment!(Align256, 256);
+alignment!(Align512, 512);
+alignment!(Align1024, 1024);
+alignment!(Align2048, 2048);
+alignment!(Align4096, 4096);
+
+/// An array of bytes aligned according to `A`.
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct AlignedBytes<const SIZE: usize, A: Alignment> {
+	_alignment: [A; 0],
+	bytes: [u8; SIZE],
+}
+
+impl<const SIZE: usize, A: Alignment> AlignedBytes<SIZE, A> {
+	pub const fn new() -> Self {
+		Self {
+			_alignment: [],
+			bytes: [0; SIZE],
+		}
+	}
+}
+
+impl<const SIZE: usize, A: Alignment> Deref for AlignedBytes<SIZE, A> {
+	type Target = [u8; SIZE];
+	
+	fn deref(&self) -> &Self::Target {
+		&self.bytes
+	}
+}
+
+impl<const SIZE: usize, A: Alignment> DerefMut for AlignedBytes<SIZE, A> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.bytes
+	}
+}
\ No newline at end of file
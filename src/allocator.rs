@@ -0,0 +1,60 @@
+use std::alloc::Layout;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+
+/// The allocator of the internal buffer of a [`DynReadBuffer`],
+/// either the global allocator or one passed to [`DynReadBuffer::new_in`].
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// 
+/// Clones share the same allocator, so storage allocated by one
+/// can be deallocated by another.
+/// 
+/// [`DynReadBuffer::new_in`]: crate::DynReadBuffer::new_in
+#[derive(Clone)]
+pub(crate) struct BufferAllocator(Option<Arc<dyn Allocator + Send + Sync>>);
+
+impl BufferAllocator {
+	pub fn global() -> Self {
+		Self(None)
+	}
+	
+	pub fn new(allocator: impl Allocator + Send + Sync + 'static) -> Self {
+		Self(Some(Arc::new(allocator)))
+	}
+	
+	fn get(&self) -> &dyn Allocator {
+		match &self.0 {
+			Some(allocator) => allocator.as_ref(),
+			None => &Global,
+		}
+	}
+}
+
+// SAFETY: all calls are forwarded to the same allocator for the whole lifetime of the value
+unsafe impl Allocator for BufferAllocator {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.get().allocate(layout)
+	}
+	
+	fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.get().allocate_zeroed(layout)
+	}
+	
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		self.get().deallocate(ptr, layout)
+	}
+	
+	unsafe fn grow(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.get().grow(ptr, old_layout, new_layout)
+	}
+	
+	unsafe fn grow_zeroed(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.get().grow_zeroed(ptr, old_layout, new_layout)
+	}
+	
+	unsafe fn shrink(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.get().shrink(ptr, old_layout, new_layout)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,168 @@
+use std::io::{self, ErrorKind};
+use std::str::{self, FromStr};
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads an unsigned integer in ASCII decimal notation, with an optional
+	/// leading `+`, stopping at the first byte that isn't a digit.
+	/// 
+	/// Only the bytes making up the number are consumed.
+	/// 
+	/// # Errors
+	/// 
+	/// If no digits are found or the number doesn't fit into a [u64],
+	/// an error of the kind [ErrorKind::InvalidData][`InvalidData`]
+	/// is returned and nothing is consumed.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// In any case, the data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "1920x1080".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_ascii_u64()?, 1920);
+	/// assert_eq!(buffer.read_bytes(1)?, b"x");
+	/// assert_eq!(buffer.read_ascii_u64()?, 1080);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_ascii_u64(&mut self) -> Result<u64, io::Error> {
+		self.read_ascii_number(|data, eof| scan_integer(data, eof, false))
+	}
+	
+	/// Like [`read_ascii_u64`], but also accepts a leading `-`
+	/// and returns an [i64].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_ascii_u64`].
+	/// 
+	/// [`read_ascii_u64`]: DynReadBuffer::read_ascii_u64
+	pub fn read_ascii_i64(&mut self) -> Result<i64, io::Error> {
+		self.read_ascii_number(|data, eof| scan_integer(data, eof, true))
+	}
+	
+	/// Reads a floating point number in ASCII decimal notation,
+	/// made up of an optional sign, digits with an optional decimal point
+	/// and an optional exponent like `e-3`, stopping at the first byte
+	/// that can't continue the number.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_ascii_u64`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "-1.5e3,0.25".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_ascii_f64()?, -1500.0);
+	/// assert_eq!(buffer.read_bytes(1)?, b",");
+	/// assert_eq!(buffer.read_ascii_f64()?, 0.25);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_ascii_u64`]: DynReadBuffer::read_ascii_u64
+	pub fn read_ascii_f64(&mut self) -> Result<f64, io::Error> {
+		self.read_ascii_number(scan_float)
+	}
+	
+	/// Like [`read_ascii_f64`], but returns an [f32].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_ascii_u64`].
+	/// 
+	/// [`read_ascii_f64`]: DynReadBuffer::read_ascii_f64
+	/// [`read_ascii_u64`]: DynReadBuffer::read_ascii_u64
+	pub fn read_ascii_f32(&mut self) -> Result<f32, io::Error> {
+		self.read_ascii_number(scan_float)
+	}
+	
+	/// Reads until `scan` finds the length of the number at the start of the buffered data,
+	/// then parses and consumes it.
+	/// 
+	/// `scan` is given the buffered data and whether the "end of file" was reached,
+	/// and returns [None] if more data could still be part of the number.
+	fn read_ascii_number<T: FromStr>(&mut self, scan: impl Fn(&[u8], bool) -> Option<usize>) -> Result<T, io::Error> {
+		let length = loop {
+			if let Some(length) = scan(self.buffer.filled(), false) {
+				break length;
+			}
+			
+			if self.fill_buffer()? == 0 {
+				break scan(self.buffer.filled(), true)
+					.expect("number should end at the end of file");
+			}
+		};
+		
+		// the scanned bytes are all ASCII
+		let number = str::from_utf8(&self.buffer.filled()[..length])
+			.ok()
+			.and_then(|number| number.parse().ok())
+			.ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "invalid number"))?;
+		
+		self.consume(length);
+		Ok(number)
+	}
+}
+
+fn scan_integer(data: &[u8], eof: bool, signed: bool) -> Option<usize> {
+	let end = skip_digits(data, sign_length(data, signed));
+	(end < data.len() || eof).then_some(end)
+}
+
+fn scan_float(data: &[u8], eof: bool) -> Option<usize> {
+	let mut end = skip_digits(data, sign_length(data, true));
+	
+	if data.get(end) == Some(&b'.') {
+		end = skip_digits(data, end + 1);
+	}
+	
+	if matches!(data.get(end), Some(b'e' | b'E')) {
+		let exponent_start = end + 1 + sign_length(&data[end + 1..], true);
+		let exponent_end = skip_digits(data, exponent_start);
+		
+		if exponent_end == data.len() && !eof {
+			return None;
+		}
+		
+		if exponent_end > exponent_start {
+			end = exponent_end;
+		}
+	}
+	
+	(end < data.len() || eof).then_some(end)
+}
+
+fn sign_length(data: &[u8], signed: bool) -> usize {
+	match data.first() {
+		Some(b'+') => 1,
+		Some(b'-') if signed => 1,
+		_ => 0,
+	}
+}
+
+/// Returns the index of the first byte at or after `start` which isn't a digit.
+fn skip_digits(data: &[u8], start: usize) -> usize {
+	let start = start.min(data.len());
+	start + data[start..].iter().take_while(|byte| byte.is_ascii_digit()).count()
+}
\ No newline at end of file
@@ -0,0 +1,450 @@
+use std::future::poll_fn;
+use std::io::{self, ErrorKind};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll, Waker, ready};
+use std::thread::{self, Thread};
+use std::time::Instant;
+use futures_io::AsyncRead;
+use crate::buffer::Buffer;
+
+/// A dynamically sized buffer to read into from an [AsyncRead]
+/// and safely access the read data, the asynchronous counterpart
+/// of [`DynReadBuffer`].
+/// 
+/// It works with any [AsyncRead] from the `futures` ecosystem,
+/// which includes the readers of `smol` and `async-std`.
+/// The same internal buffer as in [`DynReadBuffer`] is used,
+/// so the behavior of the methods matches theirs.
+/// 
+/// All methods are cancel-safe: if a returned future is dropped
+/// before it completes, the data read so far is preserved
+/// in the internal buffer for future reads.
+/// 
+/// Requires the `futures-io` feature.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+pub struct AsyncDynReadBuffer<R: AsyncRead + Unpin> {
+	pub(crate) buffer: Buffer,
+	pub(crate) reader: R,
+	/// The position and length of the frame completed by the last poll method,
+	/// which is no longer valid once the position changed.
+	completed_frame: Option<(u64, usize)>,
+	/// The position, delimiter and amount of bytes already searched
+	/// by [`AsyncDynReadBuffer::poll_read_until`].
+	search: Option<(u64, u8, usize)>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDynReadBuffer<R> {
+	/// Creates a new **AsyncDynReadBuffer** to read from the given [AsyncRead].
+	pub fn new(reader: R) -> Self {
+		Self {
+			buffer: Buffer::new(),
+			reader,
+			completed_frame: None,
+			search: None,
+		}
+	}
+	
+	/// Creates a new **AsyncDynReadBuffer** to read from the given [AsyncRead]
+	/// with an internal buffer of at least the specified capacity.
+	pub fn with_capacity(reader: R, capacity: usize) -> Self {
+		Self {
+			buffer: Buffer::with_capacity(capacity),
+			reader,
+			completed_frame: None,
+			search: None,
+		}
+	}
+	
+	/// Reads the specified amount of bytes from the given [AsyncRead]
+	/// and returns a slice referencing them.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`DynReadBuffer::read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # async fn example() -> Result<(), std::io::Error> {
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// let reader = [1, 2, 3, 4, 5].as_slice();
+	/// let mut buffer = AsyncDynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_bytes(2).await?, [1, 2]);
+	/// assert_eq!(buffer.read_bytes(3).await?, [3, 4, 5]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`DynReadBuffer::read_bytes`]: crate::DynReadBuffer::read_bytes
+	pub async fn read_bytes(&mut self, amount: usize) -> Result<&[u8], io::Error> {
+		if amount > self.buffer.len() {
+			self.buffer.reserve(amount - self.buffer.len());
+		}
+		
+		while amount > self.buffer.len() {
+			if self.read_into_buffer().await? == 0 {
+				return Err(ErrorKind::UnexpectedEof.into());
+			}
+		}
+		
+		Ok(self.buffer.take(amount))
+	}
+	
+	/// Reads from the given [AsyncRead] until the delimiter is found
+	/// and returns a slice referencing the data up to and including the delimiter.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`DynReadBuffer::read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # async fn example() -> Result<(), std::io::Error> {
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// let reader = "Hello\nWorld\n".as_bytes();
+	/// let mut buffer = AsyncDynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n').await?, b"Hello\n");
+	/// assert_eq!(buffer.read_until(b'\n').await?, b"World\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`DynReadBuffer::read_until`]: crate::DynReadBuffer::read_until
+	pub async fn read_until(&mut self, delimiter: u8) -> Result<&[u8], io::Error> {
+		let mut searched_length = 0;
+		
+		loop {
+			if let Some(position) = self.buffer.find(delimiter, searched_length) {
+				return Ok(self.buffer.take(position + 1));
+			}
+			
+			searched_length = self.buffer.len();
+			self.buffer.reserve(self.buffer.read_reservation());
+			
+			if self.read_into_buffer().await? == 0 {
+				return Err(ErrorKind::UnexpectedEof.into());
+			}
+		}
+	}
+	
+	/// Like [`read_until`], but gives up with an error of the kind
+	/// [ErrorKind::TimedOut][`TimedOut`] if the delimiter couldn't be read
+	/// before the specified deadline.
+	/// 
+	/// Unlike wrapping [`read_until`] in a timeout for every call,
+	/// the deadline bounds the total time across all reads needed for the frame.
+	/// As this doesn't depend on a specific runtime, waiting for the deadline
+	/// uses a background thread, which is only started if a read has to wait.
+	/// On targets which don't support threads, like `wasm32-unknown-unknown`,
+	/// this panics if a read has to wait.
+	/// 
+	/// # Errors
+	/// 
+	/// If the deadline passes, an error of the kind [ErrorKind::TimedOut][`TimedOut`]
+	/// is returned. The data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// All other errors are handled the same way as in [`read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # async fn example() -> Result<(), std::io::Error> {
+	/// use std::time::{Duration, Instant};
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// let reader = "Hello\n".as_bytes();
+	/// let mut buffer = AsyncDynReadBuffer::new(reader);
+	/// let deadline = Instant::now() + Duration::from_secs(5);
+	/// 
+	/// assert_eq!(buffer.read_until_deadline(b'\n', deadline).await?, b"Hello\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: AsyncDynReadBuffer::read_until
+	/// [`TimedOut`]: std::io::ErrorKind::TimedOut
+	pub async fn read_until_deadline(&mut self, delimiter: u8, deadline: Instant) -> Result<&[u8], io::Error> {
+		let mut searched_length = 0;
+		let mut timer = None;
+		
+		loop {
+			if let Some(position) = self.buffer.find(delimiter, searched_length) {
+				return Ok(self.buffer.take(position + 1));
+			}
+			
+			searched_length = self.buffer.len();
+			self.buffer.reserve(self.buffer.read_reservation());
+			
+			if self.read_into_buffer_before(Some((deadline, &mut timer))).await? == 0 {
+				return Err(ErrorKind::UnexpectedEof.into());
+			}
+		}
+	}
+	
+	/// Polls reading the specified amount of bytes from the given [AsyncRead],
+	/// for use in manual implementations of [Future] or [AsyncRead].
+	/// 
+	/// Once this returns `Poll::Ready(Ok(()))`, the read bytes
+	/// can be retrieved using [`take_frame`].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use std::future::Future;
+	/// use std::io;
+	/// use std::pin::Pin;
+	/// use std::task::{Context, Poll, ready};
+	/// use futures_io::AsyncRead;
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// /// Reads a header of four bytes and returns it as a number.
+	/// struct ReadHeader<'a, R: AsyncRead + Unpin> {
+	///     buffer: &'a mut AsyncDynReadBuffer<R>,
+	/// }
+	/// 
+	/// impl<R: AsyncRead + Unpin> Future for ReadHeader<'_, R> {
+	///     type Output = io::Result<u32>;
+	/// 
+	///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+	///         ready!(self.buffer.poll_read_bytes(cx, 4))?;
+	///         let header = self.buffer.take_frame().unwrap();
+	///         Poll::Ready(Ok(u32::from_be_bytes(header.try_into().unwrap())))
+	///     }
+	/// }
+	/// ```
+	/// 
+	/// [Future]: std::future::Future
+	/// [`take_frame`]: AsyncDynReadBuffer::take_frame
+	/// [`read_bytes`]: AsyncDynReadBuffer::read_bytes
+	pub fn poll_read_bytes(&mut self, cx: &mut Context<'_>, amount: usize) -> Poll<Result<(), io::Error>> {
+		if amount > self.buffer.len() {
+			self.buffer.reserve(amount - self.buffer.len());
+		}
+		
+		while amount > self.buffer.len() {
+			if ready!(self.poll_read_into_buffer(cx))? == 0 {
+				return Poll::Ready(Err(ErrorKind::UnexpectedEof.into()));
+			}
+		}
+		
+		self.completed_frame = Some((self.buffer.position(), amount));
+		Poll::Ready(Ok(()))
+	}
+	
+	/// Polls reading from the given [AsyncRead] until the delimiter is found,
+	/// for use in manual implementations of [Future] or [AsyncRead].
+	/// 
+	/// Once this returns `Poll::Ready(Ok(()))`, the data up to and including
+	/// the delimiter can be retrieved using [`take_frame`].
+	/// The data which was already searched is remembered between calls,
+	/// so polling repeatedly doesn't search it again.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_until`].
+	/// 
+	/// [Future]: std::future::Future
+	/// [`take_frame`]: AsyncDynReadBuffer::take_frame
+	/// [`read_until`]: AsyncDynReadBuffer::read_until
+	pub fn poll_read_until(&mut self, cx: &mut Context<'_>, delimiter: u8) -> Poll<Result<(), io::Error>> {
+		let position = self.buffer.position();
+		
+		let mut searched_length = match self.search {
+			Some((search_position, search_delimiter, searched_length))
+				if search_position == position && search_delimiter == delimiter => searched_length,
+			_ => 0,
+		};
+		
+		loop {
+			if let Some(found) = self.buffer.find(delimiter, searched_length) {
+				self.search = None;
+				self.completed_frame = Some((position, found + 1));
+				return Poll::Ready(Ok(()));
+			}
+			
+			searched_length = self.buffer.len();
+			self.search = Some((position, delimiter, searched_length));
+			self.buffer.reserve(self.buffer.read_reservation());
+			
+			if ready!(self.poll_read_into_buffer(cx))? == 0 {
+				return Poll::Ready(Err(ErrorKind::UnexpectedEof.into()));
+			}
+		}
+	}
+	
+	/// Returns a slice referencing the frame completed by the last call to
+	/// [`poll_read_bytes`] or [`poll_read_until`] which returned `Poll::Ready(Ok(()))`,
+	/// so the following reads start after it.
+	/// 
+	/// Returns [None] if no frame was completed since the last one was taken,
+	/// or if other data was read in the meantime.
+	/// 
+	/// [`poll_read_bytes`]: AsyncDynReadBuffer::poll_read_bytes
+	/// [`poll_read_until`]: AsyncDynReadBuffer::poll_read_until
+	pub fn take_frame(&mut self) -> Option<&[u8]> {
+		let (position, length) = self.completed_frame.take()?;
+		
+		if position != self.buffer.position() || length > self.buffer.len() {
+			return None;
+		}
+		
+		Some(self.buffer.take(length))
+	}
+	
+	/// Returns how many bytes have been read into the internal buffer
+	/// but not yet returned, for example the bytes gathered by a call to
+	/// [`read_bytes`] which was cancelled or timed out before it completed.
+	/// 
+	/// This makes it possible to decide whether to wait for the rest of the data
+	/// or to give up, for example by dropping the connection.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # async fn example(stream: impl futures_io::AsyncRead + Unpin) {
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// let mut buffer = AsyncDynReadBuffer::new(stream);
+	/// 
+	/// // cancelled, for example by a timeout
+	/// drop(buffer.read_bytes(1024));
+	/// 
+	/// if buffer.pending_exact() > 0 {
+	///     // part of the message has arrived, keep waiting for the rest
+	/// }
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: AsyncDynReadBuffer::read_bytes
+	pub fn pending_exact(&self) -> usize {
+		self.buffer.len()
+	}
+	
+	/// Performs a single read into the space after the buffered data,
+	/// retrying if it is interrupted.
+	async fn read_into_buffer(&mut self) -> Result<usize, io::Error> {
+		self.read_into_buffer_before(None).await
+	}
+	
+	/// Like [`AsyncDynReadBuffer::read_into_buffer`], but fails with
+	/// [ErrorKind::TimedOut] once the deadline has passed,
+	/// starting the timer to be woken at the deadline if the read has to wait.
+	async fn read_into_buffer_before(
+		&mut self,
+		mut deadline: Option<(Instant, &mut Option<DeadlineTimer>)>,
+	) -> Result<usize, io::Error> {
+		poll_fn(|cx| {
+			if let Some((deadline, _)) = &deadline {
+				if Instant::now() >= *deadline {
+					return Poll::Ready(Err(ErrorKind::TimedOut.into()));
+				}
+			}
+			
+			let poll = self.poll_read_into_buffer(cx);
+			
+			if poll.is_pending() {
+				if let Some((deadline, timer)) = &mut deadline {
+					match timer {
+						Some(timer) => timer.set_waker(cx.waker()),
+						None => **timer = Some(DeadlineTimer::start(*deadline, cx.waker())),
+					}
+				}
+			}
+			
+			poll
+		}).await
+	}
+	
+	/// Polls a single read into the space after the buffered data,
+	/// retrying if it is interrupted.
+	fn poll_read_into_buffer(&mut self, cx: &mut Context<'_>) -> Poll<Result<usize, io::Error>> {
+		self.poll_read_into_buffer_at_most(cx, usize::MAX)
+	}
+	
+	/// Like [`AsyncDynReadBuffer::poll_read_into_buffer`], but reads
+	/// no more than the specified amount of bytes.
+	pub(crate) fn poll_read_into_buffer_at_most(&mut self, cx: &mut Context<'_>, max_amount: usize) -> Poll<Result<usize, io::Error>> {
+		loop {
+			let spare_buffer = self.buffer.spare_mut();
+			let max_amount = max_amount.min(spare_buffer.len());
+			let spare_buffer = &mut spare_buffer[..max_amount];
+			
+			match ready!(Pin::new(&mut self.reader).poll_read(cx, spare_buffer)) {
+				Ok(amount_read) => {
+					self.buffer.commit(amount_read);
+					return Poll::Ready(Ok(amount_read));
+				},
+				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+				Err(err) => return Poll::Ready(Err(err)),
+			}
+		}
+	}
+}
+
+/// Wakes a task once a deadline has passed, using a background thread
+/// which exits early once the **DeadlineTimer** is dropped.
+struct DeadlineTimer {
+	shared: Arc<TimerShared>,
+	thread: Thread,
+}
+
+struct TimerShared {
+	waker: Mutex<Waker>,
+	stopped: AtomicBool,
+}
+
+impl DeadlineTimer {
+	fn start(deadline: Instant, waker: &Waker) -> Self {
+		let shared = Arc::new(TimerShared {
+			waker: Mutex::new(waker.clone()),
+			stopped: AtomicBool::new(false),
+		});
+		
+		let thread_shared = Arc::clone(&shared);
+		let thread = thread::spawn(move || {
+			while !thread_shared.stopped.load(Ordering::Acquire) {
+				let remaining = deadline.saturating_duration_since(Instant::now());
+				
+				if remaining.is_zero() {
+					thread_shared.waker.lock().unwrap_or_else(PoisonError::into_inner).wake_by_ref();
+					return;
+				}
+				
+				thread::park_timeout(remaining);
+			}
+		}).thread().clone();
+		
+		Self {
+			shared,
+			thread,
+		}
+	}
+	
+	fn set_waker(&self, waker: &Waker) {
+		let mut current_waker = self.shared.waker.lock().unwrap_or_else(PoisonError::into_inner);
+		
+		if !current_waker.will_wake(waker) {
+			current_waker.clone_from(waker);
+		}
+	}
+}
+
+impl Drop for DeadlineTimer {
+	fn drop(&mut self) {
+		self.shared.stopped.store(true, Ordering::Release);
+		self.thread.unpark();
+	}
+}
\ No newline at end of file
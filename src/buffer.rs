@@ -0,0 +1,840 @@
+use std::io::{self, Write};
+use std::mem;
+use crate::growth::{GrowthPolicy, ShrinkPolicy};
+#[cfg(feature = "nightly")]
+use std::io::BorrowedBuf;
+#[cfg(feature = "nightly")]
+use std::mem::MaybeUninit;
+#[cfg(feature = "nightly")]
+use std::slice;
+#[cfg(feature = "allocator-api2")]
+use crate::allocator::BufferAllocator;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+#[cfg(all(feature = "secret", any(unix, windows)))]
+use allocator_api2::collections::TryReserveError;
+use crate::ByteSource;
+
+#[cfg(not(feature = "allocator-api2"))]
+pub(crate) type Storage = Vec<u8>;
+#[cfg(feature = "allocator-api2")]
+pub(crate) type Storage = allocator_api2::vec::Vec<u8, BufferAllocator>;
+
+/// The storage of the dynamically sized buffer types, keeping track of
+/// which part of it holds data that was read but not yet consumed.
+/// 
+/// No data outside of the filled part is ever handed out,
+/// except as space to be filled through [`Buffer::spare_mut`].
+/// 
+/// With the `nightly` feature, only the bytes up to the length of the vector
+/// are initialized and its remaining capacity is filled using [ByteSource::read_into_cursor],
+/// so no memory needs to be zeroed before reading into it.
+/// 
+/// With an alignment other than 1, every read goes into space starting
+/// at an aligned address and is a multiple of the alignment long,
+/// moving the buffered data if necessary. All of the space is then initialized.
+/// 
+/// With the `allocator-api2` feature, the storage can be allocated
+/// using a custom allocator.
+/// 
+/// Up to `INLINE` bytes are stored inline, without allocating.
+/// Only once more space is needed, the data is moved to the vector,
+/// which is then used from there on.
+/// 
+/// With the `zeroize` feature, all of the storage is wiped on drop and by
+/// [`Buffer::clear`], and the vector is never reallocated in place,
+/// so no copies of the data are left behind in freed memory.
+pub(crate) struct Buffer<const INLINE: usize = 0> {
+	buffer: Storage,
+	inline: [u8; INLINE],
+	filled_buffer_start: usize,
+	filled_buffer_length: usize,
+	taken_frames: u64,
+	alignment: usize,
+	position: u64,
+	mark: Option<u64>,
+	hold: Option<u64>,
+	lines: Option<u64>,
+	capture: Option<Box<dyn Write + Send + Sync>>,
+	capture_error: Option<io::Error>,
+	reclaim: Option<Reclaim>,
+	growth_policy: GrowthPolicy,
+	shrink_policy: Option<ShrinkPolicy>,
+	idle_frames: u32,
+	read_chunk_size: usize,
+}
+
+/// The amount of space reserved for each read when the amount
+/// of data needed isn't known in advance, like when searching for a delimiter.
+const DEFAULT_READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// The least amount of free space for which a read is performed
+/// without growing the storage first.
+const MIN_READ_SIZE: usize = 32;
+
+/// Takes back the storage of a dropped [Buffer], for example to reuse it.
+pub(crate) type Reclaim = Box<dyn FnOnce(Storage) + Send + Sync>;
+
+impl<const INLINE: usize> Buffer<INLINE> {
+	pub fn new() -> Self {
+		Self::from_storage(empty_storage(), 1)
+	}
+	
+	pub fn with_capacity(capacity: usize) -> Self {
+		let mut buffer = Self::new();
+		buffer.allocate(capacity);
+		buffer
+	}
+	
+	/// Creates a buffer which only reads into space starting at an address
+	/// which is a multiple of the alignment, in multiples of the alignment.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the alignment is not a power of two.
+	pub fn with_alignment(capacity: usize, alignment: usize) -> Self {
+		assert!(alignment.is_power_of_two(), "alignment must be a power of two");
+		
+		let mut buffer = Self::from_storage(empty_storage(), alignment);
+		buffer.reserve_aligned(capacity);
+		buffer
+	}
+	
+	/// Creates a buffer of at least the specified capacity
+	/// whose storage is allocated using the given allocator.
+	#[cfg(feature = "allocator-api2")]
+	pub fn with_capacity_in(capacity: usize, allocator: BufferAllocator) -> Self {
+		let mut buffer = Self::from_storage(Storage::new_in(allocator), 1);
+		buffer.allocate(capacity);
+		buffer
+	}
+	
+	/// Creates a buffer holding the given data,
+	/// using the vector as the storage if possible.
+	#[cfg(not(feature = "allocator-api2"))]
+	pub fn from_vec(data: Vec<u8>) -> Self {
+		let length = data.len();
+		let mut buffer = Self::from_storage(data, 1);
+		buffer.filled_buffer_length = length;
+		buffer
+	}
+	
+	/// Creates a buffer holding the given data,
+	/// using the vector as the storage if possible.
+	#[cfg(feature = "allocator-api2")]
+	pub fn from_vec(#[allow(unused_mut)] mut data: Vec<u8>) -> Self {
+		let mut buffer = Self::new();
+		buffer.extend_from_slice(&data);
+		
+		#[cfg(feature = "zeroize")]
+		data.zeroize();
+		
+		buffer
+	}
+	
+	/// Creates a buffer of at least the specified capacity using the given storage,
+	/// or new storage if none is given, which is passed to the given [Reclaim]
+	/// once the buffer is dropped.
+	pub fn with_storage(storage: Option<Storage>, capacity: usize, reclaim: Reclaim) -> Self {
+		let mut buffer = Self::from_storage(storage.unwrap_or_else(empty_storage), 1);
+		buffer.allocate(capacity);
+		buffer.reclaim = Some(reclaim);
+		buffer
+	}
+	
+	/// Like [`Buffer::with_capacity_in`], but returns an error
+	/// instead of aborting if the storage can't be allocated.
+	#[cfg(all(feature = "secret", any(unix, windows)))]
+	pub fn try_with_capacity_in(capacity: usize, allocator: BufferAllocator) -> Result<Self, TryReserveError> {
+		let mut storage = Storage::new_in(allocator);
+		storage.try_reserve_exact(capacity)?;
+		
+		let mut buffer = Self::from_storage(storage, 1);
+		buffer.allocate(capacity);
+		Ok(buffer)
+	}
+	
+	fn from_storage(buffer: Storage, alignment: usize) -> Self {
+		Self {
+			buffer,
+			inline: [0; INLINE],
+			filled_buffer_start: 0,
+			filled_buffer_length: 0,
+			taken_frames: 0,
+			alignment,
+			position: 0,
+			mark: None,
+			hold: None,
+			lines: None,
+			capture: None,
+			capture_error: None,
+			reclaim: None,
+			growth_policy: GrowthPolicy::Amortized,
+			shrink_policy: None,
+			idle_frames: 0,
+			read_chunk_size: DEFAULT_READ_CHUNK_SIZE,
+		}
+	}
+	
+	/// Allocates space for the specified amount of bytes in the empty storage,
+	/// without initializing it until it's needed.
+	fn allocate(&mut self, capacity: usize) {
+		if capacity > INLINE {
+			self.buffer.reserve_exact(capacity);
+		}
+	}
+	
+	/// The amount of buffered bytes.
+	pub fn len(&self) -> usize {
+		self.filled_buffer_length
+	}
+	
+	/// The amount of bytes the buffer can hold without growing.
+	pub fn capacity(&self) -> usize {
+		match self.is_inline() {
+			true => INLINE,
+			false => self.buffer.capacity(),
+		}
+	}
+	
+	pub fn is_empty(&self) -> bool {
+		self.filled_buffer_length == 0
+	}
+	
+	/// The amount of space reserved for each read when the amount
+	/// of data needed isn't known in advance.
+	pub fn read_chunk_size(&self) -> usize {
+		self.read_chunk_size
+	}
+	
+	pub fn set_read_chunk_size(&mut self, read_chunk_size: usize) {
+		self.read_chunk_size = read_chunk_size;
+	}
+	
+	/// The amount of space to reserve for a read when the amount
+	/// of data needed isn't known in advance.
+	/// 
+	/// This uses the free space up to the read chunk size
+	/// and only grows the storage by the read chunk size
+	/// once there is barely any free space left.
+	pub fn read_reservation(&self) -> usize {
+		let free_space = self.capacity() - self.len();
+		
+		match free_space >= MIN_READ_SIZE {
+			true => free_space.min(self.read_chunk_size),
+			false => self.read_chunk_size,
+		}
+	}
+	
+	/// How the storage grows once it's too small.
+	pub fn growth_policy(&self) -> GrowthPolicy {
+		self.growth_policy
+	}
+	
+	pub fn set_growth_policy(&mut self, growth_policy: GrowthPolicy) {
+		self.growth_policy = growth_policy;
+	}
+	
+	pub fn filled(&self) -> &[u8] {
+		&self.storage()[self.filled_buffer_start..self.filled_buffer_end()]
+	}
+	
+	/// Finds the first occurrence of the delimiter in the buffered data,
+	/// skipping the first `skip` bytes which are known not to contain it.
+	pub fn find(&self, delimiter: u8, skip: usize) -> Option<usize> {
+		self.filled()[skip..].iter()
+			.position(|byte| *byte == delimiter)
+			.map(|position| skip + position)
+	}
+	
+	pub fn consume(&mut self, amount: usize) {
+		assert!(amount <= self.filled_buffer_length, "cannot consume more data than is buffered");
+		
+		if let Some(mut capture) = self.capture.take() {
+			match capture.write_all(&self.filled()[..amount]) {
+				Ok(()) => self.capture = Some(capture),
+				Err(err) => self.capture_error = Some(err),
+			}
+		}
+		
+		self.advance(amount);
+	}
+	
+	/// Consumes the specified amount of bytes without writing them to the capture.
+	fn advance(&mut self, amount: usize) {
+		if let Some(lines) = self.lines {
+			self.lines = Some(lines + count_lines(&self.filled()[..amount]));
+		}
+		
+		self.filled_buffer_start += amount;
+		self.filled_buffer_length -= amount;
+		self.position += amount as u64;
+	}
+	
+	/// The amount of bytes consumed in total, minus the ones made
+	/// part of the buffered data again.
+	pub fn position(&self) -> u64 {
+		self.position
+	}
+	
+	/// The amount of consumed bytes directly preceding the buffered data
+	/// which are still stored and can be restored using [`Buffer::unconsume`].
+	/// 
+	/// Consumed bytes are only moved away by compacting, which discards them
+	/// unless they are kept, or by aligning the buffered data,
+	/// after which only the kept ones are tracked.
+	pub fn retained(&self) -> usize {
+		match (self.alignment, self.kept_position()) {
+			(1, _) => self.filled_buffer_start,
+			(_, Some(kept_position)) => self.filled_buffer_start.min(self.position.saturating_sub(kept_position) as usize),
+			_ => 0,
+		}
+	}
+	
+	/// Makes the specified amount of bytes directly preceding
+	/// the buffered data part of it again.
+	pub fn unconsume(&mut self, amount: usize) {
+		assert!(amount <= self.retained(), "cannot unconsume more data than is retained");
+		self.filled_buffer_start -= amount;
+		self.filled_buffer_length += amount;
+		self.position -= amount as u64;
+		
+		if let Some(lines) = self.lines {
+			self.lines = Some(lines - count_lines(&self.filled()[..amount]));
+		}
+	}
+	
+	/// Moves to the specified [`Buffer::position`], returning whether the data
+	/// at that position is still stored. Otherwise nothing is changed.
+	pub fn rewind_to(&mut self, position: u64) -> bool {
+		if position <= self.position {
+			match usize::try_from(self.position - position) {
+				Ok(amount) if amount <= self.retained() => self.unconsume(amount),
+				_ => return false,
+			}
+		} else {
+			match usize::try_from(position - self.position) {
+				Ok(amount) if amount <= self.filled_buffer_length => self.consume(amount),
+				_ => return false,
+			}
+		}
+		
+		true
+	}
+	
+	/// The amount of newlines consumed since [`Buffer::track_lines`] was first called.
+	pub fn lines(&self) -> Option<u64> {
+		self.lines
+	}
+	
+	/// Starts counting the newlines in the consumed data, if not done already.
+	pub fn track_lines(&mut self) {
+		self.lines.get_or_insert(0);
+	}
+	
+	/// The [`Buffer::position`] from which on all data stays stored,
+	/// even once consumed.
+	pub fn mark(&self) -> Option<u64> {
+		self.mark
+	}
+	
+	pub fn set_mark(&mut self, mark: Option<u64>) {
+		self.mark = mark;
+	}
+	
+	/// Like [`Buffer::mark`], but independent of it,
+	/// so transactions don't interfere with the mark.
+	pub fn hold(&self) -> Option<u64> {
+		self.hold
+	}
+	
+	pub fn set_hold(&mut self, hold: Option<u64>) {
+		self.hold = hold;
+	}
+	
+	/// Consumes all of the buffered data and discards it without capturing it,
+	/// along with all data retained before it, the mark and the hold.
+	pub fn discard(&mut self) {
+		self.advance(self.filled_buffer_length);
+		self.mark = None;
+		self.hold = None;
+		self.compact();
+	}
+	
+	/// Writes all bytes consumed from now on to the given [Write],
+	/// until writing fails.
+	pub fn set_capture(&mut self, capture: Box<dyn Write + Send + Sync>) {
+		self.capture = Some(capture);
+	}
+	
+	/// Takes the error which made writing to the capture fail, if any.
+	pub fn take_capture_error(&mut self) -> Option<io::Error> {
+		self.capture_error.take()
+	}
+	
+	/// Like [`Buffer::discard`], but also wipes all of the storage
+	/// with the `zeroize` feature.
+	pub fn clear(&mut self) {
+		self.discard();
+		
+		#[cfg(feature = "zeroize")]
+		self.wipe();
+	}
+	
+	/// Overwrites all of the initialized storage with zeroes.
+	#[cfg(feature = "zeroize")]
+	fn wipe(&mut self) {
+		self.inline.zeroize();
+		self.buffer[..].zeroize();
+	}
+	
+	/// Consumes the specified amount of bytes and returns them,
+	/// counting them as a frame.
+	pub fn take(&mut self, amount: usize) -> &[u8] {
+		let start = self.take_frame(amount);
+		&self.storage()[start..start + amount]
+	}
+	
+	/// Like [`Buffer::take`], but returns the bytes mutably.
+	pub fn take_mut(&mut self, amount: usize) -> &mut [u8] {
+		let start = self.take_frame(amount);
+		&mut self.storage_mut()[start..start + amount]
+	}
+	
+	/// Consumes the specified amount of bytes, counting them as a frame.
+	pub fn consume_frame(&mut self, amount: usize) {
+		self.take_frame(amount);
+	}
+	
+	/// Consumes the specified amount of bytes as a frame, returning where it starts,
+	/// after shrinking the storage first if the [ShrinkPolicy] calls for it.
+	fn take_frame(&mut self, amount: usize) -> usize {
+		self.apply_shrink_policy();
+		
+		let start = self.filled_buffer_start;
+		self.consume(amount);
+		self.taken_frames += 1;
+		start
+	}
+	
+	/// Counts the frames taken while the storage is larger than
+	/// the high watermark but holds little data, and shrinks it
+	/// to the low watermark once there were enough of them.
+	fn apply_shrink_policy(&mut self) {
+		let Some(policy) = self.shrink_policy else {
+			return;
+		};
+		
+		if self.capacity() <= policy.high || self.len() > policy.low {
+			self.idle_frames = 0;
+			return;
+		}
+		
+		self.idle_frames += 1;
+		
+		if self.idle_frames >= policy.operations {
+			self.idle_frames = 0;
+			self.shrink_to(policy.low);
+		}
+	}
+	
+	pub fn shrink_policy(&self) -> Option<ShrinkPolicy> {
+		self.shrink_policy
+	}
+	
+	pub fn set_shrink_policy(&mut self, shrink_policy: Option<ShrinkPolicy>) {
+		self.shrink_policy = shrink_policy;
+		self.idle_frames = 0;
+	}
+	
+	/// Shrinks the storage to the specified capacity, or as close to it
+	/// as possible while keeping the buffered data and the kept data before it.
+	pub fn shrink_to(&mut self, capacity: usize) {
+		if self.is_inline() {
+			return;
+		}
+		
+		self.compact();
+		let capacity = capacity.max(self.filled_buffer_end());
+		
+		if capacity >= self.buffer.capacity() {
+			return;
+		}
+		
+		self.shrink_storage(capacity);
+	}
+	
+	/// The amount of frames taken using [`Buffer::take`] or [`Buffer::consume_frame`].
+	pub fn taken_frames(&self) -> u64 {
+		self.taken_frames
+	}
+	
+	/// The space after the buffered data which can be filled
+	/// and then marked as filled using [`Buffer::commit`].
+	pub fn spare_mut(&mut self) -> &mut [u8] {
+		let filled_buffer_end = self.filled_buffer_end();
+		&mut self.storage_mut()[filled_buffer_end..]
+	}
+	
+	/// The buffered data followed by the specified amount of bytes
+	/// at the start of [`Buffer::spare_mut`], without marking them as filled.
+	pub fn filled_with_spare(&self, amount: usize) -> &[u8] {
+		&self.storage()[self.filled_buffer_start..self.filled_buffer_end() + amount]
+	}
+	
+	/// Marks the specified amount of bytes at the start of
+	/// [`Buffer::spare_mut`] as filled.
+	pub fn commit(&mut self, amount: usize) {
+		assert!(self.filled_buffer_end() + amount <= self.storage().len(), "cannot commit more data than there is space");
+		self.filled_buffer_length += amount;
+	}
+	
+	pub fn extend_from_slice(&mut self, data: &[u8]) {
+		self.reserve(data.len());
+		self.spare_mut()[..data.len()].copy_from_slice(data);
+		self.commit(data.len());
+	}
+	
+	/// Ensures that [`Buffer::spare_mut`] is at least the specified amount of bytes long.
+	pub fn reserve(&mut self, amount: usize) {
+		if self.storage().len() >= self.filled_buffer_end() + amount {
+			return;
+		}
+		
+		// only the initialized part of the storage may be too short,
+		// in which case the data stays where it is
+		if self.capacity() < self.filled_buffer_end() + amount {
+			self.compact();
+		}
+		
+		self.resize(self.filled_buffer_end() + amount);
+	}
+	
+	/// Ensures that at least the specified amount of bytes
+	/// can be read into the buffer using [`Buffer::read_from`].
+	#[cfg(not(feature = "nightly"))]
+	pub fn reserve_for_read(&mut self, amount: usize) {
+		if self.alignment > 1 {
+			return self.reserve_aligned(amount);
+		}
+		
+		self.reserve(amount);
+	}
+	
+	/// Ensures that at least the specified amount of bytes
+	/// can be read into the buffer using [`Buffer::read_from`].
+	#[cfg(feature = "nightly")]
+	pub fn reserve_for_read(&mut self, amount: usize) {
+		if self.alignment > 1 {
+			return self.reserve_aligned(amount);
+		}
+		
+		if self.capacity() >= self.filled_buffer_end() + amount {
+			return;
+		}
+		
+		self.compact();
+		
+		let required_length = self.filled_buffer_end() + amount;
+		
+		if self.is_inline() {
+			if required_length <= INLINE {
+				return;
+			}
+			
+			self.spill(required_length);
+		}
+		
+		self.reserve_storage(required_length);
+	}
+	
+	/// Performs a single call to [`ByteSource::read_into`] into the space after the buffered data,
+	/// reading at most the specified amount of bytes, and marks the read bytes as filled.
+	#[cfg(not(feature = "nightly"))]
+	pub fn read_from(&mut self, reader: &mut impl ByteSource, max_amount: usize) -> Result<usize, io::Error> {
+		let max_amount = self.align_read(max_amount);
+		self.read_into_spare(reader, max_amount)
+	}
+	
+	/// Performs a single call to [`ByteSource::read_into`] into [`Buffer::spare_mut`],
+	/// reading at most the specified amount of bytes, and marks the read bytes as filled.
+	fn read_into_spare(&mut self, reader: &mut impl ByteSource, max_amount: usize) -> Result<usize, io::Error> {
+		let spare_buffer = self.spare_mut();
+		let length = spare_buffer.len().min(max_amount);
+		
+		let amount_read = reader.read_into(&mut spare_buffer[..length])?;
+		self.commit(amount_read);
+		Ok(amount_read)
+	}
+	
+	/// Performs a single call to [`ByteSource::read_into_cursor`] into the space after the buffered data,
+	/// reading at most the specified amount of bytes, and marks the read bytes as filled.
+	#[cfg(feature = "nightly")]
+	pub fn read_from(&mut self, reader: &mut impl ByteSource, max_amount: usize) -> Result<usize, io::Error> {
+		let max_amount = self.align_read(max_amount);
+		
+		if self.is_inline() {
+			return self.read_into_spare(reader, max_amount);
+		}
+		
+		let filled_buffer_end = self.filled_buffer_end();
+		let initialized_length = self.buffer.len() - filled_buffer_end;
+		let spare_capacity = self.buffer.capacity() - filled_buffer_end;
+		
+		// SAFETY: the range lies within the allocation of the vector
+		// and viewing initialized bytes as possibly uninitialized is always sound
+		let spare_buffer = unsafe {
+			let start = self.buffer.as_mut_ptr().add(filled_buffer_end);
+			slice::from_raw_parts_mut(start.cast::<MaybeUninit<u8>>(), spare_capacity)
+		};
+		let length = spare_capacity.min(max_amount);
+		let mut spare_buffer = BorrowedBuf::from(&mut spare_buffer[..length]);
+		
+		if initialized_length >= length {
+			// SAFETY: all bytes up to the length of the vector are initialized
+			unsafe {
+				spare_buffer.set_init();
+			}
+		}
+		
+		reader.read_into_cursor(spare_buffer.unfilled())?;
+		
+		let amount_read = spare_buffer.len();
+		let initialized_end = match spare_buffer.is_init() {
+			true => filled_buffer_end + length,
+			false => filled_buffer_end + amount_read,
+		};
+		
+		if initialized_end > self.buffer.len() {
+			// SAFETY: BorrowedBuf guarantees that its filled part is initialized,
+			// as well as all of it if it reports being initialized
+			unsafe {
+				self.buffer.set_len(initialized_end);
+			}
+		}
+		
+		self.commit(amount_read);
+		Ok(amount_read)
+	}
+	
+	/// Ensures that the space after the buffered data starts at an aligned address
+	/// and is at least the specified amount of bytes, rounded up to the alignment, long.
+	fn reserve_aligned(&mut self, amount: usize) {
+		let amount = amount.max(1).next_multiple_of(self.alignment);
+		let filled_buffer_end = self.filled_buffer_end();
+		let is_aligned = self.storage().as_ptr().wrapping_add(filled_buffer_end).align_offset(self.alignment) == 0;
+		
+		if is_aligned && self.storage().len() >= filled_buffer_end + amount {
+			return;
+		}
+		
+		let kept_start = self.kept_start();
+		let kept_length = filled_buffer_end - kept_start;
+		self.resize(kept_length + amount + self.alignment - 1);
+		
+		let start = self.storage().as_ptr().wrapping_add(kept_length).align_offset(self.alignment);
+		self.storage_mut().copy_within(kept_start..filled_buffer_end, start);
+		self.filled_buffer_start = start + (self.filled_buffer_start - kept_start);
+	}
+	
+	/// Moves the buffered data, along with the kept data before it,
+	/// so that it starts at an address with the specified alignment.
+	#[cfg(feature = "bytemuck")]
+	pub fn align_filled(&mut self, alignment: usize) {
+		let is_aligned = self.storage().as_ptr().wrapping_add(self.filled_buffer_start).align_offset(alignment) == 0;
+		
+		if is_aligned {
+			return;
+		}
+		
+		let kept_start = self.kept_start();
+		let kept_length = self.filled_buffer_end() - kept_start;
+		let kept_offset = self.filled_buffer_start - kept_start;
+		self.resize(kept_length + alignment - 1);
+		
+		let start = self.storage().as_ptr().wrapping_add(kept_offset).align_offset(alignment);
+		self.storage_mut().copy_within(kept_start..kept_start + kept_length, start);
+		self.filled_buffer_start = start + kept_offset;
+	}
+	
+	/// Prepares a read of at most the specified amount of bytes,
+	/// returning the amount rounded to the alignment.
+	fn align_read(&mut self, max_amount: usize) -> usize {
+		if self.alignment == 1 {
+			return max_amount;
+		}
+		
+		self.reserve_aligned(self.alignment);
+		let spare_length = self.storage().len() - self.filled_buffer_end();
+		let max_amount = max_amount.checked_next_multiple_of(self.alignment).unwrap_or(usize::MAX);
+		max_amount.min(spare_length) / self.alignment * self.alignment
+	}
+	
+	/// Moves the buffered data, along with the kept data before it,
+	/// to the start of the buffer.
+	fn compact(&mut self) {
+		let kept_start = self.kept_start();
+		
+		if kept_start > 0 {
+			let kept_buffer = kept_start..self.filled_buffer_end();
+			self.storage_mut().copy_within(kept_buffer, 0);
+			self.filled_buffer_start -= kept_start;
+		}
+	}
+	
+	/// The start of the data which needs to be kept when moving the buffered data.
+	fn kept_start(&self) -> usize {
+		match self.kept_position() {
+			Some(kept_position) if kept_position < self.position => {
+				self.filled_buffer_start - (self.position - kept_position) as usize
+			},
+			_ => self.filled_buffer_start,
+		}
+	}
+	
+	/// The [`Buffer::position`] from which on all data is kept, if any,
+	/// which is the earlier one of the mark and the hold.
+	fn kept_position(&self) -> Option<u64> {
+		match (self.mark, self.hold) {
+			(Some(mark), Some(hold)) => Some(mark.min(hold)),
+			(mark, hold) => mark.or(hold),
+		}
+	}
+	
+	/// Ensures that the initialized storage is at least the specified amount of bytes long.
+	fn resize(&mut self, length: usize) {
+		if self.is_inline() {
+			if length <= INLINE {
+				return;
+			}
+			
+			self.spill(length);
+		}
+		
+		if self.buffer.len() < length {
+			self.reserve_storage(length);
+			
+			// the storage is only initialized as it's needed, doubling each time
+			// so it doesn't have to be extended again for every small read
+			let length = length.max(self.buffer.len() * 2).min(self.buffer.capacity());
+			self.buffer.resize(length, 0);
+		}
+	}
+	
+	/// Ensures that the vector can hold at least the specified amount of bytes,
+	/// growing it according to the [GrowthPolicy].
+	#[cfg(not(feature = "zeroize"))]
+	fn reserve_storage(&mut self, capacity: usize) {
+		if self.buffer.capacity() >= capacity {
+			return;
+		}
+		
+		let capacity = self.growth_policy.grown_capacity(self.buffer.capacity(), capacity);
+		self.buffer.reserve_exact(capacity - self.buffer.len());
+	}
+	
+	/// Ensures that the vector can hold at least the specified amount of bytes,
+	/// growing it according to the [GrowthPolicy] by moving the data
+	/// to a new vector and wiping the old one.
+	#[cfg(feature = "zeroize")]
+	fn reserve_storage(&mut self, capacity: usize) {
+		if self.buffer.capacity() >= capacity {
+			return;
+		}
+		
+		let mut buffer = empty_storage_like(&self.buffer);
+		buffer.reserve_exact(self.growth_policy.grown_capacity(self.buffer.capacity(), capacity));
+		buffer.extend_from_slice(&self.buffer);
+		self.buffer[..].zeroize();
+		self.buffer = buffer;
+	}
+	
+	/// Shrinks the vector to the specified capacity,
+	/// which holds all of the buffered data.
+	#[cfg(not(feature = "zeroize"))]
+	fn shrink_storage(&mut self, capacity: usize) {
+		self.buffer.truncate(capacity);
+		self.buffer.shrink_to(capacity);
+	}
+	
+	/// Shrinks the vector to the specified capacity, which holds all
+	/// of the buffered data, by moving the data to a new vector
+	/// and wiping the old one.
+	#[cfg(feature = "zeroize")]
+	fn shrink_storage(&mut self, capacity: usize) {
+		let mut buffer = empty_storage_like(&self.buffer);
+		buffer.reserve_exact(capacity);
+		buffer.extend_from_slice(&self.buffer[..self.buffer.len().min(capacity)]);
+		self.buffer[..].zeroize();
+		self.buffer = buffer;
+	}
+	
+	/// Moves the data stored inline into the vector,
+	/// allocating at least the specified amount of bytes
+	/// according to the [GrowthPolicy].
+	fn spill(&mut self, capacity: usize) {
+		self.buffer.reserve_exact(self.growth_policy.grown_capacity(INLINE, capacity).max(1));
+		self.buffer.extend_from_slice(&self.inline[..self.filled_buffer_end()]);
+	}
+	
+	/// Whether the data is stored inline, which is the case until
+	/// anything has been allocated in the vector.
+	fn is_inline(&self) -> bool {
+		self.buffer.capacity() == 0
+	}
+	
+	fn storage(&self) -> &[u8] {
+		match self.is_inline() {
+			true => &self.inline,
+			false => &self.buffer,
+		}
+	}
+	
+	fn storage_mut(&mut self) -> &mut [u8] {
+		match self.is_inline() {
+			true => &mut self.inline,
+			false => &mut self.buffer,
+		}
+	}
+	
+	fn filled_buffer_end(&self) -> usize {
+		self.filled_buffer_start + self.filled_buffer_length
+	}
+}
+
+fn count_lines(data: &[u8]) -> u64 {
+	data.iter().filter(|byte| **byte == b'\n').count() as u64
+}
+
+#[cfg(not(feature = "allocator-api2"))]
+fn empty_storage() -> Storage {
+	Vec::new()
+}
+
+#[cfg(feature = "allocator-api2")]
+fn empty_storage() -> Storage {
+	Storage::new_in(BufferAllocator::global())
+}
+
+#[cfg(all(feature = "zeroize", not(feature = "allocator-api2")))]
+fn empty_storage_like(_storage: &Storage) -> Storage {
+	Vec::new()
+}
+
+#[cfg(all(feature = "zeroize", feature = "allocator-api2"))]
+fn empty_storage_like(storage: &Storage) -> Storage {
+	Storage::new_in(storage.allocator().clone())
+}
+
+impl<const INLINE: usize> Drop for Buffer<INLINE> {
+	fn drop(&mut self) {
+		#[cfg(feature = "zeroize")]
+		self.wipe();
+		
+		if let Some(reclaim) = self.reclaim.take() {
+			let mut storage = mem::replace(&mut self.buffer, empty_storage());
+			storage.clear();
+			reclaim(storage);
+		}
+	}
+}
\ No newline at end of file
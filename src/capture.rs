@@ -0,0 +1,62 @@
+use std::io::{self, Write};
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource> DynReadBuffer<R> {
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// which writes every byte it hands out to the given [Write].
+	/// 
+	/// This is useful for protocol traffic dumps and audit logs.
+	/// Unlike wrapping the [ByteSource] in an adapter copying all read data,
+	/// this doesn't change how much data is read by each call to [ByteSource::read_into],
+	/// and only data which was actually consumed is written,
+	/// in the order in which it was consumed.
+	/// 
+	/// Bytes skipped without being returned, for example by [`skip_until`],
+	/// are written as well, but bytes thrown away by [`clear`] are not.
+	/// Bytes returned again after rolling back, for example using [`reset_to_mark`],
+	/// are written again.
+	/// 
+	/// Once writing to the [Write] fails, nothing more is written to it
+	/// and the error can be retrieved using [`take_capture_error`].
+	/// Reading from the **DynReadBuffer** is not affected.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::fs::File;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// # let path = std::env::temp_dir().join("read_buffer_capture_example");
+	/// let reader = "GET /\nHost: example.com\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::with_capture(reader, File::create(&path)?);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"GET /\n");
+	/// drop(buffer);
+	/// 
+	/// assert_eq!(std::fs::read(&path)?, b"GET /\n");
+	/// # std::fs::remove_file(&path)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`skip_until`]: DynReadBuffer::skip_until
+	/// [`clear`]: DynReadBuffer::clear
+	/// [`reset_to_mark`]: DynReadBuffer::reset_to_mark
+	/// [`take_capture_error`]: DynReadBuffer::take_capture_error
+	pub fn with_capture(reader: R, sink: impl Write + Send + Sync + 'static) -> Self {
+		let mut buffer = Self::new(reader);
+		buffer.buffer.set_capture(Box::new(sink));
+		buffer
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Returns the error which made writing to the [Write] given to
+	/// [`with_capture`] fail, if any, and clears it.
+	/// 
+	/// [`with_capture`]: DynReadBuffer::with_capture
+	pub fn take_capture_error(&mut self) -> Option<io::Error> {
+		self.buffer.take_capture_error()
+	}
+}
\ No newline at end of file
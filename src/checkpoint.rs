@@ -0,0 +1,61 @@
+use crate::{ByteSource, CheckpointExpired, DynReadBuffer};
+
+/// A position in the data read by a [`DynReadBuffer`], returned from [`checkpoint`],
+/// which can be returned to using [`restore`].
+/// 
+/// Unlike [`mark`] and [`transaction`], a **Checkpoint** doesn't cause
+/// any data to be kept in the internal buffer, so it can only be restored
+/// as long as the data read since then hasn't been discarded,
+/// which happens when the internal buffer is compacted to make space for a read.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`checkpoint`]: DynReadBuffer::checkpoint
+/// [`restore`]: DynReadBuffer::restore
+/// [`mark`]: DynReadBuffer::mark
+/// [`transaction`]: DynReadBuffer::transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+	position: u64,
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Returns a [Checkpoint] of the current position in the data.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "let x".as_bytes();
+	/// let mut buffer = DynReadBuffer::with_capacity(reader, 64);
+	/// 
+	/// let checkpoint = buffer.checkpoint();
+	/// assert_eq!(buffer.read_bytes(3)?, b"let");
+	/// 
+	/// buffer.restore(checkpoint).unwrap();
+	/// assert_eq!(buffer.read_bytes(5)?, b"let x");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn checkpoint(&self) -> Checkpoint {
+		Checkpoint {
+			position: self.buffer.position(),
+		}
+	}
+	
+	/// Returns to the position of the given [Checkpoint],
+	/// so the following reads continue from there.
+	/// 
+	/// # Errors
+	/// 
+	/// If the data at the [Checkpoint] is no longer stored
+	/// in the internal buffer, [CheckpointExpired] is returned
+	/// and the position is unchanged.
+	pub fn restore(&mut self, checkpoint: Checkpoint) -> Result<(), CheckpointExpired> {
+		match self.buffer.rewind_to(checkpoint.position) {
+			true => Ok(()),
+			false => Err(CheckpointExpired),
+		}
+	}
+}
\ No newline at end of file
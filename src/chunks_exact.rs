@@ -0,0 +1,101 @@
+use std::io::{self, ErrorKind};
+use crate::{ByteSource, DynReadBuffer};
+
+/// A view of a [`DynReadBuffer`] returning the data in chunks of a fixed size,
+/// returned by [`chunks_exact`].
+/// 
+/// Like [`slice::ChunksExact`], every chunk returned by [`next_chunk`]
+/// is exactly the chunk size long, and a final partial chunk
+/// is available using [`remainder`] instead.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`chunks_exact`]: DynReadBuffer::chunks_exact
+/// [`slice::ChunksExact`]: std::slice::ChunksExact
+/// [`next_chunk`]: ChunksExact::next_chunk
+/// [`remainder`]: ChunksExact::remainder
+pub struct ChunksExact<'a, R: ByteSource, const INLINE: usize = 0> {
+	buffer: &'a mut DynReadBuffer<R, INLINE>,
+	chunk_size: usize,
+	finished: bool,
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Returns a [ChunksExact] through which the data can be read
+	/// in chunks of exactly the specified size.
+	/// 
+	/// This is useful for record-structured data like fixed-width logs
+	/// or raw sensor dumps.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the chunk size is `0`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [1, 2, 3, 4, 5, 6, 7].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let mut chunks = buffer.chunks_exact(3);
+	/// 
+	/// assert_eq!(chunks.next_chunk().transpose()?, Some([1, 2, 3].as_slice()));
+	/// assert_eq!(chunks.next_chunk().transpose()?, Some([4, 5, 6].as_slice()));
+	/// assert_eq!(chunks.next_chunk().transpose()?, None);
+	/// assert_eq!(chunks.remainder(), [7]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn chunks_exact(&mut self, chunk_size: usize) -> ChunksExact<'_, R, INLINE> {
+		assert!(chunk_size > 0, "chunk size must be greater than 0");
+		
+		ChunksExact {
+			buffer: self,
+			chunk_size,
+			finished: false,
+		}
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> ChunksExact<'_, R, INLINE> {
+	/// Reads the next chunk and returns a slice referencing it,
+	/// or [None] once the given [ByteSource] reaches its "end of file"
+	/// before a complete chunk could be read.
+	/// 
+	/// # Errors
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// The data read so far is preserved, so calling **next_chunk** again
+	/// continues the chunk.
+	/// 
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn next_chunk(&mut self) -> Option<Result<&[u8], io::Error>> {
+		if self.finished {
+			return None;
+		}
+		
+		match self.buffer.read_bytes(self.chunk_size) {
+			Err(err) if err.kind() == ErrorKind::UnexpectedEof => {
+				self.finished = true;
+				None
+			},
+			result => Some(result),
+		}
+	}
+	
+	/// Returns the data after the last complete chunk,
+	/// which is the final partial chunk once [`next_chunk`] returned [None].
+	/// 
+	/// The remainder is not consumed and can still be read
+	/// from the [`DynReadBuffer`].
+	/// 
+	/// [`next_chunk`]: ChunksExact::next_chunk
+	/// [`DynReadBuffer`]: crate::DynReadBuffer
+	pub fn remainder(&self) -> &[u8] {
+		self.buffer.available()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,152 @@
+use std::io::{self, ErrorKind};
+use bytes::BytesMut;
+use tokio_util::codec::Decoder;
+use crate::{ByteSource, DynReadBuffer};
+use crate::delimited_message::parse_varint;
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads from the given [ByteSource] until the given [Decoder] produces a frame
+	/// and returns it, so codecs written for `tokio_util` can also be used
+	/// for synchronous reading.
+	/// 
+	/// As the [Decoder] expects a [BytesMut], the buffered data is copied
+	/// every time the [Decoder] is called, so this is best suited for small frames.
+	/// 
+	/// Requires the `tokio-util` feature.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors returned by the [Decoder] are passed on to the caller.
+	/// 
+	/// If the [ByteSource] reaches its "end of file" and [Decoder::decode_eof]
+	/// doesn't produce a frame, an error of the kind
+	/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// All other errors are handled the same way as in [`read_bytes`].
+	/// The data not yet consumed by the [Decoder] is preserved in the internal buffer.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// use tokio_util::codec::LinesCodec;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "Hello\nWorld\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let mut codec = LinesCodec::new();
+	/// 
+	/// assert_eq!(buffer.read_decoded(&mut codec)?, "Hello");
+	/// assert_eq!(buffer.read_decoded(&mut codec)?, "World");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [BytesMut]: bytes::BytesMut
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_decoded<D: Decoder>(&mut self, decoder: &mut D) -> Result<D::Item, D::Error> {
+		let mut eof = false;
+		
+		loop {
+			if !self.available().is_empty() || eof {
+				let mut data = BytesMut::from(self.available());
+				
+				let frame = match eof {
+					false => decoder.decode(&mut data)?,
+					true => decoder.decode_eof(&mut data)?,
+				};
+				
+				let consumed = self.available().len() - data.len();
+				
+				if let Some(frame) = frame {
+					self.consume(consumed);
+					return Ok(frame);
+				}
+				
+				self.buffer.consume(consumed);
+				
+				if eof {
+					return Err(io::Error::from(ErrorKind::UnexpectedEof).into());
+				}
+			}
+			
+			eof = self.fill_buffer()? == 0;
+		}
+	}
+}
+
+/// A [Decoder] splitting data into frames ending with a delimiter,
+/// like [`DynReadBuffer::read_until`].
+/// 
+/// The frames include the delimiter. Data left over at the end
+/// of the stream results in an error.
+/// 
+/// Requires the `tokio-util` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DelimiterCodec {
+	delimiter: u8,
+}
+
+impl DelimiterCodec {
+	/// Creates a new **DelimiterCodec** splitting frames after the specified delimiter.
+	pub fn new(delimiter: u8) -> Self {
+		Self {
+			delimiter,
+		}
+	}
+}
+
+impl Decoder for DelimiterCodec {
+	type Item = BytesMut;
+	type Error = io::Error;
+	
+	fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+		let position = src.iter().position(|byte| *byte == self.delimiter);
+		Ok(position.map(|position| src.split_to(position + 1)))
+	}
+}
+
+/// A [Decoder] for messages prefixed with their length encoded as a varint,
+/// like [`DynReadBuffer::read_delimited_message`].
+/// 
+/// The frames don't include the length prefix.
+/// 
+/// Requires the `tokio-util` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DelimitedMessageCodec {
+	max_size: usize,
+}
+
+impl DelimitedMessageCodec {
+	/// Creates a new **DelimitedMessageCodec** accepting messages
+	/// of at most the specified size.
+	pub fn new(max_size: usize) -> Self {
+		Self {
+			max_size,
+		}
+	}
+}
+
+impl Decoder for DelimitedMessageCodec {
+	type Item = BytesMut;
+	type Error = io::Error;
+	
+	fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+		let Some((length, prefix_length)) = parse_varint(src)? else {
+			return Ok(None);
+		};
+		
+		let length = usize::try_from(length).ok()
+			.filter(|length| *length <= self.max_size)
+			.ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "message exceeds maximum size"))?;
+		
+		if src.len() < prefix_length + length {
+			src.reserve(prefix_length + length - src.len());
+			return Ok(None);
+		}
+		
+		let _ = src.split_to(prefix_length);
+		Ok(Some(src.split_to(length)))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::ffi::CStr;
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads from the given [ByteSource] until a nul byte is found
+	/// and returns the data up to and including it as a [CStr].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = b".text\0.data\0".as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_cstr()?, c".text");
+	/// assert_eq!(buffer.read_cstr()?, c".data");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	pub fn read_cstr(&mut self) -> Result<&CStr, io::Error> {
+		let data = self.read_until(0)?;
+		
+		Ok(CStr::from_bytes_with_nul(data)
+			.expect("data should end with its only nul byte"))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,138 @@
+use std::io::{self, ErrorKind};
+use std::net::UdpSocket;
+
+const DEFAULT_CAPACITY: usize = 2048;
+
+/// A socket which receives whole datagrams, used by [DatagramReadBuffer].
+/// 
+/// This is implemented for connected [UdpSocket]s.
+/// Other message based sockets, like `SOCK_SEQPACKET` sockets,
+/// can implement it using `recv(2)` with and without `MSG_PEEK`.
+pub trait RecvDatagram {
+	/// Receives a single datagram into the given buffer,
+	/// returning its length. If the datagram doesn't fit, the rest of it is discarded.
+	fn recv(&self, buf: &mut [u8]) -> Result<usize, io::Error>;
+	
+	/// Like [`RecvDatagram::recv`], but leaves the datagram in the socket,
+	/// so it's returned again by the next call.
+	fn peek(&self, buf: &mut [u8]) -> Result<usize, io::Error>;
+}
+
+impl RecvDatagram for UdpSocket {
+	fn recv(&self, buf: &mut [u8]) -> Result<usize, io::Error> {
+		UdpSocket::recv(self, buf)
+	}
+	
+	fn peek(&self, buf: &mut [u8]) -> Result<usize, io::Error> {
+		UdpSocket::peek(self, buf)
+	}
+}
+
+impl<S: RecvDatagram + ?Sized> RecvDatagram for &S {
+	fn recv(&self, buf: &mut [u8]) -> Result<usize, io::Error> {
+		(**self).recv(buf)
+	}
+	
+	fn peek(&self, buf: &mut [u8]) -> Result<usize, io::Error> {
+		(**self).peek(buf)
+	}
+}
+
+/// A buffer for receiving from message based sockets like [UdpSocket],
+/// which returns exactly one datagram per call to [`recv`],
+/// preserving the message boundaries which are lost when using [Read].
+/// 
+/// The internal buffer grows to fit the largest datagram received so far,
+/// so datagrams are never truncated.
+/// 
+/// [`recv`]: DatagramReadBuffer::recv
+/// [Read]: std::io::Read
+pub struct DatagramReadBuffer<S: RecvDatagram> {
+	buffer: Vec<u8>,
+	socket: S,
+}
+
+impl<S: RecvDatagram> DatagramReadBuffer<S> {
+	/// Creates a new **DatagramReadBuffer** to receive from the given socket.
+	pub fn new(socket: S) -> Self {
+		Self::with_capacity(socket, DEFAULT_CAPACITY)
+	}
+	
+	/// Creates a new **DatagramReadBuffer** to receive from the given socket
+	/// with an internal buffer of the specified initial capacity.
+	pub fn with_capacity(socket: S, capacity: usize) -> Self {
+		Self {
+			buffer: vec![0; capacity.max(1)],
+			socket,
+		}
+	}
+	
+	/// Receives the next datagram and returns a slice referencing it.
+	/// 
+	/// If the datagram might not fit into the internal buffer,
+	/// its size is checked using [`RecvDatagram::peek`] and the buffer grows
+	/// before receiving it with a single call to [`RecvDatagram::recv`].
+	/// 
+	/// # Errors
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from the socket are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::net::UdpSocket;
+	/// use read_buffer::DatagramReadBuffer;
+	/// 
+	/// let socket = UdpSocket::bind("127.0.0.1:0")?;
+	/// socket.connect(socket.local_addr()?)?;
+	/// socket.send(b"hello")?;
+	/// socket.send(b"world")?;
+	/// 
+	/// let mut buffer = DatagramReadBuffer::new(socket);
+	/// 
+	/// assert_eq!(buffer.recv()?, b"hello");
+	/// assert_eq!(buffer.recv()?, b"world");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn recv(&mut self) -> Result<&[u8], io::Error> {
+		loop {
+			let length = retry_interrupted(|| self.socket.peek(&mut self.buffer))?;
+			
+			if length < self.buffer.len() {
+				break;
+			}
+			
+			let new_length = self.buffer.len() * 2;
+			self.buffer.resize(new_length, 0);
+		}
+		
+		let length = retry_interrupted(|| self.socket.recv(&mut self.buffer))?;
+		Ok(&self.buffer[..length])
+	}
+	
+	/// Returns a reference to the socket.
+	pub fn get_ref(&self) -> &S {
+		&self.socket
+	}
+	
+	/// Returns the socket, discarding the internal buffer.
+	pub fn into_inner(self) -> S {
+		self.socket
+	}
+}
+
+fn retry_interrupted(mut f: impl FnMut() -> Result<usize, io::Error>) -> Result<usize, io::Error> {
+	loop {
+		match f() {
+			Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+			result => return result,
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use std::fmt;
+
+/// Implements [Debug][fmt::Debug] using the given closure,
+/// so the buffer types can provide alternative ways of formatting themselves.
+pub(crate) struct DebugFn<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result>(pub(crate) F);
+
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> fmt::Debug for DebugFn<F> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		(self.0)(f)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::io::{self, ErrorKind};
+use crate::{ByteSource, DynReadBuffer};
+
+const MAX_VARINT_LENGTH: usize = 10;
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads a message prefixed with its length encoded as a varint
+	/// from the given [ByteSource] and returns a slice referencing the message
+	/// without the length prefix.
+	/// 
+	/// This matches the framing used by protobuf's `writeDelimitedTo`
+	/// and `parseDelimitedFrom`, so the returned slice can be decoded
+	/// directly by a protobuf implementation.
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is preserved in the internal buffer
+	/// for future reads and no data is consumed.
+	/// 
+	/// If the length prefix isn't a valid varint or the length exceeds `max_size`,
+	/// an error of the kind [ErrorKind::InvalidData][`InvalidData`] is returned.
+	/// 
+	/// All other errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [3, 1, 2, 3, 1, 4].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_delimited_message(1024)?, [1, 2, 3]);
+	/// assert_eq!(buffer.read_delimited_message(1024)?, [4]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_delimited_message(&mut self, max_size: usize) -> Result<&[u8], io::Error> {
+		let (length, prefix_length) = self.peek_varint()?;
+		
+		let length = usize::try_from(length).ok()
+			.filter(|length| *length <= max_size)
+			.ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "message exceeds maximum size"))?;
+		let total_length = prefix_length.checked_add(length)
+			.ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "message exceeds maximum size"))?;
+		
+		let message = self.read_bytes(total_length)?;
+		Ok(&message[prefix_length..])
+	}
+	
+	fn peek_varint(&mut self) -> Result<(u64, usize), io::Error> {
+		for length in 1..=MAX_VARINT_LENGTH {
+			if let Some(varint) = parse_varint(self.lookahead(length)?)? {
+				return Ok(varint);
+			}
+		}
+		
+		Err(io::Error::new(ErrorKind::InvalidData, "varint is too long"))
+	}
+}
+
+/// Parses a varint at the start of the data, returning its value and length,
+/// or [None] if the data ends before the varint does.
+pub(crate) fn parse_varint(data: &[u8]) -> Result<Option<(u64, usize)>, io::Error> {
+	let mut value = 0;
+	
+	for (index, &byte) in data.iter().enumerate().take(MAX_VARINT_LENGTH) {
+		if index == MAX_VARINT_LENGTH - 1 && byte > 1 {
+			return Err(io::Error::new(ErrorKind::InvalidData, "varint overflows u64"));
+		}
+		
+		value |= u64::from(byte & 0x7f) << (7 * index);
+		
+		if byte & 0x80 == 0 {
+			return Ok(Some((value, index + 1)));
+		}
+	}
+	
+	if data.len() >= MAX_VARINT_LENGTH {
+		return Err(io::Error::new(ErrorKind::InvalidData, "varint is too long"));
+	}
+	
+	Ok(None)
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::io;
+use serde::de::DeserializeOwned;
+use crate::{ByteSource, DynReadBuffer};
+
+/// A data format that frames read by [`read_frame_deserialize`] can be deserialized from.
+/// 
+/// Implementations are provided for `Json` and `Postcard`
+/// behind the `serde_json` and `postcard` features.
+/// Other formats can be supported by implementing this trait.
+/// 
+/// *This trait is only available with the `serde` feature.*
+/// 
+/// [`read_frame_deserialize`]: DynReadBuffer::read_frame_deserialize
+pub trait FrameFormat {
+	/// Deserializes a value of type `T` from the given frame.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors during deserialization should be returned
+	/// as an error of the kind [ErrorKind::InvalidData][`InvalidData`].
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	fn deserialize<T: DeserializeOwned>(&self, frame: &[u8]) -> Result<T, io::Error>;
+}
+
+/// The JSON format using [serde_json].
+/// 
+/// *This type is only available with the `serde_json` feature.*
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+#[cfg(feature = "serde_json")]
+impl FrameFormat for Json {
+	fn deserialize<T: DeserializeOwned>(&self, frame: &[u8]) -> Result<T, io::Error> {
+		serde_json::from_slice(frame)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+	}
+}
+
+/// The postcard format using [postcard].
+/// 
+/// *This type is only available with the `postcard` feature.*
+#[cfg(feature = "postcard")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Postcard;
+
+#[cfg(feature = "postcard")]
+impl FrameFormat for Postcard {
+	fn deserialize<T: DeserializeOwned>(&self, frame: &[u8]) -> Result<T, io::Error> {
+		postcard::from_bytes(frame)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads a frame of the specified length
+	/// and deserializes it using the given format.
+	/// 
+	/// *This method is only available with the `serde` feature.*
+	/// 
+	/// # Errors
+	/// 
+	/// If the frame can't be deserialized, the error from the format is returned.
+	/// The frame is consumed regardless, so that the next read
+	/// starts after it.
+	/// 
+	/// All other errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// # #[cfg(feature = "serde_json")] {
+	/// use read_buffer::{DynReadBuffer, Json};
+	/// 
+	/// let reader = b"[1,2,3]{}".as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let numbers: Vec<u32> = buffer.read_frame_deserialize(7, Json)?;
+	/// 
+	/// assert_eq!(numbers, [1, 2, 3]);
+	/// # }
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_frame_deserialize<T, F>(&mut self, length: usize, format: F) -> Result<T, io::Error>
+	where
+		T: DeserializeOwned,
+		F: FrameFormat,
+	{
+		let frame = self.read_bytes(length)?;
+		format.deserialize(frame)
+	}
+}
\ No newline at end of file
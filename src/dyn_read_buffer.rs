@@ -1,62 +1,476 @@
+use std::{char, fmt, str};
 use std::io::{Read, self, ErrorKind};
+use crate::{ByteSource, Endianness, QuotaExceeded, ReadObserver, Truncated};
+use crate::buffer::Buffer;
+use crate::debug::DebugFn;
+#[cfg(feature = "allocator-api2")]
+use crate::allocator::BufferAllocator;
+#[cfg(feature = "allocator-api2")]
+use allocator_api2::alloc::Allocator;
 
-/// A dynamically sized buffer to read into from a [Read] and safely access the read data.
+/// The storage for decoded text, which is wiped on drop with the `zeroize` feature.
+#[cfg(not(feature = "zeroize"))]
+type DecodeBuffer = String;
+#[cfg(feature = "zeroize")]
+type DecodeBuffer = zeroize::Zeroizing<String>;
+
+/// A dynamically sized buffer to read into from a [ByteSource] and safely access the read data.
 /// 
 /// **DynReadBuffer** provides a heap-allocated buffer to read into using
 /// [`read_bytes`] or [`read_until`],
-/// but crucially doesn't allow **any** access to the data inside the buffer
-/// outside of the slices returned from [`read_bytes`] and [`read_until`].
+/// but crucially only ever exposes data which was actually read from the [ByteSource]
+/// and hasn't been consumed yet. Besides the returned slices, methods like [`available`]
+/// give access to the buffered data, but no stale data from earlier reads
+/// or unused parts of the buffer can be accessed.
 /// 
 /// This type is preferrable over [`ReadBuffer`] when the maximum expected size of a single read
 /// is not known at compile time.
 /// 
+/// Besides any [Read], every other [ByteSource] can be read from as well.
+/// 
 /// [`read_bytes`]: DynReadBuffer::read_bytes
 /// [`read_until`]: DynReadBuffer::read_until
+/// [`available`]: DynReadBuffer::available
 /// [`ReadBuffer`]: crate::ReadBuffer
-pub struct DynReadBuffer<R: Read> {
-	buffer: Vec<u8>,
-	reader: R,
-	filled_buffer_start: usize,
-	filled_buffer_length: usize,
+pub struct DynReadBuffer<R: ByteSource, const INLINE: usize = 0> {
+	pub(crate) buffer: Buffer<INLINE>,
+	pub(crate) reader: R,
+	decode_buffer: DecodeBuffer,
+	read_length: u64,
+	read_calls: u64,
+	quota: Option<u64>,
+	pub(crate) observer: Option<Box<dyn ReadObserver + Send + Sync>>,
 }
 
-impl<R: Read> DynReadBuffer<R> {
-	/// Creates a new **DynReadBuffer** to read from the given [Read].
+impl<R: ByteSource> DynReadBuffer<R> {
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource].
 	pub fn new(reader: R) -> Self {
 		Self {
-			buffer: Vec::new(),
+			buffer: Buffer::new(),
 			reader,
-			filled_buffer_start: 0,
-			filled_buffer_length: 0,
+			decode_buffer: DecodeBuffer::default(),
+			read_length: 0,
+			read_calls: 0,
+			quota: None,
+			observer: None,
 		}
 	}
 	
-	/// Creates a new **DynReadBuffer** to read from the given [Read]
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
 	/// with an internal buffer of at least the specified capacity.
+	/// 
+	/// The internal buffer is allocated up front, but only initialized
+	/// as it's filled, so creating a large buffer is cheap.
 	pub fn with_capacity(reader: R, capacity: usize) -> Self {
 		Self {
-			buffer: vec![0; capacity],
+			buffer: Buffer::with_capacity(capacity),
+			reader,
+			decode_buffer: DecodeBuffer::default(),
+			read_length: 0,
+			read_calls: 0,
+			quota: None,
+			observer: None,
+		}
+	}
+	
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// whose internal buffer is allocated using the given allocator.
+	/// 
+	/// Only the internal buffer is allocated using the allocator,
+	/// some methods like [`read_utf16_line`] still use the global allocator.
+	/// 
+	/// Requires the `allocator-api2` feature.
+	/// 
+	/// [`read_utf16_line`]: DynReadBuffer::read_utf16_line
+	#[cfg(feature = "allocator-api2")]
+	pub fn new_in(reader: R, allocator: impl Allocator + Send + Sync + 'static) -> Self {
+		Self::with_capacity_in(reader, 0, allocator)
+	}
+	
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// whose internal buffer of at least the specified capacity
+	/// is allocated using the given allocator.
+	/// 
+	/// Requires the `allocator-api2` feature.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use allocator_api2::alloc::Global;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [1, 2, 3].as_slice();
+	/// let mut buffer = DynReadBuffer::with_capacity_in(reader, 64, Global);
+	/// 
+	/// assert_eq!(buffer.read_bytes(2)?, [1, 2]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "allocator-api2")]
+	pub fn with_capacity_in(reader: R, capacity: usize, allocator: impl Allocator + Send + Sync + 'static) -> Self {
+		Self {
+			buffer: Buffer::with_capacity_in(capacity, BufferAllocator::new(allocator)),
+			reader,
+			decode_buffer: DecodeBuffer::default(),
+			read_length: 0,
+			read_calls: 0,
+			quota: None,
+			observer: None,
+		}
+	}
+	
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// using the given internal buffer.
+	pub(crate) fn from_buffer(reader: R, buffer: Buffer) -> Self {
+		Self {
+			buffer,
+			reader,
+			decode_buffer: DecodeBuffer::default(),
+			read_length: 0,
+			read_calls: 0,
+			quota: None,
+			observer: None,
+		}
+	}
+	
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// with an internal buffer of at least the specified capacity,
+	/// which only reads into space starting at an address that is
+	/// a multiple of the alignment, in multiples of the alignment.
+	/// 
+	/// This makes it possible to read from sources with strict
+	/// alignment requirements, like files opened with `O_DIRECT`,
+	/// by using their block size as the alignment.
+	/// Buffered data may be moved to keep the reads aligned.
+	/// 
+	/// As reads are rounded up to a multiple of the alignment,
+	/// a quota set using [`set_quota`] may be exceeded by less than the alignment.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the alignment is not a power of two.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [1; 1024].as_slice();
+	/// let mut buffer = DynReadBuffer::with_alignment(reader, 4096, 512);
+	/// 
+	/// let data = buffer.read_bytes(100)?;
+	/// assert_eq!(data, [1; 100]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`set_quota`]: DynReadBuffer::set_quota
+	pub fn with_alignment(reader: R, capacity: usize, alignment: usize) -> Self {
+		Self {
+			buffer: Buffer::with_alignment(capacity, alignment),
+			reader,
+			decode_buffer: DecodeBuffer::default(),
+			read_length: 0,
+			read_calls: 0,
+			quota: None,
+			observer: None,
+		}
+	}
+
+}
+
+impl DynReadBuffer<io::Empty> {
+	/// Creates a new **DynReadBuffer** holding the given data,
+	/// without a [ByteSource] to read more data from.
+	/// 
+	/// This makes it possible to use the same code for framing data
+	/// which is already fully in memory, for example in tests
+	/// or when replaying captured payloads.
+	/// After all of the data has been read, the **DynReadBuffer**
+	/// behaves as if it reached its "end of file".
+	/// 
+	/// Without the `allocator-api2` feature, a given [Vec] is used
+	/// as the internal buffer without copying the data.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let mut buffer = DynReadBuffer::from_bytes(b"first\nsecond".to_vec());
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"first\n");
+	/// assert_eq!(buffer.read_bytes(6)?, b"second");
+	/// assert!(buffer.read_bytes(1).is_err());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn from_bytes(data: impl Into<Vec<u8>>) -> Self {
+		Self::from_buffer(io::empty(), Buffer::from_vec(data.into()))
+	}
+}
+
+impl<R: Read, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Appends another [Read] to read from once the current one
+	/// reaches its "end of file", keeping all buffered data,
+	/// so a stream split across several sources can be read as one.
+	/// 
+	/// Frames which span the seam between two sources are read as usual.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let first = "first line\nsecond ".as_bytes();
+	/// let second = "line\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(first);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"first line\n");
+	/// 
+	/// let mut buffer = buffer.chain(second);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"second line\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn chain<N: Read>(self, next: N) -> DynReadBuffer<io::Chain<R, N>, INLINE> {
+		DynReadBuffer {
+			buffer: self.buffer,
+			reader: self.reader.chain(next),
+			decode_buffer: self.decode_buffer,
+			read_length: self.read_length,
+			read_calls: self.read_calls,
+			quota: self.quota,
+			observer: self.observer,
+		}
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// which stores up to `INLINE` bytes inline instead of allocating.
+	/// 
+	/// Only once more than `INLINE` bytes need to be buffered at the same time,
+	/// the internal buffer is moved to the heap, where it stays from then on.
+	/// This means protocols with small enough messages never allocate.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "PING\nPONG\n".as_bytes();
+	/// let mut buffer: DynReadBuffer<_, 64> = DynReadBuffer::new_inline(reader);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"PING\n");
+	/// assert_eq!(buffer.read_until(b'\n')?, b"PONG\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn new_inline(reader: R) -> Self {
+		Self {
+			buffer: Buffer::new(),
 			reader,
-			filled_buffer_start: 0,
-			filled_buffer_length: 0,
+			decode_buffer: DecodeBuffer::default(),
+			read_length: 0,
+			read_calls: 0,
+			quota: None,
+			observer: None,
+		}
+	}
+	
+	/// Limits the total amount of bytes read from the given [ByteSource]
+	/// over the whole lifetime of this **DynReadBuffer** to the specified quota,
+	/// including any bytes read before the quota was set.
+	/// 
+	/// Once the quota is used up, any read which needs more data
+	/// from the given [ByteSource] fails with an error of the kind
+	/// [ErrorKind::InvalidData][`InvalidData`] wrapping a [QuotaExceeded].
+	/// Data which is already buffered can still be read.
+	/// 
+	/// This is useful for enforcing size limits on untrusted input,
+	/// like the body of a request to a server.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use std::io::ErrorKind;
+	/// use read_buffer::{DynReadBuffer, QuotaExceeded};
+	/// 
+	/// let reader = "short line\na much longer line\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// buffer.set_quota(16);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n').unwrap(), b"short line\n");
+	/// 
+	/// let error = buffer.read_until(b'\n').unwrap_err();
+	/// assert_eq!(error.kind(), ErrorKind::InvalidData);
+	/// 
+	/// let quota_exceeded = error.get_ref()
+	///     .and_then(|err| err.downcast_ref::<QuotaExceeded>())
+	///     .unwrap();
+	/// assert_eq!(quota_exceeded.quota(), 16);
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	pub fn set_quota(&mut self, quota: u64) {
+		self.quota = Some(quota);
+	}
+	
+	/// Returns the amount of space reserved for each read
+	/// when the amount of data needed isn't known in advance,
+	/// like in [`read_until`].
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	pub fn read_chunk_size(&self) -> usize {
+		self.buffer.read_chunk_size()
+	}
+	
+	/// Sets the amount of space reserved for each read
+	/// when the amount of data needed isn't known in advance,
+	/// like in [`read_until`], which by default is 8 KiB.
+	/// 
+	/// Free space in the internal buffer is used first, up to this size,
+	/// so the internal buffer only grows by this size once it's nearly full.
+	/// 
+	/// Larger sizes reduce the amount of calls to [ByteSource::read_into]
+	/// needed for large frames, while smaller sizes keep
+	/// the internal buffer small when all frames are small.
+	/// The size is clamped to at least 1 byte.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [1; 100_000].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// buffer.set_read_chunk_size(64 * 1024);
+	/// 
+	/// assert_eq!(buffer.read_while(None, |_| true)?.len(), 100_000);
+	/// assert!(buffer.read_calls() <= 4);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	pub fn set_read_chunk_size(&mut self, read_chunk_size: usize) {
+		self.buffer.set_read_chunk_size(read_chunk_size.max(1));
+	}
+	
+	/// Returns a reference to the given [ByteSource].
+	/// 
+	/// This allows registering a socket with an event loop,
+	/// like the one of the `polling` crate, after wrapping it in a **DynReadBuffer**.
+	pub fn get_ref(&self) -> &R {
+		&self.reader
+	}
+	
+	/// Returns a mutable reference to the given [ByteSource].
+	/// 
+	/// Reading from it directly skips the internal buffer,
+	/// so any data which is buffered would be read out of order.
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.reader
+	}
+	
+	/// Returns the total amount of bytes read from the given [ByteSource]
+	/// over the whole lifetime of this **DynReadBuffer**,
+	/// including data which is still buffered.
+	pub fn total_bytes_read(&self) -> u64 {
+		self.read_length
+	}
+	
+	/// Returns how many times [ByteSource::read_into] was called on the given [ByteSource],
+	/// including calls which returned an error.
+	/// 
+	/// Comparing this with [`total_bytes_read`] shows
+	/// how much data was returned by each call on average.
+	/// 
+	/// [`total_bytes_read`]: DynReadBuffer::total_bytes_read
+	pub fn read_calls(&self) -> u64 {
+		self.read_calls
+	}
+	
+	/// Returns how many values were successfully read from this **DynReadBuffer**,
+	/// like slices returned from [`read_bytes`] or characters returned from [`read_char`].
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	/// [`read_char`]: DynReadBuffer::read_char
+	pub fn frames_returned(&self) -> u64 {
+		self.buffer.taken_frames()
+	}
+	
+	/// Returns a value whose [Debug][fmt::Debug] implementation
+	/// includes the buffered data.
+	/// 
+	/// The [Debug][fmt::Debug] implementation of **DynReadBuffer** itself
+	/// never prints any of the contents of the buffer, so they don't end up in logs
+	/// by accident. This should only be used when the data is known not to be sensitive.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "token=hunter2\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// buffer.fill_once()?;
+	/// 
+	/// assert!(format!("{buffer:?}").starts_with("DynReadBuffer { capacity: "));
+	/// assert!(format!("{:?}", buffer.debug_with_contents()).contains("buffered_data: [116, "));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn debug_with_contents(&self) -> impl fmt::Debug + '_ {
+		DebugFn(|f: &mut fmt::Formatter<'_>| self.fmt_debug(f, true))
+	}
+	
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, with_contents: bool) -> fmt::Result {
+		let mut debug = f.debug_struct("DynReadBuffer");
+		debug.field("capacity", &self.buffer.capacity())
+			.field("buffered", &self.buffer.len())
+			.field("position", &self.buffer.position())
+			.field("total_bytes_read", &self.read_length)
+			.field("read_calls", &self.read_calls)
+			.field("frames_returned", &self.buffer.taken_frames())
+			.field("quota", &self.quota);
+		
+		if with_contents {
+			debug.field("buffered_data", &self.buffer.filled());
 		}
+		
+		debug.finish_non_exhaustive()
 	}
 	
 	/// Reads the specified amount of bytes
-	/// from the given [Read] into the internal buffer
+	/// from the given [ByteSource] into the internal buffer
 	/// and returns a slice referencing the read data.
 	/// 
 	/// # Errors
 	/// 
-	/// If the given [Read] reaches its "end of file" before
+	/// If any error occurs, the data read so far is preserved in the internal buffer
+	/// for future reads. It can be inspected using [`available`],
+	/// for example to log or salvage a partial message.
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before
 	/// the requested amount of bytes could be read,
 	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`]
-	/// is returned following the behavior of [Read::read_exact].
+	/// is returned following the behavior of [Read::read_exact][std::io::Read::read_exact].
 	/// 
 	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
 	/// is encountered, it is ignored.
 	/// 
-	/// All other errors from [Read::read_exact] are passed on to the caller.
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
 	/// 
 	/// # Examples
 	/// 
@@ -70,36 +484,127 @@ impl<R: Read> DynReadBuffer<R> {
 	/// let read_data = buffer.read_bytes(3)?;
 	/// 
 	/// assert_eq!(read_data, [1, 2, 3]);
+	/// 
+	/// // Only one byte is left
+	/// assert!(buffer.read_bytes(2).is_err());
+	/// assert_eq!(buffer.available(), [4]);
 	/// # Ok(())
 	/// # }
 	/// ```
 	/// 
+	/// [`available`]: DynReadBuffer::available
 	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
 	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
 	pub fn read_bytes(&mut self, amount: usize) -> Result<&[u8], io::Error> {
-		if amount > self.filled_buffer_length {
-			let amount_to_fill = amount - self.filled_buffer_length;
-			self.reserve(amount_to_fill);
-			
-			let start = self.filled_buffer_end();
-			let end = start + amount_to_fill;
-			let buffer_to_fill = &mut self.buffer[start..end];
-			self.reader.read_exact(buffer_to_fill)?;
-			
-			self.filled_buffer_length += amount_to_fill;
-		}
-		
-		let start = self.filled_buffer_start;
-		let end = start + amount;
-		let result = &self.buffer[start..end];
+		self.fill_buffer_to("read_bytes", amount)?;
+		Ok(self.take(amount))
+	}
+	
+	/// Like [`read_bytes`], but returns a mutable slice referencing the read data,
+	/// so it can be transformed in place.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [0x21, 0x22, 0x23].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let read_data = buffer.read_bytes_mut(2)?;
+	/// read_data.iter_mut().for_each(|byte| *byte ^= 0x20);
+	/// 
+	/// assert_eq!(read_data, [1, 2]);
+	/// assert_eq!(buffer.read_bytes(1)?, [0x23]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_bytes_mut(&mut self, amount: usize) -> Result<&mut [u8], io::Error> {
+		self.fill_buffer_to("read_bytes_mut", amount)?;
 		
-		self.filled_buffer_start += amount;
-		self.filled_buffer_length -= amount;
+		if let Some(observer) = &mut self.observer {
+			observer.on_frame(amount);
+		}
 		
-		Ok(result)
+		Ok(self.buffer.take_mut(amount))
+	}
+	
+	/// Reads `N` bytes from the given [ByteSource] into the internal buffer
+	/// and returns a reference to the read data as an array.
+	/// 
+	/// This behaves exactly like [`read_bytes`] but lets fixed-size data
+	/// be destructured without having to convert the returned slice.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [1, 2, 3, 4, 5].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let [version, flags] = buffer.read_array()?;
+	/// 
+	/// assert_eq!(*version, 1);
+	/// assert_eq!(*flags, 2);
+	/// assert_eq!(buffer.read_array::<3>()?, &[3, 4, 5]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_array<const N: usize>(&mut self) -> Result<&[u8; N], io::Error> {
+		let result = self.read_bytes(N)?;
+		Ok(result.try_into().expect("slice should have a length of N"))
+	}
+	
+	/// Reads `N` bytes from the given [ByteSource] and returns a copy of them.
+	/// 
+	/// This is a by-value variant of [`read_array`] which doesn't keep
+	/// the buffer borrowed.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [0xca, 0xfe, 0, 3, 7, 8, 9].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let magic = buffer.read_array_copied::<2>()?;
+	/// let length = u16::from_be_bytes(buffer.read_array_copied()?);
+	/// let body = buffer.read_bytes(length.into())?;
+	/// 
+	/// assert_eq!(magic, [0xca, 0xfe]);
+	/// assert_eq!(body, [7, 8, 9]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_array`]: DynReadBuffer::read_array
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_array_copied<const N: usize>(&mut self) -> Result<[u8; N], io::Error> {
+		self.read_array().copied()
 	}
 	
-	/// Reads from the given [Read] until the specified delimiter is encountered
+	/// Reads from the given [ByteSource] until the specified delimiter is encountered
 	/// and returns a slice referencing the data up to and including the delimiter.
 	/// 
 	/// # Errors
@@ -107,14 +612,14 @@ impl<R: Read> DynReadBuffer<R> {
 	/// If any error occurs, the data read so far is preserved in the internal buffer
 	/// for future reads.
 	/// 
-	/// If the given [Read] reaches its "end of file" before
+	/// If the given [ByteSource] reaches its "end of file" before
 	/// the delimiter was encountered, an error of the kind
 	/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
 	/// 
 	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
 	/// is encountered, it is ignored.
 	/// 
-	/// All other errors from [Read::read] are passed on to the caller.
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
 	/// 
 	/// # Examples
 	/// 
@@ -136,73 +641,660 @@ impl<R: Read> DynReadBuffer<R> {
 	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
 	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
 	pub fn read_until(&mut self, delimiter: u8) -> Result<&[u8], io::Error> {
-		if self.filled_buffer_length > 0 {
-			let filled_buffer = &self.buffer[
-				self.filled_buffer_start..self.filled_buffer_end()
-			];
-			let delimiter_position = filled_buffer.iter()
-				.position(|byte| *byte == delimiter);
-			
-			if let Some(relative_position) = delimiter_position {
-				let absolute_position = self.filled_buffer_start
-					+ relative_position;
-				let result = &self.buffer[self.filled_buffer_start..=absolute_position];
-				self.filled_buffer_start = absolute_position + 1;
-				self.filled_buffer_length -= result.len();
-				return Ok(result);
-			}
-		}
-		
-		loop {
-			self.reserve(32);
-			
-			let filled_buffer_end = self.filled_buffer_end();
-			let available_buffer = &mut self.buffer[filled_buffer_end..];
-			let amount_read = match self.reader.read(available_buffer) {
-				Ok(n) => n,
-				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
-				Err(err) => return Err(err),
-			};
-			
-			if amount_read == 0 {
-				return Err(ErrorKind::UnexpectedEof.into());
-			}
-			
-			self.filled_buffer_length += amount_read;
-			
-			let read_data = &available_buffer[..amount_read];
-			let delimiter_position = read_data.iter()
-				.position(|byte| *byte == delimiter);
-			
-			if let Some(relative_position) = delimiter_position {
-				let absolute_position = self.filled_buffer_end()
-					- amount_read
-					+ relative_position;
-				let result = &self.buffer[self.filled_buffer_start..=absolute_position];
-				self.filled_buffer_start = absolute_position + 1;
-				self.filled_buffer_length -= result.len();
-				return Ok(result);
+		let position = self.fill_buffer_until_with("read_until", delimiter, |_| Ok(()))?;
+		Ok(self.take(position + 1))
+	}
+	
+	/// Continually calls [ByteSource::read_into] on the given [ByteSource] as long
+	/// as predicate returns true, growing the internal buffer as needed,
+	/// and returns a slice referencing all the buffered data,
+	/// including any data left over from previous reads.
+	/// 
+	/// This function takes a predicate that is called with each
+	/// chunk of data read from [ByteSource::read_into] and that decides
+	/// whether to keep reading.
+	/// 
+	/// The predicate is **not** called with an empty slice if
+	/// the call to [ByteSource::read_into] returns a length of 0.
+	/// 
+	/// This function keeps calling [ByteSource::read_into] on the given [ByteSource]
+	/// until one of the following occurs:
+	/// 
+	/// 1. The predicate returns `false`.
+	/// 1. The buffered data reaches `max_size`, if one is specified.
+	/// 1. The call to [ByteSource::read_into] returns a length of 0 indicating "end of file".
+	/// 1. The call to [ByteSource::read_into] returns an error.
+	/// 
+	/// At most `max_size` bytes are returned,
+	/// any further buffered data is preserved for future reads.
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::Read;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// // A reader which returns its data in separate calls to Read::read
+	/// let reader = [1, 2].as_slice()
+	///     .chain([3, 0].as_slice())
+	///     .chain([4, 5].as_slice());
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let read_data = buffer.read_while(None, |chunk| !chunk.contains(&0))?;
+	/// 
+	/// assert_eq!(read_data, [1, 2, 3, 0]);
+	/// assert_eq!(buffer.read_while(Some(1), |_chunk| true)?, [4]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_while(&mut self, max_size: Option<usize>, mut predicate: impl FnMut(&[u8]) -> bool) -> Result<&[u8], io::Error> {
+		let max_size = max_size.unwrap_or(usize::MAX);
+		
+		while self.buffer.len() < max_size {
+			let start = self.buffer.len();
+			
+			self.reserve_for_read(self.buffer.read_reservation());
+			let amount_read = self.read_into_buffer(max_size - start, &mut |_| Ok(()))?;
+			
+			if amount_read == 0 || !predicate(&self.buffer.filled()[start..]) {
+				break;
+			}
+		}
+		
+		Ok(self.take(self.buffer.len().min(max_size)))
+	}
+	
+	/// Reads from the given [ByteSource] until the largest prefix of the buffered data
+	/// which forms complete, valid UTF-8 is non-empty and returns that prefix.
+	/// 
+	/// If the buffered data ends with an incomplete code point, those bytes are
+	/// kept in the internal buffer and completed by subsequent calls.
+	/// If the buffer already contains at least one complete character,
+	/// no call to [ByteSource::read_into] is made.
+	/// 
+	/// If the returned string is empty, this indicates that the reader
+	/// has reached its "end of file" with no data left in the buffer.
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// If the buffered data starts with an invalid UTF-8 sequence, an error of the kind
+	/// [ErrorKind::InvalidData][`InvalidData`] is returned.
+	/// Any valid data preceding an invalid sequence is returned first.
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" while the buffer
+	/// contains an incomplete code point, an error of the kind
+	/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "añb".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_valid_utf8()?, "añb");
+	/// assert_eq!(buffer.read_valid_utf8()?, "");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_valid_utf8(&mut self) -> Result<&str, io::Error> {
+		loop {
+			let filled_buffer = self.buffer.filled();
+			
+			let valid_length = match str::from_utf8(filled_buffer) {
+				Ok(_) => filled_buffer.len(),
+				Err(err) if err.valid_up_to() == 0 && err.error_len().is_some() => {
+					return Err(io::Error::new(ErrorKind::InvalidData, err));
+				},
+				Err(err) => err.valid_up_to(),
+			};
+			
+			if valid_length > 0 {
+				let result = str::from_utf8(self.take(valid_length))
+					.expect("prefix should have been validated");
+				return Ok(result);
+			}
+			
+			let amount_read = self.fill_buffer()?;
+			
+			if amount_read == 0 {
+				if self.buffer.is_empty() {
+					return Ok("");
+				}
+				
+				return Err(self.unexpected_eof("read_valid_utf8", None));
 			}
 		}
 	}
 	
-	fn reserve(&mut self, amount: usize) {
-		let filled_buffer_end = self.filled_buffer_start + self.filled_buffer_length;
+	/// Reads UTF-16 encoded text from the given [ByteSource] until a line feed
+	/// is encountered and returns the line decoded as UTF-8,
+	/// including the line feed.
+	/// 
+	/// The code units are assembled from pairs of bytes in the given byte order,
+	/// regardless of how the data is split across calls to [ByteSource::read_into].
+	/// The decoded line is stored in an internal buffer
+	/// which is reused by subsequent calls.
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// If the line contains an unpaired surrogate, an error of the kind
+	/// [ErrorKind::InvalidData][`InvalidData`] is returned.
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before
+	/// a line feed was encountered, an error of the kind
+	/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{DynReadBuffer, Endianness};
+	/// 
+	/// let reader = [b'h', 0, b'i', 0, b'\n', 0, b'!', 0].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let line = buffer.read_utf16_line(Endianness::Little)?;
+	/// 
+	/// assert_eq!(line, "hi\n");
+	/// assert_eq!(buffer.read_bytes(2)?, [b'!', 0]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_utf16_line(&mut self, endianness: Endianness) -> Result<&str, io::Error> {
+		let mut searched_units = 0;
 		
-		if self.buffer.len() >= filled_buffer_end + amount {
-			return;
+		loop {
+			let filled_buffer = self.buffer.filled();
+			let code_units = filled_buffer.chunks_exact(2)
+				.map(|bytes| endianness.u16_from_bytes([bytes[0], bytes[1]]));
+			let line_feed_position = code_units.clone()
+				.skip(searched_units)
+				.position(|unit| unit == u16::from(b'\n'));
+			
+			if let Some(relative_position) = line_feed_position {
+				let line_length = searched_units + relative_position + 1;
+				
+				self.decode_buffer.clear();
+				
+				for decoded in char::decode_utf16(code_units.take(line_length)) {
+					let decoded = decoded
+						.map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+					self.decode_buffer.push(decoded);
+				}
+				
+				self.consume(line_length * 2);
+				return Ok(&self.decode_buffer);
+			}
+			
+			searched_units = filled_buffer.len() / 2;
+			
+			let amount_read = self.fill_buffer()?;
+			
+			if amount_read == 0 {
+				return Err(self.unexpected_eof("read_utf16_line", None));
+			}
 		}
+	}
+	
+	/// Reads a single UTF-8 encoded character from the given [ByteSource],
+	/// reading only as many bytes as are needed to complete it.
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is preserved in the internal buffer
+	/// for future reads and no data is consumed.
+	/// 
+	/// If the buffered data doesn't start with a valid UTF-8 sequence,
+	/// an error of the kind [ErrorKind::InvalidData][`InvalidData`] is returned
+	/// as soon as the first invalid byte is buffered.
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before
+	/// a complete character could be read, an error of the kind
+	/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "aß€".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_char()?, 'a');
+	/// assert_eq!(buffer.read_char()?, 'ß');
+	/// assert_eq!(buffer.read_char()?, '€');
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_char(&mut self) -> Result<char, io::Error> {
+		let invalid = || io::Error::new(ErrorKind::InvalidData, "invalid UTF-8 sequence");
+		
+		// the range of the second byte is narrower for some first bytes
+		// to rule out overlong encodings, surrogates and values above U+10FFFF
+		let (width, second_bytes) = match self.lookahead(1)?[0] {
+			0x00..=0x7f => (1, 0x80..=0xbf),
+			0xc2..=0xdf => (2, 0x80..=0xbf),
+			0xe0 => (3, 0xa0..=0xbf),
+			0xe1..=0xec | 0xee..=0xef => (3, 0x80..=0xbf),
+			0xed => (3, 0x80..=0x9f),
+			0xf0 => (4, 0x90..=0xbf),
+			0xf1..=0xf3 => (4, 0x80..=0xbf),
+			0xf4 => (4, 0x80..=0x8f),
+			_ => return Err(invalid()),
+		};
 		
-		if self.filled_buffer_start >= amount {
-			self.buffer.rotate_left(self.filled_buffer_start);
-			self.filled_buffer_start = 0;
-			return;
+		// each continuation byte is checked as soon as it's buffered,
+		// so no more data is waited for once the sequence can't be valid
+		for index in 1..width {
+			let byte = self.lookahead(index + 1)?[index];
+			
+			let is_valid = match index {
+				1 => second_bytes.contains(&byte),
+				_ => (0x80..=0xbf).contains(&byte),
+			};
+			
+			if !is_valid {
+				return Err(invalid());
+			}
 		}
 		
-		self.buffer.resize(self.filled_buffer_end() + amount, 0);
+		let encoded = self.lookahead(width)?;
+		let decoded = str::from_utf8(encoded)
+			.map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?
+			.chars()
+			.next()
+			.expect("sequence should contain exactly one character");
+		
+		self.consume(width);
+		
+		Ok(decoded)
 	}
 	
-	fn filled_buffer_end(&self) -> usize {
-		self.filled_buffer_start + self.filled_buffer_length
+	/// Returns the buffered data as two slices, like [`VecDeque::as_slices`],
+	/// without reading from the given [ByteSource] or consuming anything.
+	/// 
+	/// The buffered data is always stored contiguously, so the second slice
+	/// is currently always empty and the first one is the same as [`available`].
+	/// Scanning both slices keeps code working should that ever change.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "a,b,c".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// buffer.fill_once()?;
+	/// 
+	/// let (front, back) = buffer.buffered_slices();
+	/// let commas = front.iter().chain(back).filter(|byte| **byte == b',').count();
+	/// assert_eq!(commas, 2);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`VecDeque::as_slices`]: std::collections::VecDeque::as_slices
+	/// [`available`]: DynReadBuffer::available
+	pub fn buffered_slices(&self) -> (&[u8], &[u8]) {
+		(self.buffer.filled(), &[])
+	}
+	
+	/// Marks the specified amount of buffered bytes as read,
+	/// so the following reads start after them.
+	/// 
+	/// Together with [`lookahead`], this allows inspecting data before
+	/// deciding how much of it to read. The consumed bytes count as a single frame.
+	/// 
+	/// Like [`available`], this never reads from the given [ByteSource].
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the specified amount of bytes is larger than
+	/// the amount of buffered bytes.
+	/// 
+	/// [`lookahead`]: DynReadBuffer::lookahead
+	/// [`available`]: DynReadBuffer::available
+	pub fn consume(&mut self, amount: usize) {
+		if let Some(observer) = &mut self.observer {
+			observer.on_frame(amount);
+		}
+		
+		self.buffer.consume_frame(amount);
+	}
+	
+	/// Returns a slice referencing all of the buffered data, without reading
+	/// from the given [ByteSource] or consuming anything.
+	/// 
+	/// Together with [`fill_once`] and [`consume`], this allows building
+	/// custom scanning logic on top of the internal buffer.
+	/// 
+	/// After a read failed, this returns the data read so far,
+	/// such as the partial message left when [`read_bytes`] reaches the "end of file".
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "  \t  word".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// buffer.fill_once()?;
+	/// let whitespace = buffer.available().iter()
+	///     .take_while(|byte| byte.is_ascii_whitespace())
+	///     .count();
+	/// buffer.consume(whitespace);
+	/// 
+	/// assert_eq!(buffer.available(), b"word");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`fill_once`]: DynReadBuffer::fill_once
+	/// [`consume`]: DynReadBuffer::consume
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn available(&self) -> &[u8] {
+		self.buffer.filled()
+	}
+	
+	/// Removes all of the buffered data from the internal buffer and returns it,
+	/// without reading from the given [ByteSource].
+	/// 
+	/// The buffered data has already been read from the given [ByteSource],
+	/// so this allows recovering it after a fatal error
+	/// or when shutting down a connection instead of silently losing it.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "header\npartial".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"header\n");
+	/// assert!(buffer.read_until(b'\n').is_err());
+	/// 
+	/// assert_eq!(buffer.take_buffered(), b"partial");
+	/// assert!(buffer.available().is_empty());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn take_buffered(&mut self) -> Vec<u8> {
+		let data = self.buffer.filled().to_vec();
+		self.buffer.consume(data.len());
+		data
+	}
+	
+	/// Discards all of the buffered data without reading from the given [ByteSource],
+	/// including data kept for [`reset_to_mark`], and clears the mark.
+	/// 
+	/// With the `zeroize` feature, all of the internal buffer is overwritten
+	/// with zeroes, so data like keys and passwords doesn't linger in memory.
+	/// The internal buffer is also wiped when the **DynReadBuffer** is dropped.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "password\nrest".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// buffer.fill_once()?;
+	/// 
+	/// buffer.clear();
+	/// 
+	/// assert!(buffer.available().is_empty());
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`reset_to_mark`]: DynReadBuffer::reset_to_mark
+	pub fn clear(&mut self) {
+		self.buffer.clear();
+		
+		#[cfg(feature = "zeroize")]
+		zeroize::Zeroize::zeroize(&mut *self.decode_buffer);
+	}
+	
+	/// Consumes the specified amount of bytes as a single frame and returns them.
+	pub(crate) fn take(&mut self, amount: usize) -> &[u8] {
+		if let Some(observer) = &mut self.observer {
+			observer.on_frame(amount);
+		}
+		
+		self.buffer.take(amount)
+	}
+	
+	/// Reads from the given [ByteSource] until at least the specified amount of bytes
+	/// is buffered and returns a slice referencing them, without consuming them.
+	/// 
+	/// The following reads return the same data again,
+	/// unless it is skipped using [`consume`].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "#comment\nvalue\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// while buffer.lookahead(1)? == b"#" {
+	///     let line_length = buffer.read_until(b'\n')?.len();
+	///     assert_eq!(line_length, 9);
+	/// }
+	/// 
+	/// assert_eq!(buffer.lookahead(3)?, b"val");
+	/// buffer.consume(3);
+	/// assert_eq!(buffer.read_until(b'\n')?, b"ue\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`consume`]: DynReadBuffer::consume
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn lookahead(&mut self, amount: usize) -> Result<&[u8], io::Error> {
+		self.fill_buffer_to("lookahead", amount)?;
+		Ok(&self.buffer.filled()[..amount])
+	}
+	
+	fn fill_buffer_to(&mut self, operation: &'static str, amount: usize) -> Result<(), io::Error> {
+		self.fill_buffer_to_with(operation, amount, |_| Ok(()))
+	}
+	
+	pub(crate) fn fill_buffer(&mut self) -> Result<usize, io::Error> {
+		self.fill_buffer_with(|_| Ok(()))
+	}
+	
+	/// Creates an error of the kind [ErrorKind::UnexpectedEof] wrapping [Truncated]
+	/// with the current state of the buffer.
+	pub(crate) fn unexpected_eof(&self, operation: &'static str, requested: Option<usize>) -> io::Error {
+		io::Error::new(ErrorKind::UnexpectedEof, Truncated {
+			operation,
+			requested,
+			buffered: self.buffer.len(),
+			offset: self.buffer.position(),
+		})
+	}
+	
+	/// Reads until at least the specified amount of bytes is buffered,
+	/// calling `before_read` with the reader before each call to [ByteSource::read_into].
+	/// 
+	/// The operation is named in the error if the data ends too early.
+	pub(crate) fn fill_buffer_to_with(
+		&mut self,
+		operation: &'static str,
+		amount: usize,
+		mut before_read: impl FnMut(&mut R) -> Result<(), io::Error>,
+	) -> Result<(), io::Error> {
+		if amount > self.buffer.len() {
+			self.reserve_for_read(amount - self.buffer.len());
+		}
+		
+		while amount > self.buffer.len() {
+			let remaining = amount - self.buffer.len();
+			let amount_read = self.read_into_buffer(remaining, &mut before_read)?;
+			
+			if amount_read == 0 {
+				return Err(self.unexpected_eof(operation, Some(amount)));
+			}
+		}
+		
+		Ok(())
+	}
+	
+	/// Reads until the buffered data contains the delimiter and returns its position,
+	/// calling `before_read` with the reader before each call to [ByteSource::read_into].
+	/// 
+	/// The operation is named in the error if the data ends too early.
+	pub(crate) fn fill_buffer_until_with(
+		&mut self,
+		operation: &'static str,
+		delimiter: u8,
+		mut before_read: impl FnMut(&mut R) -> Result<(), io::Error>,
+	) -> Result<usize, io::Error> {
+		let mut searched_length = 0;
+		
+		loop {
+			if let Some(position) = self.buffer.find(delimiter, searched_length) {
+				return Ok(position);
+			}
+			
+			searched_length = self.buffer.len();
+			
+			if self.fill_buffer_with(&mut before_read)? == 0 {
+				return Err(self.unexpected_eof(operation, None));
+			}
+		}
+	}
+	
+	/// Performs a single read into the buffer, growing it if necessary,
+	/// calling `before_read` with the reader before each call to [ByteSource::read_into].
+	pub(crate) fn fill_buffer_with(
+		&mut self,
+		mut before_read: impl FnMut(&mut R) -> Result<(), io::Error>,
+	) -> Result<usize, io::Error> {
+		self.reserve_for_read(self.buffer.read_reservation());
+		self.read_into_buffer(usize::MAX, &mut before_read)
+	}
+	
+	/// Ensures that at least the specified amount of bytes can be read
+	/// into the internal buffer, which unlike [`DynReadBuffer::reserve`]
+	/// doesn't require the space to be initialized.
+	pub(crate) fn reserve_for_read(&mut self, amount: usize) {
+		let capacity = self.buffer.capacity();
+		self.buffer.reserve_for_read(amount);
+		self.notify_grow(capacity);
+	}
+	
+	pub(crate) fn notify_grow(&mut self, old_capacity: usize) {
+		if let Some(observer) = &mut self.observer {
+			let new_capacity = self.buffer.capacity();
+			
+			if new_capacity > old_capacity {
+				observer.on_grow(old_capacity, new_capacity);
+			}
+		}
+	}
+	
+	pub(crate) fn read_into_buffer(
+		&mut self,
+		max_amount: usize,
+		before_read: &mut impl FnMut(&mut R) -> Result<(), io::Error>,
+	) -> Result<usize, io::Error> {
+		let max_amount = match self.quota {
+			Some(quota) if self.read_length >= quota => {
+				return Err(io::Error::new(ErrorKind::InvalidData, QuotaExceeded { quota }));
+			},
+			Some(quota) => max_amount.min(usize::try_from(quota - self.read_length).unwrap_or(usize::MAX)),
+			None => max_amount,
+		};
+		
+		loop {
+			before_read(&mut self.reader)?;
+			
+			self.read_calls += 1;
+			let result = self.buffer.read_from(&mut self.reader, max_amount);
+			
+			if let Ok(amount_read) = result {
+				self.read_length += amount_read as u64;
+			}
+			
+			let result = match &mut self.observer {
+				Some(observer) => observer.on_read(&result).and(result),
+				None => result,
+			};
+			
+			match result {
+				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+				result => return result,
+			}
+		}
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> fmt::Debug for DynReadBuffer<R, INLINE> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
 	}
 }
\ No newline at end of file
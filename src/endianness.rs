@@ -0,0 +1,17 @@
+/// The byte order in which multi-byte values are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+	/// The least significant byte comes first.
+	Little,
+	/// The most significant byte comes first.
+	Big,
+}
+
+impl Endianness {
+	pub(crate) fn u16_from_bytes(self, bytes: [u8; 2]) -> u16 {
+		match self {
+			Endianness::Little => u16::from_le_bytes(bytes),
+			Endianness::Big => u16::from_be_bytes(bytes),
+		}
+	}
+}
\ No newline at end of file
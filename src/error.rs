@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// The error wrapped in an [`io::Error`] of the kind
+/// [ErrorKind::InvalidData][`InvalidData`] when a read would exceed
+/// the quota set using [`set_quota`].
+/// 
+/// [`io::Error`]: std::io::Error
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`set_quota`]: crate::DynReadBuffer::set_quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaExceeded {
+	pub(crate) quota: u64,
+}
+
+impl QuotaExceeded {
+	/// The quota that was exceeded, in bytes.
+	pub fn quota(&self) -> u64 {
+		self.quota
+	}
+}
+
+impl Display for QuotaExceeded {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "read quota of {} bytes exceeded", self.quota)
+	}
+}
+
+impl Error for QuotaExceeded {}
+
+/// The error wrapped in an [`io::Error`] of the kind
+/// [ErrorKind::InvalidData][`InvalidData`] when the internal buffer
+/// of a [`ReadBuffer`] is full before a read could be completed.
+/// 
+/// [`io::Error`]: std::io::Error
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`ReadBuffer`]: crate::ReadBuffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferFull {
+	pub(crate) capacity: usize,
+}
+
+impl BufferFull {
+	/// The capacity of the buffer that was full, in bytes.
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+}
+
+impl Display for BufferFull {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "buffer of {} bytes is full", self.capacity)
+	}
+}
+
+impl Error for BufferFull {}
+
+/// The error returned from [`restore`] when the data at the [`Checkpoint`]
+/// is no longer stored in the internal buffer.
+/// 
+/// [`restore`]: crate::DynReadBuffer::restore
+/// [`Checkpoint`]: crate::Checkpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointExpired;
+
+impl Display for CheckpointExpired {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "data at checkpoint is no longer buffered")
+	}
+}
+
+impl Error for CheckpointExpired {}
+
+/// The error wrapped in an [`io::Error`] of the kind
+/// [ErrorKind::InvalidData][`InvalidData`] when no delimiter is found within
+/// the maximum length given to [`read_until_limited`], or within the high watermark
+/// of a `FrameStream` or the maximum length of a `LinesStream`.
+/// 
+/// [`io::Error`]: std::io::Error
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`read_until_limited`]: crate::DynReadBuffer::read_until_limited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineTooLong {
+	pub(crate) max_length: usize,
+}
+
+impl LineTooLong {
+	/// The maximum length that was exceeded, in bytes.
+	pub fn max_length(&self) -> usize {
+		self.max_length
+	}
+}
+
+impl Display for LineTooLong {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "line exceeds the maximum length of {} bytes", self.max_length)
+	}
+}
+
+impl Error for LineTooLong {}
+
+/// The error wrapped in an [`io::Error`] of the kind
+/// [ErrorKind::InvalidData][`InvalidData`] when the data is longer than
+/// the maximum length given to [`read_to_end_limited`].
+/// 
+/// [`io::Error`]: std::io::Error
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`read_to_end_limited`]: crate::DynReadBuffer::read_to_end_limited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooLong {
+	pub(crate) max_length: usize,
+}
+
+impl TooLong {
+	/// The maximum length that was exceeded, in bytes.
+	pub fn max_length(&self) -> usize {
+		self.max_length
+	}
+}
+
+impl Display for TooLong {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "data exceeds the maximum length of {} bytes", self.max_length)
+	}
+}
+
+impl Error for TooLong {}
+
+/// The error wrapped in an [`io::Error`] of the kind
+/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] when the data of a [`DynReadBuffer`]
+/// ends before a read could be completed, describing the failed read.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use read_buffer::{DynReadBuffer, Truncated};
+/// 
+/// let reader = [1, 2, 3, 4, 5].as_slice();
+/// let mut buffer = DynReadBuffer::new(reader);
+/// 
+/// buffer.read_bytes(2).unwrap();
+/// let error = buffer.read_bytes(4).unwrap_err();
+/// let truncated = error.get_ref().unwrap().downcast_ref::<Truncated>().unwrap();
+/// 
+/// assert_eq!(truncated.operation(), "read_bytes");
+/// assert_eq!(truncated.requested(), Some(4));
+/// assert_eq!(truncated.buffered(), 3);
+/// assert_eq!(truncated.offset(), 2);
+/// ```
+/// 
+/// [`io::Error`]: std::io::Error
+/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Truncated {
+	pub(crate) operation: &'static str,
+	pub(crate) requested: Option<usize>,
+	pub(crate) buffered: usize,
+	pub(crate) offset: u64,
+}
+
+impl Truncated {
+	/// The name of the method that failed, like `"read_bytes"`.
+	pub fn operation(&self) -> &'static str {
+		self.operation
+	}
+	
+	/// The amount of bytes that were requested,
+	/// or [None] if the read was looking for a delimiter or similar.
+	pub fn requested(&self) -> Option<usize> {
+		self.requested
+	}
+	
+	/// The amount of bytes that were buffered when the data ended.
+	pub fn buffered(&self) -> usize {
+		self.buffered
+	}
+	
+	/// The amount of bytes consumed before the failed read.
+	pub fn offset(&self) -> u64 {
+		self.offset
+	}
+}
+
+impl Display for Truncated {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "unexpected end of file in {} at offset {}", self.operation, self.offset)?;
+		
+		match self.requested {
+			Some(requested) => write!(f, ": {} of {requested} bytes buffered", self.buffered),
+			None => write!(f, ": {} bytes buffered", self.buffered),
+		}
+	}
+}
+
+impl Error for Truncated {}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use std::io::Read;
+use crate::{DynReadBuffer, Framed};
+
+/// Extension methods for wrapping any [Read] in a buffer.
+/// 
+/// This trait is implemented for all types implementing [Read].
+/// 
+/// # Examples
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use read_buffer::ReadBufferExt;
+/// 
+/// let mut lines = "first\nsecond\n".as_bytes().framed(b'\n');
+/// 
+/// assert_eq!(lines.next_frame().unwrap()?, b"first\n");
+/// assert_eq!(lines.next_frame().unwrap()?, b"second\n");
+/// assert!(lines.next_frame().is_none());
+/// # Ok(())
+/// # }
+/// ```
+pub trait ReadBufferExt: Read + Sized {
+	/// Wraps this [Read] in a new [`DynReadBuffer`].
+	/// 
+	/// This is the same as calling [`DynReadBuffer::new`].
+	/// 
+	/// [`DynReadBuffer`]: crate::DynReadBuffer
+	/// [`DynReadBuffer::new`]: crate::DynReadBuffer::new
+	fn into_dyn_buffer(self) -> DynReadBuffer<Self> {
+		DynReadBuffer::new(self)
+	}
+	
+	/// Wraps this [Read] in a new [Framed],
+	/// returning the data in frames ending with the specified delimiter.
+	fn framed(self, delimiter: u8) -> Framed<Self> {
+		Framed::new(DynReadBuffer::new(self), delimiter)
+	}
+}
+
+impl<R: Read> ReadBufferExt for R {}
\ No newline at end of file
@@ -0,0 +1,146 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek};
+use std::path::Path;
+use crate::DynReadBuffer;
+
+/// How far ahead of the current position data is requested from the kernel.
+const READ_AHEAD: u64 = 4 * 1024 * 1024;
+
+/// A [Read] which reads a [File] from start to end, hinting to the
+/// operating system that it's accessed sequentially so it can read ahead
+/// more aggressively and evict data that has already been read.
+/// 
+/// On Linux, Android and FreeBSD the [File] is marked with `POSIX_FADV_SEQUENTIAL`
+/// and the data ahead of the current position is requested using
+/// `POSIX_FADV_WILLNEED` while reading.
+/// On Windows, [`SequentialFile::open`] opens the file with
+/// `FILE_FLAG_SEQUENTIAL_SCAN`, which can't be set on an already open [File].
+/// On other platforms no hints are given.
+/// 
+/// The hints don't change what is read, so failing to give them is not an error.
+/// 
+/// Requires the `fadvise` feature.
+pub struct SequentialFile {
+	file: File,
+	offset: u64,
+	advised_until: u64,
+}
+
+impl SequentialFile {
+	/// Creates a new **SequentialFile** reading from the given [File]
+	/// starting at its current position.
+	pub fn new(mut file: File) -> Self {
+		advise_sequential(&file);
+		let offset = file.stream_position().unwrap_or(0);
+		
+		Self {
+			file,
+			offset,
+			advised_until: offset,
+		}
+	}
+	
+	/// Opens the file at the given path for sequential reading.
+	/// 
+	/// # Errors
+	/// 
+	/// If the file can't be opened, the error is returned.
+	pub fn open(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+		let mut options = OpenOptions::new();
+		options.read(true);
+		
+		#[cfg(windows)]
+		{
+			use std::os::windows::fs::OpenOptionsExt;
+			use windows_sys::Win32::Storage::FileSystem::FILE_FLAG_SEQUENTIAL_SCAN;
+			options.custom_flags(FILE_FLAG_SEQUENTIAL_SCAN);
+		}
+		
+		Ok(Self::new(options.open(path)?))
+	}
+	
+	/// Returns the wrapped [File].
+	pub fn into_inner(self) -> File {
+		self.file
+	}
+}
+
+impl Read for SequentialFile {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		if self.offset + READ_AHEAD / 2 >= self.advised_until {
+			let start = self.advised_until.max(self.offset);
+			advise_will_need(&self.file, start, READ_AHEAD);
+			self.advised_until = start + READ_AHEAD;
+		}
+		
+		let length = self.file.read(buf)?;
+		self.offset += length as u64;
+		Ok(length)
+	}
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+fn advise(file: &File, offset: u64, length: u64, advice: libc::c_int) {
+	use std::os::fd::AsRawFd;
+	
+	let (Ok(offset), Ok(length)) = (libc::off_t::try_from(offset), libc::off_t::try_from(length)) else {
+		return;
+	};
+	
+	// SAFETY: the file descriptor is valid for as long as the file is borrowed
+	unsafe {
+		libc::posix_fadvise(file.as_raw_fd(), offset, length, advice);
+	}
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+fn advise_sequential(file: &File) {
+	advise(file, 0, 0, libc::POSIX_FADV_SEQUENTIAL);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+fn advise_will_need(file: &File, offset: u64, length: u64) {
+	advise(file, offset, length, libc::POSIX_FADV_WILLNEED);
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "freebsd")))]
+fn advise_sequential(_file: &File) {}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "freebsd")))]
+fn advise_will_need(_file: &File, _offset: u64, _length: u64) {}
+
+/// A [`DynReadBuffer`] reading a [File] sequentially with read-ahead hints,
+/// created using [`DynReadBuffer::sequential`].
+/// 
+/// Requires the `fadvise` feature.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+pub type SequentialReadBuffer = DynReadBuffer<SequentialFile>;
+
+impl DynReadBuffer<SequentialFile> {
+	/// Creates a new **DynReadBuffer** to read the given [File] from start to end,
+	/// hinting to the operating system that it's accessed sequentially,
+	/// using a [SequentialFile].
+	/// 
+	/// # Examples
+	/// 
+	/// ```no_run
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::fs::File;
+	/// use read_buffer::SequentialReadBuffer;
+	/// 
+	/// let file = File::open("large.log")?;
+	/// let mut buffer = SequentialReadBuffer::sequential(file);
+	/// 
+	/// let mut lines = buffer.records(b'\n');
+	/// 
+	/// while let Some(line) = lines.next_record() {
+	///     println!("{}", String::from_utf8_lossy(line?));
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn sequential(file: File) -> Self {
+		Self::new(SequentialFile::new(file))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use std::io::{self, ErrorKind, Read, Write};
+use crate::DynReadBuffer;
+use crate::buffer::Buffer;
+
+/// The [Read] of a [`DynReadBuffer`] which is fed data through a [Feeder],
+/// created using [`DynReadBuffer::new_fed`].
+/// 
+/// It never returns any data itself. Until [`finish_feeding`] is called,
+/// reads fail with an error of the kind [ErrorKind::WouldBlock][`WouldBlock`],
+/// afterwards they report the "end of file".
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`finish_feeding`]: DynReadBuffer::finish_feeding
+/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+#[derive(Debug, Default)]
+pub struct FeedReader {
+	finished: bool,
+}
+
+impl Read for FeedReader {
+	fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+		match self.finished {
+			true => Ok(0),
+			false => Err(ErrorKind::WouldBlock.into()),
+		}
+	}
+}
+
+/// A handle implementing [Write] which appends all written data
+/// to a [`DynReadBuffer`], returned by [`feeder`].
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`feeder`]: DynReadBuffer::feeder
+pub struct Feeder<'a> {
+	buffer: &'a mut Buffer,
+}
+
+impl DynReadBuffer<FeedReader> {
+	/// Creates a new **DynReadBuffer** which is fed data
+	/// by writing it into a [Feeder] instead of reading it from a [Read].
+	/// 
+	/// This allows using the methods of the **DynReadBuffer**
+	/// with producers which only offer an API pushing data into a [Write].
+	/// Reads which need more data than has been fed fail with an error of the kind
+	/// [ErrorKind::WouldBlock][`WouldBlock`] and preserve all data,
+	/// so they can be repeated after feeding more data,
+	/// for example using [`try_read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::Write;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let mut buffer = DynReadBuffer::new_fed();
+	/// 
+	/// buffer.feeder().write_all(b"hello ")?;
+	/// assert_eq!(buffer.try_read_until(b'\n')?, None);
+	/// 
+	/// buffer.feeder().write_all(b"world\nbye")?;
+	/// assert_eq!(buffer.try_read_until(b'\n')?, Some(b"hello world\n".as_slice()));
+	/// 
+	/// buffer.finish_feeding();
+	/// assert!(buffer.read_until(b'\n').is_err());
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	/// [`try_read_until`]: DynReadBuffer::try_read_until
+	pub fn new_fed() -> Self {
+		Self::new(FeedReader::default())
+	}
+	
+	/// Returns a [Feeder] through which data can be appended
+	/// to the internal buffer.
+	pub fn feeder(&mut self) -> Feeder<'_> {
+		Feeder {
+			buffer: &mut self.buffer,
+		}
+	}
+	
+	/// Marks the end of the fed data, so reads which need more data
+	/// than has been fed behave as if the "end of file" was reached.
+	/// 
+	/// Data fed afterwards can still be read.
+	pub fn finish_feeding(&mut self) {
+		self.reader.finished = true;
+	}
+}
+
+impl Write for Feeder<'_> {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.buffer.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+	
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,104 @@
+use crate::buffer::Buffer;
+
+/// A dynamically sized buffer which is fed data manually
+/// and safely splits it into frames.
+/// 
+/// **FrameAccumulator** provides the same framing as [`DynReadBuffer`]
+/// for data which doesn't come from a [Read][std::io::Read],
+/// for example data handed over by a callback.
+/// Data is added using [`feed`] and taken out using [`next_until`] or [`next_bytes`],
+/// which return [None] until enough data has been fed to complete a frame.
+/// As with [`DynReadBuffer`], only data which was fed
+/// and hasn't been taken out yet is ever exposed.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`feed`]: FrameAccumulator::feed
+/// [`next_until`]: FrameAccumulator::next_until
+/// [`next_bytes`]: FrameAccumulator::next_bytes
+pub struct FrameAccumulator {
+	buffer: Buffer,
+}
+
+impl FrameAccumulator {
+	/// Creates a new empty **FrameAccumulator**.
+	pub fn new() -> Self {
+		Self {
+			buffer: Buffer::new(),
+		}
+	}
+	
+	/// Creates a new empty **FrameAccumulator**
+	/// with an internal buffer of at least the specified capacity.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			buffer: Buffer::with_capacity(capacity),
+		}
+	}
+	
+	/// Appends the given data to the internal buffer.
+	pub fn feed(&mut self, data: &[u8]) {
+		self.buffer.extend_from_slice(data);
+	}
+	
+	/// Returns a slice referencing the fed data up to and including
+	/// the first occurrence of the specified delimiter,
+	/// or [None] if the delimiter hasn't been fed yet.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use read_buffer::FrameAccumulator;
+	/// 
+	/// let mut accumulator = FrameAccumulator::new();
+	/// 
+	/// accumulator.feed(b"hello ");
+	/// assert_eq!(accumulator.next_until(b'\n'), None);
+	/// 
+	/// accumulator.feed(b"world\nand more");
+	/// assert_eq!(accumulator.next_until(b'\n'), Some(b"hello world\n".as_slice()));
+	/// ```
+	pub fn next_until(&mut self, delimiter: u8) -> Option<&[u8]> {
+		let position = self.buffer.find(delimiter, 0)?;
+		Some(self.buffer.take(position + 1))
+	}
+	
+	/// Returns a slice referencing the specified amount of fed bytes,
+	/// or [None] if not enough data has been fed yet.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use read_buffer::FrameAccumulator;
+	/// 
+	/// let mut accumulator = FrameAccumulator::new();
+	/// 
+	/// accumulator.feed(&[1, 2]);
+	/// assert_eq!(accumulator.next_bytes(3), None);
+	/// 
+	/// accumulator.feed(&[3, 4]);
+	/// assert_eq!(accumulator.next_bytes(3), Some([1, 2, 3].as_slice()));
+	/// ```
+	pub fn next_bytes(&mut self, amount: usize) -> Option<&[u8]> {
+		if amount > self.buffer.len() {
+			return None;
+		}
+		
+		Some(self.buffer.take(amount))
+	}
+	
+	/// Returns the amount of fed bytes which haven't been returned yet.
+	pub fn len(&self) -> usize {
+		self.buffer.len()
+	}
+	
+	/// Returns `true` if all fed data has been returned.
+	pub fn is_empty(&self) -> bool {
+		self.buffer.is_empty()
+	}
+}
+
+impl Default for FrameAccumulator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
\ No newline at end of file
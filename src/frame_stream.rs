@@ -0,0 +1,148 @@
+use std::io::{self, ErrorKind};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use futures_core::Stream;
+use futures_io::AsyncRead;
+use crate::{AsyncDynReadBuffer, LineTooLong};
+
+/// A [Stream] of owned frames separated by a delimiter,
+/// created using [`into_frame_stream`].
+/// 
+/// Frames are returned the same way as by [`Records`]: reaching the "end of file"
+/// isn't an error, the data after the last delimiter is returned as a final frame
+/// if there is any, after which the stream ends.
+/// 
+/// Requires the `futures-io` feature.
+/// 
+/// [`into_frame_stream`]: AsyncDynReadBuffer::into_frame_stream
+/// [`Records`]: crate::Records
+pub struct FrameStream<R: AsyncRead + Unpin> {
+	buffer: AsyncDynReadBuffer<R>,
+	delimiter: u8,
+	high_watermark: Option<usize>,
+	searched_length: usize,
+	finished: bool,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDynReadBuffer<R> {
+	/// Turns the **AsyncDynReadBuffer** into a [FrameStream]
+	/// returning the data in frames ending with the specified delimiter.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # async fn example() -> Result<(), std::io::Error> {
+	/// use std::future::poll_fn;
+	/// use std::pin::Pin;
+	/// use futures_core::Stream;
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// let reader = "first\nsecond\nlast".as_bytes();
+	/// let mut frames = AsyncDynReadBuffer::new(reader).into_frame_stream(b'\n');
+	/// let mut count = 0;
+	/// 
+	/// while let Some(frame) = poll_fn(|cx| Pin::new(&mut frames).poll_next(cx)).await {
+	///     println!("{:?}", frame?);
+	///     count += 1;
+	/// }
+	/// 
+	/// assert_eq!(count, 3);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn into_frame_stream(self, delimiter: u8) -> FrameStream<R> {
+		FrameStream {
+			buffer: self,
+			delimiter,
+			high_watermark: None,
+			searched_length: 0,
+			finished: false,
+		}
+	}
+}
+
+impl<R: AsyncRead + Unpin> FrameStream<R> {
+	/// Returns the maximum amount of undecoded data which is buffered,
+	/// as set by [`set_high_watermark`].
+	/// 
+	/// [`set_high_watermark`]: FrameStream::set_high_watermark
+	pub fn high_watermark(&self) -> Option<usize> {
+		self.high_watermark
+	}
+	
+	/// Sets the maximum amount of undecoded data which is buffered.
+	/// 
+	/// Data is only read from the given [AsyncRead] while the next frame is polled,
+	/// and each read is limited so no more than `high_watermark` bytes
+	/// are buffered which haven't been returned as a frame yet.
+	/// A slow consumer therefore leaves the data in the [AsyncRead],
+	/// exerting backpressure on the sender instead of growing the buffer.
+	/// 
+	/// If a single frame is longer than `high_watermark` bytes,
+	/// an error of the kind [ErrorKind::InvalidData][`InvalidData`]
+	/// wrapping [LineTooLong] is returned.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if `high_watermark` is zero.
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	pub fn set_high_watermark(&mut self, high_watermark: usize) {
+		assert!(high_watermark > 0, "high watermark must be at least one byte");
+		self.high_watermark = Some(high_watermark);
+	}
+	
+	/// Returns the underlying [`AsyncDynReadBuffer`],
+	/// which still holds all data that hasn't been returned as a frame.
+	pub fn into_inner(self) -> AsyncDynReadBuffer<R> {
+		self.buffer
+	}
+}
+
+impl<R: AsyncRead + Unpin> Stream for FrameStream<R> {
+	type Item = Result<Vec<u8>, io::Error>;
+	
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = &mut *self;
+		
+		if this.finished {
+			return Poll::Ready(None);
+		}
+		
+		loop {
+			let buffer = &mut this.buffer.buffer;
+			
+			if let Some(position) = buffer.find(this.delimiter, this.searched_length) {
+				this.searched_length = 0;
+				return Poll::Ready(Some(Ok(buffer.take(position + 1).to_vec())));
+			}
+			
+			this.searched_length = buffer.len();
+			let mut max_amount = buffer.read_reservation();
+			
+			if let Some(high_watermark) = this.high_watermark {
+				if buffer.len() >= high_watermark {
+					this.finished = true;
+					let error = LineTooLong {
+						max_length: high_watermark,
+					};
+					return Poll::Ready(Some(Err(io::Error::new(ErrorKind::InvalidData, error))));
+				}
+				
+				max_amount = max_amount.min(high_watermark - buffer.len());
+			}
+			
+			buffer.reserve(max_amount);
+			
+			if ready!(this.buffer.poll_read_into_buffer_at_most(cx, max_amount))? == 0 {
+				this.finished = true;
+				let buffer = &mut this.buffer.buffer;
+				
+				return match buffer.len() {
+					0 => Poll::Ready(None),
+					length => Poll::Ready(Some(Ok(buffer.take(length).to_vec()))),
+				};
+			}
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use crate::{ByteSource, DynReadBuffer};
+
+/// How the internal buffer of a [`DynReadBuffer`] grows
+/// once it's too small, set using [`set_growth_policy`].
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`set_growth_policy`]: DynReadBuffer::set_growth_policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GrowthPolicy {
+	/// Grows to exactly the required capacity,
+	/// keeping memory usage low at the cost of growing more often.
+	Exact,
+	/// Grows to at least double the previous capacity,
+	/// so growing the buffer takes amortized constant time.
+	/// This is the default.
+	Amortized,
+	/// Grows to the required capacity rounded up to a multiple
+	/// of the specified amount of bytes.
+	/// A chunk size of 0 is treated as [GrowthPolicy::Exact].
+	Chunked(usize),
+}
+
+/// When the internal buffer of a [`DynReadBuffer`] shrinks on its own,
+/// set using [`set_shrink_policy`].
+/// 
+/// Once the capacity is larger than `high`, the buffer shrinks to `low`
+/// after `operations` frames in a row have been read while no more than
+/// `low` bytes were buffered. This way a long-lived buffer which once
+/// had to hold a very large frame eventually returns the memory.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`set_shrink_policy`]: DynReadBuffer::set_shrink_policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShrinkPolicy {
+	/// The capacity above which the buffer may shrink.
+	pub high: usize,
+	/// The capacity the buffer shrinks to.
+	pub low: usize,
+	/// The amount of frames read in a row with little buffered data
+	/// before the buffer shrinks.
+	pub operations: u32,
+}
+
+impl GrowthPolicy {
+	/// The capacity to grow a buffer of the given capacity to
+	/// so it can hold at least the required amount of bytes.
+	pub(crate) fn grown_capacity(self, capacity: usize, required: usize) -> usize {
+		match self {
+			GrowthPolicy::Exact | GrowthPolicy::Chunked(0) => required,
+			GrowthPolicy::Amortized => required.max(capacity.saturating_mul(2)),
+			GrowthPolicy::Chunked(chunk_size) => required.checked_next_multiple_of(chunk_size).unwrap_or(required),
+		}
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Returns how the internal buffer grows once it's too small.
+	pub fn growth_policy(&self) -> GrowthPolicy {
+		self.buffer.growth_policy()
+	}
+	
+	/// Sets how the internal buffer grows once it's too small,
+	/// which by default is [GrowthPolicy::Amortized].
+	/// 
+	/// Memory constrained programs with many buffers may prefer
+	/// [GrowthPolicy::Exact] or [GrowthPolicy::Chunked],
+	/// while parsers which need high throughput
+	/// benefit from growing the buffer less often.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{DynReadBuffer, GrowthPolicy};
+	/// 
+	/// let reader = [0; 100].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// buffer.set_growth_policy(GrowthPolicy::Chunked(4096));
+	/// 
+	/// assert_eq!(buffer.read_bytes(100)?.len(), 100);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn set_growth_policy(&mut self, growth_policy: GrowthPolicy) {
+		self.buffer.set_growth_policy(growth_policy);
+	}
+	
+	/// Returns when the internal buffer shrinks on its own, if at all.
+	pub fn shrink_policy(&self) -> Option<ShrinkPolicy> {
+		self.buffer.shrink_policy()
+	}
+	
+	/// Sets when the internal buffer shrinks on its own,
+	/// or disables shrinking with [None], which is the default.
+	/// 
+	/// The buffer is only shrunk before reading a frame,
+	/// so it never invalidates previously returned data.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{DynReadBuffer, ShrinkPolicy};
+	/// 
+	/// let reader = [0; 100].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// buffer.set_shrink_policy(Some(ShrinkPolicy {
+	///     high: 1024 * 1024,
+	///     low: 64 * 1024,
+	///     operations: 100,
+	/// }));
+	/// 
+	/// assert_eq!(buffer.read_bytes(100)?.len(), 100);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn set_shrink_policy(&mut self, shrink_policy: Option<ShrinkPolicy>) {
+		self.buffer.set_shrink_policy(shrink_policy);
+	}
+	
+	/// Shrinks the internal buffer to the specified capacity,
+	/// or as close to it as possible while keeping the buffered data
+	/// as well as the data kept for a [`mark`].
+	/// 
+	/// Consumed data which could otherwise still be restored,
+	/// like the data after a [`Checkpoint`], may be discarded.
+	/// 
+	/// [`mark`]: DynReadBuffer::mark
+	/// [`Checkpoint`]: crate::Checkpoint
+	pub fn shrink_to(&mut self, capacity: usize) {
+		self.buffer.shrink_to(capacity);
+	}
+}
\ No newline at end of file
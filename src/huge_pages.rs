@@ -0,0 +1,163 @@
+use std::alloc::Layout;
+use std::fs;
+use std::ptr::{self, NonNull};
+use std::sync::OnceLock;
+use allocator_api2::alloc::{AllocError, Allocator};
+use crate::{ByteSource, DynReadBuffer};
+
+const DEFAULT_HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+/// The kind of huge pages used by [`DynReadBuffer::with_huge_pages`].
+/// 
+/// Requires the `huge-pages` feature and is only available on Linux.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HugePages {
+	/// Transparent huge pages, which the kernel uses for the internal buffer
+	/// whenever possible, as requested using `madvise(MADV_HUGEPAGE)`.
+	/// Unlike explicit huge pages, they don't need to be reserved in advance.
+	Transparent,
+	/// Explicit huge pages from the pool reserved through `vm.nr_hugepages`,
+	/// allocated using `MAP_HUGETLB`.
+	/// If no huge pages are available, transparent huge pages are used instead.
+	Explicit,
+}
+
+impl<R: ByteSource> DynReadBuffer<R> {
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// whose internal buffer of at least the specified capacity
+	/// is backed by huge pages.
+	/// 
+	/// This reduces TLB pressure when streaming through buffers
+	/// of multiple megabytes. As the internal buffer is always
+	/// a whole number of huge pages, it is not useful for small buffers.
+	/// 
+	/// Requires the `huge-pages` feature and is only available on Linux.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{DynReadBuffer, HugePages};
+	/// 
+	/// let reader = [1, 2, 3].as_slice();
+	/// let mut buffer = DynReadBuffer::with_huge_pages(reader, 8 * 1024 * 1024, HugePages::Transparent);
+	/// 
+	/// assert_eq!(buffer.read_bytes(2)?, [1, 2]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_huge_pages(reader: R, capacity: usize, huge_pages: HugePages) -> Self {
+		Self::with_capacity_in(reader, capacity, HugePageAllocator(huge_pages))
+	}
+}
+
+/// An allocator which maps whole huge pages for each allocation.
+struct HugePageAllocator(HugePages);
+
+impl HugePageAllocator {
+	fn mapping_size(layout: Layout) -> Result<usize, AllocError> {
+		if layout.align() > huge_page_size() {
+			return Err(AllocError);
+		}
+		
+		layout.size().checked_next_multiple_of(huge_page_size()).ok_or(AllocError)
+	}
+	
+	fn map_explicit(size: usize) -> Option<NonNull<u8>> {
+		let flags = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_HUGETLB;
+		
+		// SAFETY: an anonymous mapping doesn't alias any existing memory
+		let memory = unsafe {
+			libc::mmap(ptr::null_mut(), size, libc::PROT_READ | libc::PROT_WRITE, flags, -1, 0)
+		};
+		
+		match memory {
+			libc::MAP_FAILED => None,
+			memory => NonNull::new(memory.cast()),
+		}
+	}
+	
+	fn map_transparent(size: usize) -> Option<NonNull<u8>> {
+		// map an additional huge page so the mapping can be aligned to a huge page,
+		// which is needed for the kernel to back it with huge pages
+		let page_size = huge_page_size();
+		let mapped_size = size.checked_add(page_size)?;
+		let flags = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS;
+		
+		// SAFETY: an anonymous mapping doesn't alias any existing memory
+		let memory = unsafe {
+			libc::mmap(ptr::null_mut(), mapped_size, libc::PROT_READ | libc::PROT_WRITE, flags, -1, 0)
+		};
+		
+		if memory == libc::MAP_FAILED {
+			return None;
+		}
+		
+		let memory = memory.cast::<u8>();
+		let offset = memory.align_offset(page_size);
+		
+		// SAFETY: the unaligned start and the remaining end lie within the mapping
+		// and are unmapped in whole pages, leaving exactly the aligned range
+		unsafe {
+			let start = memory.add(offset);
+			
+			if offset > 0 {
+				libc::munmap(memory.cast(), offset);
+			}
+			
+			if page_size - offset > 0 {
+				libc::munmap(start.add(size).cast(), page_size - offset);
+			}
+			
+			libc::madvise(start.cast(), size, libc::MADV_HUGEPAGE);
+			NonNull::new(start)
+		}
+	}
+}
+
+// SAFETY: all memory is mapped and unmapped in whole huge pages,
+// using the same size derived from the requested layout
+unsafe impl Allocator for HugePageAllocator {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		let size = Self::mapping_size(layout)?;
+		
+		if size == 0 {
+			let dangling = NonNull::new(layout.align() as *mut u8).ok_or(AllocError)?;
+			return Ok(NonNull::slice_from_raw_parts(dangling, 0));
+		}
+		
+		let memory = match self.0 {
+			HugePages::Explicit => Self::map_explicit(size).or_else(|| Self::map_transparent(size)),
+			HugePages::Transparent => Self::map_transparent(size),
+		};
+		
+		memory
+			.map(|memory| NonNull::slice_from_raw_parts(memory, size))
+			.ok_or(AllocError)
+	}
+	
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		let size = Self::mapping_size(layout)
+			.expect("layout was already checked when allocating");
+		
+		if size > 0 {
+			libc::munmap(ptr.as_ptr().cast(), size);
+		}
+	}
+}
+
+fn huge_page_size() -> usize {
+	static HUGE_PAGE_SIZE: OnceLock<usize> = OnceLock::new();
+	
+	*HUGE_PAGE_SIZE.get_or_init(|| {
+		let Ok(meminfo) = fs::read_to_string("/proc/meminfo") else {
+			return DEFAULT_HUGE_PAGE_SIZE;
+		};
+		
+		meminfo.lines()
+			.find_map(|line| line.strip_prefix("Hugepagesize:"))
+			.and_then(|size| size.trim().strip_suffix("kB"))
+			.and_then(|size| size.trim().parse::<usize>().ok())
+			.map_or(DEFAULT_HUGE_PAGE_SIZE, |size| size * 1024)
+	})
+}
\ No newline at end of file
@@ -0,0 +1,164 @@
+use std::io::{self, ErrorKind};
+use crate::buffer::Buffer;
+use crate::ByteSource;
+
+/// A buffer to read into from a [ByteSource] and safely access the read data,
+/// which stores up to `N` bytes inline like a [`ReadBuffer`]
+/// but moves to the heap once a frame doesn't fit anymore.
+/// 
+/// Like with [`ReadBuffer`], the [ByteSource] is passed to every method,
+/// and data read past the end of a frame is carried over to the following reads.
+/// Unlike [`ReadBuffer`], frames may be larger than `N`, in which case
+/// the internal buffer is moved to the heap, where it stays from then on.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use read_buffer::HybridReadBuffer;
+/// 
+/// let long_line = "a".repeat(100) + "\n";
+/// let data = format!("short\n{long_line}");
+/// let mut reader = data.as_bytes();
+/// let mut buffer: HybridReadBuffer<16> = HybridReadBuffer::new();
+/// 
+/// assert_eq!(buffer.read_until(&mut reader, b'\n')?, b"short\n");
+/// assert_eq!(buffer.read_until(&mut reader, b'\n')?, long_line.as_bytes());
+/// # Ok(())
+/// # }
+/// ```
+/// 
+/// [`ReadBuffer`]: crate::ReadBuffer
+pub struct HybridReadBuffer<const N: usize> {
+	buffer: Buffer<N>,
+}
+
+impl<const N: usize> HybridReadBuffer<N> {
+	/// Creates a new **HybridReadBuffer**.
+	pub fn new() -> Self {
+		Self {
+			buffer: Buffer::new(),
+		}
+	}
+	
+	/// Returns the carried over data if there is any, otherwise reads
+	/// from the given [ByteSource] into the internal buffer once
+	/// and returns a slice referencing the read data.
+	/// 
+	/// If the length of the returned slice is `0`,
+	/// this indicates that the reader has reached its "end of file"
+	/// as specified for [ByteSource::read_into].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors from [ByteSource::read_into] are passed on to the caller.
+	/// Besides those, this method does not return any errors.
+	pub fn read_from(&mut self, source: &mut impl ByteSource) -> Result<&[u8], io::Error> {
+		if self.buffer.is_empty() {
+			self.reserve_for_read();
+			self.buffer.read_from(source, usize::MAX)?;
+		}
+		
+		let length = self.buffer.len();
+		Ok(self.buffer.take(length))
+	}
+	
+	/// Reads the specified amount of bytes from the given [ByteSource]
+	/// and returns a slice referencing them,
+	/// moving the internal buffer to the heap if they don't fit inline.
+	/// 
+	/// # Errors
+	/// 
+	/// If the [ByteSource] reaches its "end of file" before enough bytes could be read,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// In any case, the data read so far is carried over to the following reads.
+	/// 
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_bytes(&mut self, source: &mut impl ByteSource, amount: usize) -> Result<&[u8], io::Error> {
+		if amount > self.buffer.len() {
+			self.buffer.reserve_for_read(amount - self.buffer.len());
+		}
+		
+		while amount > self.buffer.len() {
+			if self.read_once(source, amount - self.buffer.len())? == 0 {
+				return Err(ErrorKind::UnexpectedEof.into());
+			}
+		}
+		
+		Ok(self.buffer.take(amount))
+	}
+	
+	/// Reads from the given [ByteSource] until the delimiter is found
+	/// and returns a slice referencing the data up to and including the delimiter,
+	/// moving the internal buffer to the heap if it doesn't fit inline.
+	/// 
+	/// # Errors
+	/// 
+	/// If the [ByteSource] reaches its "end of file" before the delimiter is found,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// In any case, the data read so far is carried over to the following reads.
+	/// 
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_until(&mut self, source: &mut impl ByteSource, delimiter: u8) -> Result<&[u8], io::Error> {
+		let mut searched_length = 0;
+		
+		loop {
+			if let Some(position) = self.buffer.find(delimiter, searched_length) {
+				return Ok(self.buffer.take(position + 1));
+			}
+			
+			searched_length = self.buffer.len();
+			
+			self.reserve_for_read();
+			
+			if self.read_once(source, usize::MAX)? == 0 {
+				return Err(ErrorKind::UnexpectedEof.into());
+			}
+		}
+	}
+	
+	/// Returns the amount of bytes the internal buffer can hold without growing,
+	/// which is `N` until it is moved to the heap.
+	pub fn capacity(&self) -> usize {
+		self.buffer.capacity()
+	}
+	
+	/// Makes space to read into, using all of the inline space
+	/// before moving to the heap, then at least doubling the space with each growth.
+	fn reserve_for_read(&mut self) {
+		let length = self.buffer.len();
+		let amount = match N.checked_sub(length) {
+			Some(free) if free > 0 => free,
+			_ => length.max(32),
+		};
+		
+		self.buffer.reserve_for_read(amount);
+	}
+	
+	fn read_once(&mut self, source: &mut impl ByteSource, max_amount: usize) -> Result<usize, io::Error> {
+		loop {
+			match self.buffer.read_from(source, max_amount) {
+				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+				result => return result,
+			}
+		}
+	}
+}
+
+impl<const N: usize> Default for HybridReadBuffer<N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
\ No newline at end of file
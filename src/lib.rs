@@ -82,13 +82,179 @@
 //! # }
 //! ```
 //! 
+//! # Event loops
+//! 
+//! [DynReadBuffer] can be used in readiness-based event loops, like ones built
+//! on the `polling` or `mio` crates, with the source in non-blocking mode:
+//! 
+//! 1. Register the source, which stays reachable through [`DynReadBuffer::get_ref`].
+//! 2. When the source is readable, perform a single read using [`DynReadBuffer::fill_once`].
+//!    An error of the kind [ErrorKind::WouldBlock][`WouldBlock`] just means
+//!    there was nothing to read after all.
+//! 3. Extract all complete frames using methods which never read,
+//!    like [`DynReadBuffer::next_until`], [`DynReadBuffer::next_bytes`]
+//!    and [`DynReadBuffer::drain_buffered`].
+//! 4. Wait for the source to be readable again. Incomplete frames stay buffered
+//!    and are completed by the following reads.
+//! 
+//! [`DynReadBuffer::read_available_frames`] combines the second and third step.
+//! A complete example using the `polling` crate can be found in
+//! `examples/polling.rs` in the repository.
+//! 
+//! # Errors
+//! 
+//! Errors specific to this crate, like [Truncated] or [QuotaExceeded], are wrapped
+//! in an [`io::Error`] and can be retrieved using [`get_ref`] and [`downcast_ref`].
+//! 
+//! # WebAssembly
+//! 
+//! All buffer types work on `wasm32-wasip1` as well as `wasm32-unknown-unknown`.
+//! The few features which rely on background threads, like [PrefetchReader]
+//! and [`DynReadBuffer::spawn_frames`], panic on targets without support for threads.
+//! 
+//! With the `web` feature, a `ReadableStreamReader` allows using an `AsyncDynReadBuffer`
+//! on the JavaScript `ReadableStream`s of the browser on `wasm32-unknown-unknown`.
+//! 
 //! [`Read`]: std::io::Read
 //! [`Read_read`]: std::io::Read::read
+//! [`io::Error`]: std::io::Error
+//! [`get_ref`]: std::io::Error::get_ref
+//! [`downcast_ref`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.downcast_ref
+//! [`WouldBlock`]: std::io::ErrorKind::WouldBlock
 
 #![deny(missing_docs)]
+#![cfg_attr(feature = "nightly", feature(read_buf, core_io_borrowed_buf, borrowed_buf_init))]
+#![cfg_attr(all(feature = "nightly", feature = "allocator-api2"), feature(allocator_api))]
 
+mod buffer;
+mod source;
+mod debug;
+mod alignment;
+mod error;
 mod read_buffer;
 mod dyn_read_buffer;
+mod hybrid_read_buffer;
+mod sync_dyn_read_buffer;
+mod frame_accumulator;
+mod endianness;
+mod primitives;
+mod delimited_message;
+mod parse;
+mod nonblocking;
+mod timeout;
+mod scope;
+mod progress;
+mod observer;
+mod replay;
+mod spare_capacity;
+mod prefetch;
+mod seek;
+mod mark;
+mod transaction;
+mod checkpoint;
+mod chunks_exact;
+mod streamed;
+mod split;
+mod records;
+mod token;
+mod ascii_number;
+mod cstr;
+mod sequence;
+mod limited;
+mod located;
+mod ext;
+mod feed;
+mod capture;
+mod pool;
+mod spawn;
+mod peek;
+mod datagram;
+mod growth;
+#[cfg(all(feature = "io-uring", target_os = "linux"))]
+mod uring;
+#[cfg(any(unix, windows))]
+mod read_at;
+#[cfg(feature = "fadvise")]
+mod fadvise;
+#[cfg(all(feature = "huge-pages", target_os = "linux"))]
+mod huge_pages;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "allocator-api2")]
+mod allocator;
+#[cfg(feature = "bytemuck")]
+mod typed;
+#[cfg(feature = "tokio-util")]
+mod codec;
+#[cfg(feature = "regex")]
+mod regex;
+#[cfg(all(feature = "secret", any(unix, windows)))]
+mod secret;
+#[cfg(feature = "futures-io")]
+mod async_read_buffer;
+#[cfg(feature = "futures-io")]
+mod frame_stream;
+#[cfg(feature = "futures-io")]
+mod lines_stream;
+#[cfg(feature = "thread-local-pool")]
+mod local_pool;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(all(feature = "web", target_arch = "wasm32", target_os = "unknown"))]
+mod web;
+#[cfg(feature = "serde")]
+mod deserialize;
 
-pub use self::read_buffer::ReadBuffer;
-pub use self::dyn_read_buffer::DynReadBuffer;
\ No newline at end of file
+pub use self::source::ByteSource;
+pub use self::read_buffer::{ReadBuffer, ReadOutcome};
+pub use self::alignment::{Alignment, Align1, Align2, Align4, Align8, Align16, Align32, Align64, Align128, Align256, Align512, Align1024, Align2048, Align4096};
+pub use self::dyn_read_buffer::DynReadBuffer;
+pub use self::hybrid_read_buffer::HybridReadBuffer;
+pub use self::sync_dyn_read_buffer::SyncDynReadBuffer;
+pub use self::frame_accumulator::FrameAccumulator;
+pub use self::endianness::Endianness;
+pub use self::parse::Incomplete;
+pub use self::timeout::TimedRead;
+pub use self::peek::PeekRead;
+pub use self::datagram::{DatagramReadBuffer, RecvDatagram};
+pub use self::scope::Scope;
+pub use self::transaction::Transaction;
+pub use self::checkpoint::Checkpoint;
+pub use self::chunks_exact::ChunksExact;
+pub use self::records::{Records, Framed, IntoFrames};
+pub use self::located::Located;
+pub use self::ext::ReadBufferExt;
+pub use self::feed::{FeedReader, Feeder};
+pub use self::pool::BufferPool;
+pub use self::growth::{GrowthPolicy, ShrinkPolicy};
+pub use self::observer::ReadObserver;
+pub use self::replay::{RecordingReader, ReplayReader};
+pub use self::prefetch::PrefetchReader;
+pub use self::spare_capacity::PendingFill;
+#[cfg(all(feature = "io-uring", target_os = "linux"))]
+pub use self::uring::{UringReader, UringReadBuffer};
+pub use self::error::{QuotaExceeded, BufferFull, CheckpointExpired, LineTooLong, TooLong, Truncated};
+#[cfg(any(unix, windows))]
+pub use self::read_at::{OffsetReader, ReadAtBuffer};
+#[cfg(feature = "fadvise")]
+pub use self::fadvise::{SequentialFile, SequentialReadBuffer};
+#[cfg(all(feature = "huge-pages", target_os = "linux"))]
+pub use self::huge_pages::HugePages;
+#[cfg(feature = "futures-io")]
+pub use self::async_read_buffer::AsyncDynReadBuffer;
+#[cfg(feature = "futures-io")]
+pub use self::frame_stream::FrameStream;
+#[cfg(feature = "futures-io")]
+pub use self::lines_stream::LinesStream;
+#[cfg(all(feature = "web", target_arch = "wasm32", target_os = "unknown"))]
+pub use self::web::ReadableStreamReader;
+#[cfg(all(feature = "secret", any(unix, windows)))]
+pub use self::secret::SecretReadBuffer;
+#[cfg(feature = "tokio-util")]
+pub use self::codec::{DelimiterCodec, DelimitedMessageCodec};
+#[cfg(feature = "serde")]
+pub use self::deserialize::FrameFormat;
+#[cfg(feature = "serde_json")]
+pub use self::deserialize::Json;
+#[cfg(feature = "postcard")]
+pub use self::deserialize::Postcard;
\ No newline at end of file
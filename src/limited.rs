@@ -0,0 +1,159 @@
+use std::io::{self, ErrorKind};
+use crate::{ByteSource, DynReadBuffer, LineTooLong, TooLong};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Like [`read_until`], but fails if the delimiter isn't found within
+	/// the first `max_length` bytes, protecting against unbounded lines.
+	/// 
+	/// The returned slice, including the delimiter, is at most `max_length` bytes long
+	/// and no more than `max_length` bytes are buffered while searching for it.
+	/// 
+	/// # Errors
+	/// 
+	/// If the delimiter isn't found within the first `max_length` bytes,
+	/// an error of the kind [ErrorKind::InvalidData][`InvalidData`]
+	/// wrapping [LineTooLong] is returned. The data stays buffered,
+	/// so the rest of the line can be skipped using [`skip_until`].
+	/// 
+	/// All other errors are handled the same way as in [`read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{DynReadBuffer, LineTooLong};
+	/// 
+	/// let reader = "short\nway too long\nok\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_until_limited(b'\n', 8)?, b"short\n");
+	/// 
+	/// let error = buffer.read_until_limited(b'\n', 8).unwrap_err();
+	/// assert!(error.get_ref().unwrap().is::<LineTooLong>());
+	/// buffer.skip_until(b'\n')?;
+	/// 
+	/// assert_eq!(buffer.read_until_limited(b'\n', 8)?, b"ok\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	/// [`skip_until`]: DynReadBuffer::skip_until
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	pub fn read_until_limited(&mut self, delimiter: u8, max_length: usize) -> Result<&[u8], io::Error> {
+		let mut searched_length = 0;
+		
+		loop {
+			let searchable_length = self.buffer.len().min(max_length);
+			let position = self.buffer.filled()[searched_length..searchable_length].iter()
+				.position(|byte| *byte == delimiter);
+			
+			if let Some(position) = position {
+				return Ok(self.take(searched_length + position + 1));
+			}
+			
+			if searchable_length == max_length {
+				return Err(io::Error::new(ErrorKind::InvalidData, LineTooLong { max_length }));
+			}
+			
+			searched_length = searchable_length;
+			
+			let remaining = max_length - searched_length;
+			self.reserve_for_read(remaining.min(self.buffer.read_reservation()));
+			
+			if self.read_into_buffer(remaining, &mut |_| Ok(()))? == 0 {
+				return Err(self.unexpected_eof("read_until_limited", None));
+			}
+		}
+	}
+	
+	/// Reads all data until the given [ByteSource] reaches its "end of file"
+	/// and returns a slice referencing it, failing if there are
+	/// more than `max_length` bytes.
+	/// 
+	/// No more than `max_length + 1` bytes are buffered,
+	/// so this can safely be used to read a whole message body
+	/// from an untrusted source.
+	/// 
+	/// # Errors
+	/// 
+	/// If there are more than `max_length` bytes, an error of the kind
+	/// [ErrorKind::InvalidData][`InvalidData`] wrapping [TooLong] is returned.
+	/// The data read so far stays buffered.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{DynReadBuffer, TooLong};
+	/// 
+	/// let reader = "a short body".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// assert_eq!(buffer.read_to_end_limited(16)?, b"a short body");
+	/// 
+	/// let reader = "a much longer body".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let error = buffer.read_to_end_limited(16).unwrap_err();
+	/// assert!(error.get_ref().unwrap().is::<TooLong>());
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_to_end_limited(&mut self, max_length: usize) -> Result<&[u8], io::Error> {
+		let limit = max_length.saturating_add(1);
+		
+		while self.buffer.len() < limit {
+			let remaining = limit - self.buffer.len();
+			self.reserve_for_read(remaining.min(self.buffer.read_reservation()));
+			
+			if self.read_into_buffer(remaining, &mut |_| Ok(()))? == 0 {
+				return Ok(self.take(self.buffer.len()));
+			}
+		}
+		
+		Err(io::Error::new(ErrorKind::InvalidData, TooLong { max_length }))
+	}
+	
+	/// Discards data up to and including the next occurrence of the delimiter
+	/// and returns the amount of bytes discarded, without buffering all of them at once.
+	/// 
+	/// # Errors
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before the delimiter is found,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned
+	/// and all data has been discarded.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// The data discarded so far stays discarded.
+	/// 
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn skip_until(&mut self, delimiter: u8) -> Result<u64, io::Error> {
+		let mut skipped_length = 0;
+		
+		loop {
+			if let Some(position) = self.buffer.find(delimiter, 0) {
+				self.buffer.consume(position + 1);
+				return Ok(skipped_length + position as u64 + 1);
+			}
+			
+			let length = self.buffer.len();
+			self.buffer.consume(length);
+			skipped_length += length as u64;
+			
+			if self.fill_buffer()? == 0 {
+				return Err(self.unexpected_eof("skip_until", None));
+			}
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+use std::io::{self, ErrorKind};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use futures_core::Stream;
+use futures_io::AsyncRead;
+use crate::{AsyncDynReadBuffer, FrameStream, LineTooLong};
+
+/// A [Stream] of the lines of an [AsyncRead] as [String]s,
+/// created using [`lines_stream`].
+/// 
+/// Lines end with either `\n` or `\r\n`, which isn't included in the returned line.
+/// The data after the last line ending is returned as a final line if there is any,
+/// after which the stream ends.
+/// 
+/// Requires the `futures-io` feature.
+/// 
+/// [`lines_stream`]: AsyncDynReadBuffer::lines_stream
+pub struct LinesStream<R: AsyncRead + Unpin> {
+	frames: FrameStream<R>,
+	max_length: usize,
+	finished: bool,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDynReadBuffer<R> {
+	/// Turns the **AsyncDynReadBuffer** into a [LinesStream]
+	/// returning the data line by line, with lines of at most `max_length` bytes.
+	/// 
+	/// No more than `max_length + 2` bytes are buffered at once,
+	/// so this can safely be used for line-based protocols like IRC
+	/// with an untrusted peer.
+	/// 
+	/// # Errors
+	/// 
+	/// If a line is longer than `max_length` bytes, not counting the line ending,
+	/// an error of the kind [ErrorKind::InvalidData][`InvalidData`]
+	/// wrapping [LineTooLong] is returned, after which the stream ends.
+	/// 
+	/// If a line isn't valid UTF-8, an error of the kind
+	/// [ErrorKind::InvalidData][`InvalidData`] is returned
+	/// and the stream continues with the next line.
+	/// 
+	/// All other errors are handled the same way as in [FrameStream].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # async fn example() -> Result<(), std::io::Error> {
+	/// use std::future::poll_fn;
+	/// use std::pin::Pin;
+	/// use futures_core::Stream;
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// let reader = "NICK guest\r\nJOIN #rust\r\n".as_bytes();
+	/// let mut lines = AsyncDynReadBuffer::new(reader).lines_stream(512);
+	/// 
+	/// while let Some(line) = poll_fn(|cx| Pin::new(&mut lines).poll_next(cx)).await {
+	///     println!("{}", line?);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	pub fn lines_stream(self, max_length: usize) -> LinesStream<R> {
+		let mut frames = self.into_frame_stream(b'\n');
+		frames.set_high_watermark(max_length.saturating_add(2));
+		
+		LinesStream {
+			frames,
+			max_length,
+			finished: false,
+		}
+	}
+}
+
+impl<R: AsyncRead + Unpin> LinesStream<R> {
+	/// Returns the underlying [`AsyncDynReadBuffer`],
+	/// which still holds all data that hasn't been returned as a line.
+	pub fn into_inner(self) -> AsyncDynReadBuffer<R> {
+		self.frames.into_inner()
+	}
+}
+
+impl<R: AsyncRead + Unpin> Stream for LinesStream<R> {
+	type Item = Result<String, io::Error>;
+	
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let max_length = self.max_length;
+		let too_long = || io::Error::new(ErrorKind::InvalidData, LineTooLong { max_length });
+		
+		if self.finished {
+			return Poll::Ready(None);
+		}
+		
+		let mut line = match ready!(Pin::new(&mut self.frames).poll_next(cx)) {
+			Some(Ok(line)) => line,
+			Some(Err(err)) if err.get_ref().is_some_and(|err| err.is::<LineTooLong>()) => {
+				self.finished = true;
+				return Poll::Ready(Some(Err(too_long())));
+			},
+			Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+			None => return Poll::Ready(None),
+		};
+		
+		if line.last() == Some(&b'\n') {
+			line.pop();
+			
+			if line.last() == Some(&b'\r') {
+				line.pop();
+			}
+		}
+		
+		if line.len() > max_length {
+			self.finished = true;
+			return Poll::Ready(Some(Err(too_long())));
+		}
+		
+		let line = String::from_utf8(line)
+			.map_err(|err| io::Error::new(ErrorKind::InvalidData, err.utf8_error()));
+		
+		Poll::Ready(Some(line))
+	}
+}
\ No newline at end of file
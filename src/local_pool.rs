@@ -0,0 +1,65 @@
+use std::cell::RefCell;
+use crate::{ByteSource, DynReadBuffer};
+use crate::buffer::{Buffer, Storage};
+
+/// The maximum amount of unused buffers kept by each thread.
+const MAX_BUFFERS: usize = 16;
+
+/// The maximum capacity of a buffer to be kept for reuse,
+/// so a single large message doesn't keep its memory alive indefinitely.
+const MAX_CAPACITY: usize = 64 * 1024;
+
+thread_local! {
+	static FREE_BUFFERS: RefCell<Vec<Storage>> = const { RefCell::new(Vec::new()) };
+}
+
+impl<R: ByteSource> DynReadBuffer<R> {
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// whose internal buffer is taken from a free list of the current thread,
+	/// if one is available.
+	/// 
+	/// When the **DynReadBuffer** is dropped, its internal buffer is returned
+	/// to the free list of the thread dropping it, unless it has grown larger
+	/// than 64 KiB or the free list already holds 16 buffers.
+	/// This makes short-lived buffers, like ones used for handling a single request,
+	/// practically free after the first ones have been allocated.
+	/// 
+	/// Requires the `thread-local-pool` feature.
+	/// 
+	/// For more control over the pooled buffers, see [`BufferPool`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// for request in ["GET /a\n", "GET /b\n"] {
+	///     let mut buffer = DynReadBuffer::new_pooled(request.as_bytes());
+	///     assert_eq!(buffer.read_until(b'\n')?, request.as_bytes());
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`BufferPool`]: crate::BufferPool
+	pub fn new_pooled(reader: R) -> Self {
+		let storage = FREE_BUFFERS.with_borrow_mut(Vec::pop);
+		let reclaim = Box::new(|storage: Storage| {
+			if storage.capacity() > MAX_CAPACITY {
+				return;
+			}
+			
+			// the free list is gone if the thread is exiting, then the storage is dropped
+			let _ = FREE_BUFFERS.try_with(|buffers| {
+				let mut buffers = buffers.borrow_mut();
+				
+				if buffers.len() < MAX_BUFFERS {
+					buffers.push(storage);
+				}
+			});
+		});
+		
+		Self::from_buffer(reader, Buffer::with_storage(storage, 0, reclaim))
+	}
+}
\ No newline at end of file
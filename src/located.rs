@@ -0,0 +1,75 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+/// A frame returned from [`read_until_located`] along with its location in the data.
+/// 
+/// [`read_until_located`]: DynReadBuffer::read_until_located
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Located<'a> {
+	/// The data of the frame, including the delimiter.
+	pub data: &'a [u8],
+	/// The amount of bytes consumed before the start of the frame.
+	pub offset: u64,
+	/// The line on which the frame starts, starting from `1`.
+	pub line: u64,
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Like [`read_until`], but also returns the location of the frame,
+	/// for reporting diagnostics like a parser or compiler would.
+	/// 
+	/// Lines are counted by the newlines (`\n`) consumed since [`track_lines`]
+	/// or **read_until_located** was first called, so if other reads come first,
+	/// [`track_lines`] should be called right after creating the **DynReadBuffer**.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "let a = 1;\nlet b =\n  2;".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let first = buffer.read_until_located(b';')?;
+	/// assert_eq!((first.offset, first.line), (0, 1));
+	/// 
+	/// let second = buffer.read_until_located(b';')?;
+	/// assert_eq!(second.data, b"\nlet b =\n  2;");
+	/// assert_eq!((second.offset, second.line), (10, 1));
+	/// 
+	/// buffer.read_until_located(b';').unwrap_err();
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	/// [`track_lines`]: DynReadBuffer::track_lines
+	pub fn read_until_located(&mut self, delimiter: u8) -> Result<Located<'_>, io::Error> {
+		self.buffer.track_lines();
+		
+		let offset = self.buffer.position();
+		let line = self.buffer.lines().unwrap_or(0) + 1;
+		let data = self.read_until(delimiter)?;
+		
+		Ok(Located {
+			data,
+			offset,
+			line,
+		})
+	}
+	
+	/// Starts counting the lines in the returned data for [`read_until_located`],
+	/// so the current position counts as the start of line `1`.
+	/// 
+	/// Calling this again once lines are counted has no effect.
+	/// 
+	/// [`read_until_located`]: DynReadBuffer::read_until_located
+	pub fn track_lines(&mut self) {
+		self.buffer.track_lines();
+	}
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Remembers the current position in the data,
+	/// so it can be returned to using [`reset_to_mark`].
+	/// 
+	/// All data after the mark stays in the internal buffer, even once returned,
+	/// until the mark is removed using [`clear_mark`] or replaced by another call to **mark**,
+	/// so the internal buffer grows to hold everything read after the mark.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "42 apples".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// buffer.mark();
+	/// 
+	/// if buffer.read_bytes(5)? != b"apple" {
+	///     buffer.reset_to_mark();
+	/// }
+	/// 
+	/// buffer.clear_mark();
+	/// assert_eq!(buffer.read_until(b' ')?, b"42 ");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`reset_to_mark`]: DynReadBuffer::reset_to_mark
+	/// [`clear_mark`]: DynReadBuffer::clear_mark
+	pub fn mark(&mut self) {
+		let position = self.buffer.position();
+		self.buffer.set_mark(Some(position));
+	}
+	
+	/// Returns to the position remembered using [`mark`],
+	/// so all data read since then is returned again by the following reads.
+	/// 
+	/// The mark stays in place, so this can be used multiple times.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if no mark is set.
+	/// 
+	/// [`mark`]: DynReadBuffer::mark
+	pub fn reset_to_mark(&mut self) {
+		let mark = self.buffer.mark().expect("no mark is set");
+		let restored = self.buffer.rewind_to(mark);
+		debug_assert!(restored, "marked data is no longer stored");
+	}
+	
+	/// Removes the mark set using [`mark`],
+	/// allowing the data after it to be discarded once it is returned.
+	/// 
+	/// [`mark`]: DynReadBuffer::mark
+	pub fn clear_mark(&mut self) {
+		self.buffer.set_mark(None);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,235 @@
+use std::io::{self, ErrorKind};
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Like [`read_bytes`], but returns `Ok(None)` instead of an error
+	/// if the given [ByteSource] returns an error of the kind
+	/// [ErrorKind::WouldBlock][`WouldBlock`].
+	/// 
+	/// This is intended for non-blocking sources like sockets
+	/// in non-blocking mode. All data read before the source would have blocked
+	/// is preserved, so the call can simply be repeated once more data is available.
+	/// 
+	/// # Errors
+	/// 
+	/// All errors other than [ErrorKind::WouldBlock][`WouldBlock`]
+	/// are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::{self, Read};
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// // A source which has no data available at the moment
+	/// struct NotReady;
+	/// 
+	/// impl Read for NotReady {
+	///     fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+	///         Err(io::ErrorKind::WouldBlock.into())
+	///     }
+	/// }
+	/// 
+	/// let reader = [1, 2].as_slice().chain(NotReady);
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.try_read_bytes(1)?, Some([1].as_slice()));
+	/// assert_eq!(buffer.try_read_bytes(2)?, None);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	pub fn try_read_bytes(&mut self, amount: usize) -> Result<Option<&[u8]>, io::Error> {
+		match self.read_bytes(amount) {
+			Err(err) if err.kind() == ErrorKind::WouldBlock => Ok(None),
+			result => result.map(Some),
+		}
+	}
+	
+	/// Like [`read_until`], but returns `Ok(None)` instead of an error
+	/// if the given [ByteSource] returns an error of the kind
+	/// [ErrorKind::WouldBlock][`WouldBlock`].
+	/// 
+	/// This is intended for non-blocking sources like sockets
+	/// in non-blocking mode. All data read before the source would have blocked
+	/// is preserved, so the call can simply be repeated once more data is available.
+	/// 
+	/// # Errors
+	/// 
+	/// All errors other than [ErrorKind::WouldBlock][`WouldBlock`]
+	/// are handled the same way as in [`read_until`].
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	pub fn try_read_until(&mut self, delimiter: u8) -> Result<Option<&[u8]>, io::Error> {
+		match self.read_until(delimiter) {
+			Err(err) if err.kind() == ErrorKind::WouldBlock => Ok(None),
+			result => result.map(Some),
+		}
+	}
+	
+	/// Performs a single call to [ByteSource::read_into] on the given [ByteSource],
+	/// appending the read data to the internal buffer,
+	/// and returns the amount of bytes read.
+	/// 
+	/// A return value of `0` indicates that the reader
+	/// has reached its "end of file".
+	/// 
+	/// Together with [`next_until`] and [`next_bytes`], which only operate on
+	/// data that is already buffered, this allows using **DynReadBuffer**
+	/// in readiness-based event loops: call `fill_once` when the source
+	/// is reported to be readable, then extract all complete frames.
+	/// For custom scanning logic, use [`available`] and [`consume`] instead.
+	/// 
+	/// # Errors
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, the call to [ByteSource::read_into] is retried.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller,
+	/// including [ErrorKind::WouldBlock][`WouldBlock`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = b"first\nsecond\nthi".as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// buffer.fill_once()?;
+	/// 
+	/// assert_eq!(buffer.next_until(b'\n'), Some(b"first\n".as_slice()));
+	/// assert_eq!(buffer.next_until(b'\n'), Some(b"second\n".as_slice()));
+	/// assert_eq!(buffer.next_until(b'\n'), None);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`next_until`]: DynReadBuffer::next_until
+	/// [`next_bytes`]: DynReadBuffer::next_bytes
+	/// [`available`]: DynReadBuffer::available
+	/// [`consume`]: DynReadBuffer::consume
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	pub fn fill_once(&mut self) -> Result<usize, io::Error> {
+		self.fill_buffer()
+	}
+	
+	/// Performs a single call to [ByteSource::read_into] like [`fill_once`],
+	/// then calls the given closure with every complete frame ending
+	/// with the specified delimiter which is buffered afterwards,
+	/// and returns the amount of bytes read.
+	/// 
+	/// A return value of `0` indicates that the reader
+	/// has reached its "end of file". Data after the last delimiter
+	/// stays buffered and is completed by the following calls.
+	/// 
+	/// For protocols sending many small frames, this handles all frames
+	/// which arrived together with a single call to [ByteSource::read_into],
+	/// instead of one call for each frame.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`fill_once`].
+	/// Frames which were already buffered are not passed to the closure
+	/// if an error occurs.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = b"first\nsecond\nthi".as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let mut frames = Vec::new();
+	/// 
+	/// while buffer.read_available_frames(b'\n', |frame| frames.push(frame.to_vec()))? > 0 {}
+	/// 
+	/// assert_eq!(frames, [b"first\n".as_slice(), b"second\n"]);
+	/// assert_eq!(buffer.available(), b"thi");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`fill_once`]: DynReadBuffer::fill_once
+	pub fn read_available_frames(&mut self, delimiter: u8, mut f: impl FnMut(&[u8])) -> Result<usize, io::Error> {
+		let amount_read = self.fill_once()?;
+		
+		while let Some(frame) = self.next_until(delimiter) {
+			f(frame);
+		}
+		
+		Ok(amount_read)
+	}
+	
+	/// Returns a slice referencing the buffered data up to and including
+	/// the first occurrence of the specified delimiter,
+	/// or [None] if the buffered data doesn't contain the delimiter.
+	/// 
+	/// Unlike [`read_until`], this never reads from the given [ByteSource].
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	pub fn next_until(&mut self, delimiter: u8) -> Option<&[u8]> {
+		let position = self.buffer.find(delimiter, 0)?;
+		Some(self.take(position + 1))
+	}
+	
+	/// Returns a slice referencing the specified amount of buffered bytes,
+	/// or [None] if not enough data is buffered.
+	/// 
+	/// Unlike [`read_bytes`], this never reads from the given [ByteSource].
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn next_bytes(&mut self, amount: usize) -> Option<&[u8]> {
+		if amount > self.buffer.len() {
+			return None;
+		}
+		
+		Some(self.take(amount))
+	}
+	
+	/// Returns a slice referencing up to the specified amount of buffered bytes,
+	/// which is empty if nothing is buffered.
+	/// 
+	/// Unlike [`next_bytes`], this returns whatever is buffered instead of
+	/// waiting for the full amount, and like it, this never reads from the given [ByteSource].
+	/// This allows event loops to process the data they already have,
+	/// like forwarding it to another connection, before reading more using [`fill_once`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = b"abcde".as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.drain_buffered(4), b"");
+	/// 
+	/// buffer.fill_once()?;
+	/// assert_eq!(buffer.drain_buffered(4), b"abcd");
+	/// assert_eq!(buffer.drain_buffered(4), b"e");
+	/// assert_eq!(buffer.drain_buffered(4), b"");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`next_bytes`]: DynReadBuffer::next_bytes
+	/// [`fill_once`]: DynReadBuffer::fill_once
+	pub fn drain_buffered(&mut self, max_amount: usize) -> &[u8] {
+		let amount = max_amount.min(self.buffer.len());
+		
+		if amount == 0 {
+			return &[];
+		}
+		
+		self.take(amount)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+/// Hooks into the lifecycle of a [`DynReadBuffer`],
+/// installed using [`set_observer`].
+/// 
+/// This allows adding logging, accounting or fault injection
+/// without wrapping the [ByteSource]. All methods have default implementations
+/// which do nothing, so only the relevant ones need to be implemented.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::io;
+/// use read_buffer::{DynReadBuffer, ReadObserver};
+/// 
+/// struct Logger;
+/// 
+/// impl ReadObserver for Logger {
+///     fn on_read(&mut self, result: &io::Result<usize>) -> io::Result<()> {
+///         println!("read returned {result:?}");
+///         Ok(())
+///     }
+/// }
+/// 
+/// let reader = [1, 2, 3].as_slice();
+/// let mut buffer = DynReadBuffer::new(reader);
+/// buffer.set_observer(Logger);
+/// ```
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`set_observer`]: DynReadBuffer::set_observer
+pub trait ReadObserver {
+	/// Called after every call to [ByteSource::read_into] with its result,
+	/// after any data it read has been added to the buffer.
+	/// 
+	/// Returning an error makes the read fail with that error instead,
+	/// which can be used to inject faults.
+	/// Any data which was read is preserved in the buffer regardless.
+	fn on_read(&mut self, result: &Result<usize, io::Error>) -> Result<(), io::Error> {
+		let _ = result;
+		Ok(())
+	}
+	
+	/// Called whenever the internal buffer grows,
+	/// with its capacity before and after growing.
+	fn on_grow(&mut self, old_capacity: usize, new_capacity: usize) {
+		let _ = (old_capacity, new_capacity);
+	}
+	
+	/// Called whenever a value is successfully read from the buffer,
+	/// with the amount of bytes it consumed.
+	fn on_frame(&mut self, length: usize) {
+		let _ = length;
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Installs the given [ReadObserver], replacing any previously installed one.
+	pub fn set_observer(&mut self, observer: impl ReadObserver + Send + Sync + 'static) {
+		self.observer = Some(Box::new(observer));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,212 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads frames ending with the specified delimiter and calls the given closure
+	/// with an owned copy of each of them on the [rayon] thread pool.
+	/// 
+	/// Frames are split the same way as by [`records`], so the data after
+	/// the last delimiter is processed as a final frame.
+	/// Frames are read sequentially while earlier ones are being processed,
+	/// but the closure may be called for them in any order.
+	/// To keep the order of the results, use [`process_frames_parallel_ordered`].
+	/// 
+	/// Returns once all frames have been processed.
+	/// 
+	/// Requires the `rayon` feature.
+	/// 
+	/// # Errors
+	/// 
+	/// If reading fails, no more frames are read and the error is returned
+	/// once all frames read before have been processed.
+	/// 
+	/// # Panics
+	/// 
+	/// If the closure panics, no more frames are read and the panic
+	/// is resumed once all frames read before have been processed.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::sync::atomic::{AtomicUsize, Ordering};
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "GET /\nPOST /login\nGET /about\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let gets = AtomicUsize::new(0);
+	/// 
+	/// buffer.process_frames_parallel(b'\n', |frame| {
+	///     if frame.starts_with(b"GET ") {
+	///         gets.fetch_add(1, Ordering::Relaxed);
+	///     }
+	/// })?;
+	/// 
+	/// assert_eq!(gets.load(Ordering::Relaxed), 2);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`records`]: DynReadBuffer::records
+	/// [`process_frames_parallel_ordered`]: DynReadBuffer::process_frames_parallel_ordered
+	pub fn process_frames_parallel(&mut self, delimiter: u8, f: impl Fn(Vec<u8>) + Sync) -> Result<(), io::Error> {
+		self.process_parallel(delimiter, false, f, |()| {})
+	}
+	
+	/// Like [`process_frames_parallel`], but passes the results of the given closure
+	/// to `output` on the calling thread, in the same order as the frames were read.
+	/// 
+	/// Results which are ready before the ones of all earlier frames
+	/// are held back until those are ready as well.
+	/// 
+	/// Requires the `rayon` feature.
+	/// 
+	/// # Errors
+	/// 
+	/// If reading fails, no more frames are read and the error is returned
+	/// once the results of all frames read before have been passed to `output`.
+	/// 
+	/// # Panics
+	/// 
+	/// If the closure panics, no more frames are read and the panic
+	/// is resumed once all frames read before have been processed.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "3\n1\n2\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let mut squares = Vec::new();
+	/// 
+	/// buffer.process_frames_parallel_ordered(
+	///     b'\n',
+	///     |frame| {
+	///         let number: u32 = std::str::from_utf8(&frame).unwrap().trim().parse().unwrap();
+	///         number * number
+	///     },
+	///     |square| squares.push(square),
+	/// )?;
+	/// 
+	/// assert_eq!(squares, [9, 1, 4]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`process_frames_parallel`]: DynReadBuffer::process_frames_parallel
+	pub fn process_frames_parallel_ordered<T: Send>(
+		&mut self,
+		delimiter: u8,
+		f: impl Fn(Vec<u8>) -> T + Sync,
+		output: impl FnMut(T),
+	) -> Result<(), io::Error> {
+		self.process_parallel(delimiter, true, f, output)
+	}
+	
+	fn process_parallel<T: Send>(
+		&mut self,
+		delimiter: u8,
+		ordered: bool,
+		f: impl Fn(Vec<u8>) -> T + Sync,
+		output: impl FnMut(T),
+	) -> Result<(), io::Error> {
+		// limits how many frames are held in memory at once
+		let max_in_flight = rayon::current_num_threads() * 4;
+		let (sender, receiver) = mpsc::channel();
+		let mut collector = Collector {
+			ordered,
+			output,
+			pending: BTreeMap::new(),
+			next_index: 0,
+			panic: None,
+		};
+		let f = &f;
+		
+		let result = rayon::in_place_scope(|scope| {
+			let mut records = self.records(delimiter);
+			let mut index = 0;
+			let mut in_flight = 0;
+			
+			let result = loop {
+				if collector.panic.is_some() {
+					break Ok(());
+				}
+				
+				let frame = match records.next_record() {
+					Some(Ok(frame)) => frame.to_vec(),
+					Some(Err(err)) => break Err(err),
+					None => break Ok(()),
+				};
+				
+				while in_flight >= max_in_flight {
+					collector.collect(receiver.recv().expect("a sender is kept until all frames are processed"));
+					in_flight -= 1;
+				}
+				
+				let sender = sender.clone();
+				scope.spawn(move |_| {
+					let result = panic::catch_unwind(AssertUnwindSafe(|| f(frame)));
+					let _ = sender.send((index, result));
+				});
+				index += 1;
+				in_flight += 1;
+				
+				while let Ok(message) = receiver.try_recv() {
+					collector.collect(message);
+					in_flight -= 1;
+				}
+			};
+			
+			for _ in 0..in_flight {
+				collector.collect(receiver.recv().expect("a sender is kept until all frames are processed"));
+			}
+			
+			result
+		});
+		
+		if let Some(payload) = collector.panic {
+			panic::resume_unwind(payload);
+		}
+		
+		result
+	}
+}
+
+/// Receives the results of processing frames in parallel
+/// and passes them on, in order if required.
+struct Collector<T, O: FnMut(T)> {
+	ordered: bool,
+	output: O,
+	pending: BTreeMap<u64, T>,
+	next_index: u64,
+	panic: Option<Box<dyn Any + Send>>,
+}
+
+impl<T, O: FnMut(T)> Collector<T, O> {
+	fn collect(&mut self, (index, result): (u64, Result<T, Box<dyn Any + Send>>)) {
+		let value = match result {
+			Ok(value) => value,
+			Err(payload) => {
+				self.panic.get_or_insert(payload);
+				return;
+			},
+		};
+		
+		if !self.ordered {
+			return (self.output)(value);
+		}
+		
+		self.pending.insert(index, value);
+		
+		while let Some(value) = self.pending.remove(&self.next_index) {
+			(self.output)(value);
+			self.next_index += 1;
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+/// Returned by a parser passed to [`read_parse`] to signal that
+/// more data is needed to parse a complete value.
+/// 
+/// [`read_parse`]: DynReadBuffer::read_parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Incomplete;
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Repeatedly calls the given parser with all the buffered data,
+	/// reading more data from the given [ByteSource] whenever the parser
+	/// returns [Incomplete], until it returns a value.
+	/// 
+	/// On success, the parser returns the amount of bytes it consumed
+	/// along with the parsed value. Exactly that amount of bytes is consumed
+	/// from the buffer and the value is returned.
+	/// 
+	/// The parser is called with the currently buffered data first,
+	/// which may be empty. Parsers which can fail should return their
+	/// error as part of the value, for example by returning a [Result].
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" while the parser
+	/// still returns [Incomplete], an error of the kind
+	/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the parser reports having consumed more bytes
+	/// than it was given.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{DynReadBuffer, Incomplete};
+	/// 
+	/// let reader = b"12,345,".as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let mut parse_number = |input: &[u8]| {
+	///     let end = input.iter().position(|byte| *byte == b',').ok_or(Incomplete)?;
+	///     let number: u32 = std::str::from_utf8(&input[..end]).unwrap().parse().unwrap();
+	///     Ok((end + 1, number))
+	/// };
+	/// 
+	/// assert_eq!(buffer.read_parse(&mut parse_number)?, 12);
+	/// assert_eq!(buffer.read_parse(&mut parse_number)?, 345);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_parse<T>(&mut self, mut parser: impl FnMut(&[u8]) -> Result<(usize, T), Incomplete>) -> Result<T, io::Error> {
+		loop {
+			if let Ok((consumed, value)) = parser(self.available()) {
+				self.consume(consumed);
+				return Ok(value);
+			}
+			
+			if self.fill_buffer()? == 0 {
+				return Err(self.unexpected_eof("read_parse", None));
+			}
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use std::io::{self, ErrorKind, Read};
+use std::net::TcpStream;
+use crate::DynReadBuffer;
+
+/// A [Read] which can return data without removing it from its source,
+/// used by [`peek_from_socket`].
+/// 
+/// This is implemented for [TcpStream], which peeks at the data
+/// in the kernel's receive buffer using `MSG_PEEK`.
+/// 
+/// [`peek_from_socket`]: DynReadBuffer::peek_from_socket
+pub trait PeekRead: Read {
+	/// Reads data into the given buffer like [Read::read],
+	/// without removing it from the source, so it's returned again
+	/// by the next call to [Read::read].
+	fn peek(&self, buf: &mut [u8]) -> Result<usize, io::Error>;
+}
+
+impl PeekRead for TcpStream {
+	fn peek(&self, buf: &mut [u8]) -> Result<usize, io::Error> {
+		TcpStream::peek(self, buf)
+	}
+}
+
+impl PeekRead for &TcpStream {
+	fn peek(&self, buf: &mut [u8]) -> Result<usize, io::Error> {
+		TcpStream::peek(self, buf)
+	}
+}
+
+impl<R: PeekRead, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Returns up to the specified amount of bytes without consuming them,
+	/// neither from the internal buffer nor from the socket.
+	/// 
+	/// Data which is already buffered is returned first.
+	/// If more is needed, a single peek at the socket is performed,
+	/// which waits until at least some data is available, but may return
+	/// less than requested, the same way as [TcpStream::peek].
+	/// An empty slice is only returned once the socket reached its "end of file",
+	/// or if no bytes were requested.
+	/// 
+	/// As the peeked data remains in the kernel's receive buffer,
+	/// a **DynReadBuffer** reading from a `&TcpStream` can be used to detect
+	/// the protocol, after which the [TcpStream] is handed to another subsystem
+	/// with all of its data intact, as long as nothing has been buffered yet.
+	/// 
+	/// # Errors
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [`PeekRead::peek`] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```no_run
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::net::TcpStream;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let stream = TcpStream::connect("localhost:8080")?;
+	/// let mut buffer = DynReadBuffer::new(&stream);
+	/// 
+	/// if buffer.peek_from_socket(3)? == b"GET" {
+	///     drop(buffer);
+	///     // hand the stream over to an HTTP server
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn peek_from_socket(&mut self, amount: usize) -> Result<&[u8], io::Error> {
+		let buffered = self.buffer.len();
+		
+		if buffered >= amount {
+			return Ok(&self.buffer.filled()[..amount]);
+		}
+		
+		let missing = amount - buffered;
+		self.reserve(missing);
+		
+		let peeked = loop {
+			match self.reader.peek(&mut self.buffer.spare_mut()[..missing]) {
+				Ok(peeked) => break peeked,
+				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+				Err(err) => return Err(err),
+			}
+		};
+		
+		Ok(self.buffer.filled_with_spare(peeked))
+	}
+}
\ No newline at end of file
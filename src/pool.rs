@@ -0,0 +1,132 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use crate::{ByteSource, DynReadBuffer};
+use crate::buffer::{Buffer, Storage};
+
+/// A pool of internal buffers for [`DynReadBuffer`]s,
+/// reusing their memory instead of allocating new buffers.
+/// 
+/// Buffers are handed out in size classes: a new **DynReadBuffer** gets
+/// a buffer of the smallest size class fitting the requested capacity.
+/// When the **DynReadBuffer** is dropped, its buffer is returned to the pool,
+/// as long as the memory retained by the pool stays within the configured limit.
+/// 
+/// This avoids allocator churn and fragmentation for servers
+/// handling many short-lived connections.
+/// A **BufferPool** can be cloned cheaply, all clones share the same buffers.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use read_buffer::BufferPool;
+/// 
+/// let pool = BufferPool::new(&[4096, 65536], 1 << 20);
+/// 
+/// let mut buffer = pool.buffer("PING\n".as_bytes(), 1024);
+/// assert_eq!(buffer.read_until(b'\n')?, b"PING\n");
+/// drop(buffer);
+/// 
+/// assert_eq!(pool.retained(), 4096);
+/// # Ok(())
+/// # }
+/// ```
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+#[derive(Clone)]
+pub struct BufferPool {
+	shared: Arc<Mutex<PoolState>>,
+}
+
+struct PoolState {
+	classes: Vec<SizeClass>,
+	retained: usize,
+	max_retained: usize,
+}
+
+struct SizeClass {
+	size: usize,
+	free: Vec<Storage>,
+}
+
+impl BufferPool {
+	/// Creates a new empty **BufferPool** with the given size classes,
+	/// which retains at most the specified amount of bytes in unused buffers.
+	pub fn new(size_classes: &[usize], max_retained: usize) -> Self {
+		let mut sizes = size_classes.to_vec();
+		sizes.sort_unstable();
+		sizes.dedup();
+		
+		let classes = sizes.into_iter()
+			.map(|size| SizeClass {
+				size,
+				free: Vec::new(),
+			})
+			.collect();
+		
+		Self {
+			shared: Arc::new(Mutex::new(PoolState {
+				classes,
+				retained: 0,
+				max_retained,
+			})),
+		}
+	}
+	
+	/// Creates a new **DynReadBuffer** to read from the given [ByteSource]
+	/// with an internal buffer of at least the specified capacity taken from the pool.
+	/// 
+	/// If no unused buffer of the fitting size class is available, a new one is allocated.
+	/// If the capacity is larger than all size classes,
+	/// the buffer is neither taken from nor returned to the pool.
+	pub fn buffer<R: ByteSource>(&self, reader: R, capacity: usize) -> DynReadBuffer<R> {
+		let mut state = self.lock();
+		
+		let Some(class) = state.classes.iter_mut().find(|class| class.size >= capacity) else {
+			return DynReadBuffer::with_capacity(reader, capacity);
+		};
+		
+		let size = class.size;
+		let storage = class.free.pop();
+		
+		if let Some(storage) = &storage {
+			state.retained -= storage.capacity();
+		}
+		
+		drop(state);
+		
+		let shared = Arc::clone(&self.shared);
+		let reclaim = Box::new(move |storage| {
+			shared.lock()
+				.unwrap_or_else(PoisonError::into_inner)
+				.reclaim(storage);
+		});
+		
+		DynReadBuffer::from_buffer(reader, Buffer::with_storage(storage, size, reclaim))
+	}
+	
+	/// Returns the amount of bytes retained by the pool in unused buffers.
+	pub fn retained(&self) -> usize {
+		self.lock().retained
+	}
+	
+	fn lock(&self) -> MutexGuard<'_, PoolState> {
+		self.shared.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+impl PoolState {
+	/// Returns the storage to the largest size class it fits,
+	/// unless that would exceed the limit of retained bytes.
+	fn reclaim(&mut self, storage: Storage) {
+		let capacity = storage.capacity();
+		
+		if self.retained + capacity > self.max_retained {
+			return;
+		}
+		
+		if let Some(class) = self.classes.iter_mut().rev().find(|class| class.size <= capacity) {
+			class.free.push(storage);
+			self.retained += capacity;
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,147 @@
+use std::io::{self, ErrorKind, Read};
+use std::mem;
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::thread;
+use crate::DynReadBuffer;
+
+/// A [Read] which keeps reading from the wrapped [Read] on a background thread,
+/// so the next chunk of data is already being read
+/// while the previous one is still being processed.
+/// 
+/// At most one chunk is read ahead of the chunk currently being returned,
+/// so the data is double-buffered and the memory used is bounded.
+/// This overlaps waiting for the [Read] with processing its data,
+/// which helps for example when parsing large files from a slow disk.
+/// 
+/// Errors from the wrapped [Read] are passed on in order
+/// and the background thread keeps reading afterwards,
+/// unless the error is of the kind [ErrorKind::Interrupted][`Interrupted`],
+/// in which case the read is retried.
+/// Once the wrapped [Read] reaches its "end of file", the background thread exits.
+/// 
+/// When the **PrefetchReader** is dropped, the background thread exits
+/// after its current call to [Read::read] returns.
+/// 
+/// Creating a **PrefetchReader** panics on targets which don't support threads,
+/// like `wasm32-unknown-unknown`.
+/// 
+/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+pub struct PrefetchReader {
+	chunks: Receiver<io::Result<Vec<u8>>>,
+	recycled: Sender<Vec<u8>>,
+	current: Vec<u8>,
+	position: usize,
+	eof: bool,
+}
+
+impl PrefetchReader {
+	/// Creates a new **PrefetchReader** which starts reading from the given [Read]
+	/// on a background thread, in chunks of at most the specified size.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the chunk size is `0`.
+	pub fn new<R: Read + Send + 'static>(mut reader: R, chunk_size: usize) -> Self {
+		assert!(chunk_size > 0, "chunk size must not be 0");
+		
+		let (chunk_sender, chunks) = mpsc::sync_channel(1);
+		let (recycled, recycled_receiver) = mpsc::channel();
+		
+		thread::spawn(move || prefetch(&mut reader, chunk_size, chunk_sender, recycled_receiver));
+		
+		Self {
+			chunks,
+			recycled,
+			current: Vec::new(),
+			position: 0,
+			eof: false,
+		}
+	}
+}
+
+fn prefetch(
+	reader: &mut impl Read,
+	chunk_size: usize,
+	chunks: SyncSender<io::Result<Vec<u8>>>,
+	recycled: Receiver<Vec<u8>>,
+) {
+	loop {
+		let mut chunk = recycled.try_recv().unwrap_or_default();
+		chunk.resize(chunk_size, 0);
+		
+		let result = match reader.read(&mut chunk) {
+			Ok(length) => {
+				chunk.truncate(length);
+				Ok(chunk)
+			},
+			Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+			Err(err) => Err(err),
+		};
+		
+		let eof = matches!(&result, Ok(chunk) if chunk.is_empty());
+		
+		if chunks.send(result).is_err() || eof {
+			return;
+		}
+	}
+}
+
+impl Read for PrefetchReader {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		if self.position == self.current.len() {
+			if self.eof || buf.is_empty() {
+				return Ok(0);
+			}
+			
+			let chunk = match self.chunks.recv() {
+				Ok(result) => result?,
+				Err(_) => return Err(io::Error::other("prefetch thread panicked")),
+			};
+			
+			if chunk.is_empty() {
+				self.eof = true;
+				return Ok(0);
+			}
+			
+			let previous = mem::replace(&mut self.current, chunk);
+			// the background thread may already have exited
+			let _ = self.recycled.send(previous);
+			self.position = 0;
+		}
+		
+		let remaining = &self.current[self.position..];
+		let length = remaining.len().min(buf.len());
+		buf[..length].copy_from_slice(&remaining[..length]);
+		self.position += length;
+		Ok(length)
+	}
+}
+
+impl DynReadBuffer<PrefetchReader> {
+	/// Creates a new **DynReadBuffer** to read from the given [Read]
+	/// through a [PrefetchReader], which keeps reading the next chunk
+	/// of the specified size on a background thread.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the chunk size is `0`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::Cursor;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = Cursor::new(b"first line\nsecond line\n".to_vec());
+	/// let mut buffer = DynReadBuffer::prefetching(reader, 8);
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"first line\n");
+	/// assert_eq!(buffer.read_until(b'\n')?, b"second line\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn prefetching<R: Read + Send + 'static>(reader: R, chunk_size: usize) -> Self {
+		Self::new(PrefetchReader::new(reader, chunk_size))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use std::io;
+use std::mem;
+use crate::{ByteSource, DynReadBuffer, ReadBuffer};
+use crate::alignment::Alignment;
+
+macro_rules! read_primitive {
+	($type:ty, $name_le:ident, $name_be:ident $(, $source:ident)?) => {
+		read_primitive!($type, $name_le, from_le_bytes, "little endian" $(, $source)?);
+		read_primitive!($type, $name_be, from_be_bytes, "big endian" $(, $source)?);
+	};
+	($type:ty, $name:ident, $from_bytes:ident, $description:literal) => {
+		#[doc = concat!("Reads a ", $description, " [`", stringify!($type), "`] from the given [ByteSource].")]
+		#[doc = ""]
+		#[doc = "# Errors"]
+		#[doc = ""]
+		#[doc = "Errors are handled the same way as in [`read_bytes`](DynReadBuffer::read_bytes)."]
+		pub fn $name(&mut self) -> Result<$type, io::Error> {
+			let bytes = self.read_array_copied::<{ mem::size_of::<$type>() }>()?;
+			Ok(<$type>::$from_bytes(bytes))
+		}
+	};
+	($type:ty, $name:ident, $from_bytes:ident, $description:literal, $source:ident) => {
+		#[doc = concat!("Reads a ", $description, " [`", stringify!($type), "`] from the given [ByteSource].")]
+		#[doc = ""]
+		#[doc = "# Errors"]
+		#[doc = ""]
+		#[doc = "Errors are handled the same way as in [`read_bytes`](ReadBuffer::read_bytes)."]
+		#[doc = ""]
+		#[doc = "# Panics"]
+		#[doc = ""]
+		#[doc = concat!("Panics if the capacity of the buffer is smaller than the size of [`", stringify!($type), "`].")]
+		pub fn $name(&mut self, $source: &mut impl ByteSource) -> Result<$type, io::Error> {
+			let bytes = self.read_bytes($source, mem::size_of::<$type>())?;
+			Ok(<$type>::$from_bytes(bytes.try_into().unwrap()))
+		}
+	};
+}
+
+macro_rules! read_primitives {
+	($($source:ident)?) => {
+		read_primitive!(u8, read_u8, from_le_bytes, "single" $(, $source)?);
+		read_primitive!(i8, read_i8, from_le_bytes, "single" $(, $source)?);
+		read_primitive!(u16, read_u16_le, read_u16_be $(, $source)?);
+		read_primitive!(i16, read_i16_le, read_i16_be $(, $source)?);
+		read_primitive!(u32, read_u32_le, read_u32_be $(, $source)?);
+		read_primitive!(i32, read_i32_le, read_i32_be $(, $source)?);
+		read_primitive!(u64, read_u64_le, read_u64_be $(, $source)?);
+		read_primitive!(i64, read_i64_le, read_i64_be $(, $source)?);
+		read_primitive!(u128, read_u128_le, read_u128_be $(, $source)?);
+		read_primitive!(i128, read_i128_le, read_i128_be $(, $source)?);
+		read_primitive!(f32, read_f32_le, read_f32_be $(, $source)?);
+		read_primitive!(f64, read_f64_le, read_f64_be $(, $source)?);
+	};
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	read_primitives!();
+}
+
+impl<const SIZE: usize, A: Alignment> ReadBuffer<SIZE, A> {
+	read_primitives!(source);
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Like [`read_bytes`], but calls `on_progress` with the amount of bytes
+	/// buffered so far and the requested amount of bytes
+	/// once at the start and after every call to [ByteSource::read_into].
+	/// 
+	/// This allows rendering a progress bar while a large amount of data
+	/// is read. The amount of bytes reported never exceeds the requested amount,
+	/// and the last call reports both as equal if the read succeeds.
+	/// 
+	/// # Errors
+	/// 
+	/// All errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [0; 1000].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let data = buffer.read_bytes_with_progress(1000, |read, total| {
+	///     println!("{read}/{total} bytes");
+	/// })?;
+	/// 
+	/// assert_eq!(data.len(), 1000);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_bytes_with_progress(
+		&mut self,
+		amount: usize,
+		mut on_progress: impl FnMut(usize, usize),
+	) -> Result<&[u8], io::Error> {
+		if amount > self.buffer.len() {
+			self.reserve_for_read(amount - self.buffer.len());
+		}
+		
+		on_progress(self.buffer.len().min(amount), amount);
+		
+		while amount > self.buffer.len() {
+			let remaining = amount - self.buffer.len();
+			let amount_read = self.read_into_buffer(remaining, &mut |_| Ok(()))?;
+			
+			if amount_read == 0 {
+				return Err(self.unexpected_eof("read_bytes_with_progress", Some(amount)));
+			}
+			
+			on_progress(self.buffer.len(), amount);
+		}
+		
+		Ok(self.take(amount))
+	}
+}
\ No newline at end of file
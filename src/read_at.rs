@@ -0,0 +1,90 @@
+use std::fs::File;
+use std::io::{self, Read};
+#[cfg(unix)]
+use std::os::unix::fs::FileExt;
+#[cfg(windows)]
+use std::os::windows::fs::FileExt;
+use crate::DynReadBuffer;
+
+/// A [Read] which reads from a shared [File] starting at a specified offset,
+/// without seeking, using positioned reads (`pread` on Unix).
+/// 
+/// Each **OffsetReader** keeps track of its own position,
+/// so several of them can read from independent offsets
+/// of the same [File] at once, for example from multiple threads.
+/// 
+/// On Windows the position of the [File] itself is changed by each read,
+/// which doesn't affect other **OffsetReader**s but does affect
+/// other reads from the [File].
+pub struct OffsetReader<'a> {
+	file: &'a File,
+	offset: u64,
+}
+
+impl<'a> OffsetReader<'a> {
+	/// Creates a new **OffsetReader** reading from the given [File]
+	/// starting at the specified offset.
+	pub fn new(file: &'a File, offset: u64) -> Self {
+		Self {
+			file,
+			offset,
+		}
+	}
+	
+	/// The offset in the [File] from which the next read will start.
+	pub fn offset(&self) -> u64 {
+		self.offset
+	}
+}
+
+impl Read for OffsetReader<'_> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		#[cfg(unix)]
+		let length = self.file.read_at(buf, self.offset)?;
+		#[cfg(windows)]
+		let length = self.file.seek_read(buf, self.offset)?;
+		
+		self.offset += length as u64;
+		Ok(length)
+	}
+}
+
+/// A [`DynReadBuffer`] reading from a shared [File] at an independent offset,
+/// created using [`DynReadBuffer::at`].
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+pub type ReadAtBuffer<'a> = DynReadBuffer<OffsetReader<'a>>;
+
+impl<'a> DynReadBuffer<OffsetReader<'a>> {
+	/// Creates a new **DynReadBuffer** to read from the given [File]
+	/// starting at the specified offset, using an [OffsetReader].
+	/// 
+	/// # Examples
+	/// 
+	/// ```no_run
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::fs::File;
+	/// use std::thread;
+	/// use read_buffer::ReadAtBuffer;
+	/// 
+	/// let file = File::open("records.bin")?;
+	/// 
+	/// thread::scope(|scope| {
+	///     for offset in [0, 4096] {
+	///         let file = &file;
+	/// 
+	///         scope.spawn(move || {
+	///             let mut buffer = ReadAtBuffer::at(file, offset);
+	///             let record = buffer.read_bytes(64)?;
+	///             println!("{record:?}");
+	///             Ok::<_, std::io::Error>(())
+	///         });
+	///     }
+	/// });
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn at(file: &'a File, offset: u64) -> Self {
+		Self::new(OffsetReader::new(file, offset))
+	}
+}
\ No newline at end of file
@@ -1,7 +1,11 @@
-use std::io;
-use std::io::Read;
+use std::{fmt, io, mem};
+use std::ops::Range;
+use std::io::ErrorKind;
+use crate::{BufferFull, ByteSource};
+use crate::debug::DebugFn;
+use crate::alignment::{Align1, AlignedBytes, Alignment};
 
-/// A buffer to read into from a [Read] and safely access the read data.
+/// A buffer to read into from a [ByteSource] and safely access the read data.
 /// 
 /// **ReadBuffer** provides a statically sized buffer
 /// to read into using [`read_from`] and [`read_while`],
@@ -10,34 +14,89 @@ use std::io::Read;
 /// 
 /// If you require reading slices of dynamic size, consider using [`DynReadBuffer`].
 /// 
+/// Methods like [`read_until`] may read more data than they return.
+/// That data is carried over and returned first by the following reads,
+/// regardless of which [ByteSource] they are called with.
+/// 
+/// The internal buffer can be aligned by specifying an [`Alignment`]
+/// like [`Align4096`] as the second generic parameter, for sources which
+/// require reads into aligned memory:
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use read_buffer::ReadBuffer;
+/// use read_buffer::Align4096;
+/// 
+/// let mut reader = [1, 2, 3].as_slice();
+/// let mut buffer: ReadBuffer<4096, Align4096> = ReadBuffer::new_aligned();
+/// 
+/// let data = buffer.read_from(&mut reader)?;
+/// assert_eq!(data.as_ptr().align_offset(4096), 0);
+/// # Ok(())
+/// # }
+/// ```
+/// 
 /// [`read_from`]: ReadBuffer::read_from
 /// [`read_while`]: ReadBuffer::read_while
+/// [`read_until`]: ReadBuffer::read_until
 /// [`DynReadBuffer`]: crate::DynReadBuffer
-#[derive(Debug)]
-pub struct ReadBuffer<const SIZE: usize> {
-	buffer: [u8; SIZE],
+/// [`Align4096`]: crate::Align4096
+pub struct ReadBuffer<const SIZE: usize, A: Alignment = Align1> {
+	buffer: AlignedBytes<SIZE, A>,
+	carried_start: usize,
+	carried_length: usize,
+	total_bytes_read: u64,
+	read_calls: u64,
+	frames_returned: u64,
 }
 
 impl<const SIZE: usize> ReadBuffer<SIZE> {
 	/// Creates a new **ReadBuffer**.
-	pub fn new() -> Self {
+	/// 
+	/// As this is a `const fn`, it can also be used to create
+	/// a **ReadBuffer** in a `const` or `static` context:
+	/// 
+	/// ```
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// const BUFFER: ReadBuffer<64> = ReadBuffer::new();
+	/// ```
+	pub const fn new() -> Self {
+		Self::new_aligned()
+	}
+}
+
+impl<const SIZE: usize, A: Alignment> ReadBuffer<SIZE, A> {
+	/// Creates a new **ReadBuffer** with an internal buffer
+	/// aligned according to `A`.
+	/// 
+	/// The data returned by [`read_from`] is aligned the same way,
+	/// unless it was carried over from a previous read.
+	/// 
+	/// [`read_from`]: ReadBuffer::read_from
+	pub const fn new_aligned() -> Self {
 		Self {
-			buffer: [0u8; SIZE],
+			buffer: AlignedBytes::new(),
+			carried_start: 0,
+			carried_length: 0,
+			total_bytes_read: 0,
+			read_calls: 0,
+			frames_returned: 0,
 		}
 	}
 	
-	/// Reads from the given [Read] into the internal buffer
+	/// Reads from the given [ByteSource] into the internal buffer
 	/// and returns a slice referencing the read data
 	/// or an error if any occurred.
 	/// 
 	/// If the length of the returned slice is `0`,
 	/// this indicates that the reader has reached its "end of file"
-	/// as specified for [Read::read].  
+	/// as specified for [ByteSource::read_into].  
 	/// (Unless this method is called on a `ReadBuffer<0>`)
 	/// 
 	/// # Errors
 	/// 
-	/// Errors from [Read::read] are passed on to the caller.
+	/// Errors from [ByteSource::read_into] are passed on to the caller.
 	/// Besides those, this method does not return any errors.
 	/// 
 	/// # Examples
@@ -55,61 +114,488 @@ impl<const SIZE: usize> ReadBuffer<SIZE> {
 	/// # Ok(())
 	/// # }
 	/// ```
-	pub fn read_from(&mut self, source: &mut impl Read) -> Result<&[u8], io::Error> {
-		let length = source.read(&mut self.buffer)?;
+	pub fn read_from(&mut self, source: &mut impl ByteSource) -> Result<&[u8], io::Error> {
+		let range = self.read_from_range(source)?;
+		Ok(&self.buffer[range])
+	}
+	
+	/// Like [`read_from`], but returns a mutable slice referencing the read data,
+	/// so it can be transformed in place.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors from [ByteSource::read_into] are passed on to the caller.
+	/// Besides those, this method does not return any errors.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// let mut reader = [0x21, 0x22, 0x23].as_slice();
+	/// let mut buffer: ReadBuffer<256> = ReadBuffer::new();
+	/// 
+	/// let read_data = buffer.read_from_mut(&mut reader)?;
+	/// read_data.iter_mut().for_each(|byte| *byte ^= 0x20);
+	/// 
+	/// assert_eq!(read_data, [1, 2, 3]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_from`]: ReadBuffer::read_from
+	pub fn read_from_mut(&mut self, source: &mut impl ByteSource) -> Result<&mut [u8], io::Error> {
+		let range = self.read_from_range(source)?;
+		Ok(&mut self.buffer[range])
+	}
+	
+	fn read_from_range(&mut self, source: &mut impl ByteSource) -> Result<Range<usize>, io::Error> {
+		if self.carried_length > 0 {
+			return Ok(self.take_carried_range(SIZE));
+		}
+		
+		self.read_calls += 1;
+		let length = source.read_into(&mut self.buffer[..])?;
+		self.total_bytes_read += length as u64;
+		self.frames_returned += 1;
+		Ok(0..length)
+	}
+	
+	/// Like [`read_from`], but returns a [ReadOutcome] which
+	/// explicitly distinguishes "end of file" from read data.
+	/// 
+	/// A `ReadBuffer<0>` never reports [ReadOutcome::Eof],
+	/// as [ByteSource::read_into] can't signal "end of file" without any space to read into.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors from [ByteSource::read_into] are passed on to the caller.
+	/// Besides those, this method does not return any errors.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::{ReadBuffer, ReadOutcome};
+	/// 
+	/// let mut reader = [1, 2, 3, 4].as_slice();
+	/// let mut buffer: ReadBuffer<256> = ReadBuffer::new();
+	/// 
+	/// loop {
+	///     match buffer.read_from_outcome(&mut reader)? {
+	///         ReadOutcome::Data(data) => assert_eq!(data, [1, 2, 3, 4]),
+	///         ReadOutcome::Eof => break,
+	///     }
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_from`]: ReadBuffer::read_from
+	pub fn read_from_outcome(&mut self, source: &mut impl ByteSource) -> Result<ReadOutcome<'_>, io::Error> {
+		let data = self.read_from(source)?;
+		
+		if data.is_empty() && SIZE > 0 {
+			return Ok(ReadOutcome::Eof);
+		}
+		
+		Ok(ReadOutcome::Data(data))
+	}
+	
+	/// Like [`read_from`], but only offers the first `limit` bytes
+	/// of the internal buffer to [ByteSource::read_into], so no more than that
+	/// is consumed from the given [ByteSource].
+	/// 
+	/// If `limit` is larger than the [`capacity`] of the buffer,
+	/// the whole buffer is used.
+	/// 
+	/// If the length of the returned slice is `0`,
+	/// this indicates that the reader has reached its "end of file"
+	/// as specified for [ByteSource::read_into].  
+	/// (Unless `limit` is `0` or this method is called on a `ReadBuffer<0>`)
+	/// 
+	/// # Errors
+	/// 
+	/// Errors from [ByteSource::read_into] are passed on to the caller.
+	/// Besides those, this method does not return any errors.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// let mut reader = [1, 2, 3, 4, 5].as_slice();
+	/// let mut buffer: ReadBuffer<256> = ReadBuffer::new();
+	/// 
+	/// assert_eq!(buffer.read_from_at_most(&mut reader, 2)?, [1, 2]);
+	/// assert_eq!(buffer.read_from(&mut reader)?, [3, 4, 5]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_from`]: ReadBuffer::read_from
+	/// [`capacity`]: ReadBuffer::capacity
+	pub fn read_from_at_most(&mut self, source: &mut impl ByteSource, limit: usize) -> Result<&[u8], io::Error> {
+		let limit = limit.min(SIZE);
+		
+		if self.carried_length > 0 {
+			return Ok(self.take_carried(limit));
+		}
+		
+		self.read_calls += 1;
+		let length = source.read_into(&mut self.buffer[..limit])?;
+		self.total_bytes_read += length as u64;
+		self.frames_returned += 1;
 		Ok(&self.buffer[..length])
 	}
 	
-	/// Continually calls [Read::read] on the given [Read] as long
+	/// Continually calls [ByteSource::read_into] on the given [ByteSource] as long
 	/// as predicate returns true, filling the internal buffer,
 	/// and returns a slice referencing all the data read over all
-	/// the calls made to [Read::read] or an error if any occurred.
+	/// the calls made to [ByteSource::read_into] or an error if any occurred.
 	/// 
 	/// This function takes a predicate that is called with each
-	/// chunk of data read from [Read::read] and that decides
+	/// chunk of data read from [ByteSource::read_into] and that decides
 	/// whether to keep reading.
 	/// 
 	/// The predicate is **not** called with an empty slice if
-	/// the call to [Read::read] returns a length of 0.
+	/// the call to [ByteSource::read_into] returns a length of 0.
 	/// 
-	/// This function keeps calling [Read::read] on the given [Read]
+	/// This function keeps calling [ByteSource::read_into] on the given [ByteSource]
 	/// until one of the following occurs:
 	/// 
 	/// 1. The predicate returns `false`.
 	/// 1. The internal buffer is full.
-	/// 1. The call to [Read::read] returns a length of 0 indicating "end of file".
-	/// 1. The call to [Read::read] returns an error.
+	/// 1. The call to [ByteSource::read_into] returns a length of 0 indicating "end of file".
+	/// 1. The call to [ByteSource::read_into] returns an error.
 	/// 
 	/// # Errors
 	/// 
-	/// Errors from [Read::read] are passed on to the caller.
+	/// If any error occurs, the data read so far is carried over
+	/// to the following reads.
+	/// 
+	/// Errors from [ByteSource::read_into] are passed on to the caller.
 	/// Besides those, this method does not return any errors.
-	pub fn read_while(&mut self, source: &mut impl Read, mut predicate: impl FnMut(&[u8]) -> bool) -> Result<&[u8], io::Error> {
-		let mut remaining = &mut self.buffer[..];
+	pub fn read_while(&mut self, source: &mut impl ByteSource, mut predicate: impl FnMut(&[u8]) -> bool) -> Result<&[u8], io::Error> {
+		self.compact_carried();
+		let mut filled_length = mem::take(&mut self.carried_length);
 		
 		loop {
-			let length = source.read(remaining)?;
+			self.read_calls += 1;
+			
+			let length = match source.read_into(&mut self.buffer[filled_length..]) {
+				Ok(length) => length,
+				Err(err) => {
+					self.carried_length = filled_length;
+					return Err(err);
+				},
+			};
+			
+			self.total_bytes_read += length as u64;
 			
 			if length == 0 {
 				break;
 			}
 			
-			let chunk: &mut [u8];
-			(chunk, remaining) = remaining.split_at_mut(length);
+			let chunk = &self.buffer[filled_length..filled_length + length];
+			filled_length += length;
 			
-			if !predicate(chunk) || remaining.is_empty() {
+			if !predicate(chunk) || filled_length == SIZE {
 				break;
 			}
 		}
 		
-		let read_bytes = SIZE - remaining.len();
-		Ok(&self.buffer[..read_bytes])
+		self.frames_returned += 1;
+		Ok(&self.buffer[..filled_length])
+	}
+	
+	/// Reads exactly the specified amount of bytes from the given [ByteSource]
+	/// into the internal buffer and returns a slice referencing the read data,
+	/// calling [ByteSource::read_into] as many times as necessary.
+	/// 
+	/// This follows the behavior of [Read::read_exact][std::io::Read::read_exact].
+	/// 
+	/// No more data than requested is read from the given [ByteSource].
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is carried over
+	/// to the following reads.
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before
+	/// the requested amount of bytes could be read,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`]
+	/// is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the requested amount of bytes is larger than
+	/// the [`capacity`] of the buffer.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::Read;
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// // A reader which returns its data in two separate calls to Read::read
+	/// let mut reader = [1, 2].as_slice().chain([3, 4, 5].as_slice());
+	/// let mut buffer: ReadBuffer<256> = ReadBuffer::new();
+	/// 
+	/// let read_data = buffer.read_exact_from(&mut reader, 4)?;
+	/// 
+	/// assert_eq!(read_data, [1, 2, 3, 4]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	/// [`capacity`]: ReadBuffer::capacity
+	pub fn read_exact_from(&mut self, source: &mut impl ByteSource, amount: usize) -> Result<&[u8], io::Error> {
+		assert!(amount <= SIZE, "cannot read more bytes than the capacity of the buffer");
+		
+		if self.fill_carried(source, amount, amount)? {
+			return Err(ErrorKind::UnexpectedEof.into());
+		}
+		
+		Ok(self.take_carried(amount))
+	}
+	
+	/// Reads the specified amount of bytes from the given [ByteSource]
+	/// into the internal buffer and returns a slice referencing the read data.
+	/// 
+	/// Unlike [`read_exact_from`], this may read more data than requested,
+	/// which is carried over to the following reads,
+	/// so fewer calls to [ByteSource::read_into] are made when reading many small values.
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is carried over
+	/// to the following reads.
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before
+	/// the requested amount of bytes could be read,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`]
+	/// is returned following the behavior of [Read::read_exact][std::io::Read::read_exact].
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the requested amount of bytes is larger than
+	/// the [`capacity`] of the buffer.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// let mut reader = [1, 2, 3, 4, 5].as_slice();
+	/// let mut buffer: ReadBuffer<256> = ReadBuffer::new();
+	/// 
+	/// assert_eq!(buffer.read_bytes(&mut reader, 2)?, [1, 2]);
+	/// assert_eq!(buffer.read_bytes(&mut reader, 3)?, [3, 4, 5]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_exact_from`]: ReadBuffer::read_exact_from
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	/// [`capacity`]: ReadBuffer::capacity
+	pub fn read_bytes(&mut self, source: &mut impl ByteSource, amount: usize) -> Result<&[u8], io::Error> {
+		assert!(amount <= SIZE, "cannot read more bytes than the capacity of the buffer");
+		
+		if self.fill_carried(source, amount, SIZE)? {
+			return Err(ErrorKind::UnexpectedEof.into());
+		}
+		
+		Ok(self.take_carried(amount))
+	}
+	
+	/// Keeps reading from the given [ByteSource] into the internal buffer
+	/// until it is full or the [ByteSource] reaches its "end of file"
+	/// and returns a slice referencing the read data,
+	/// along with whether "end of file" was reached.
+	/// 
+	/// Unlike [`read_from`], which returns whatever a single call
+	/// to [ByteSource::read_into] provided, this only returns a partially filled buffer
+	/// at the end of the data, which is what most loops reading a file want.
+	/// 
+	/// If the buffer is filled completely, no further call to [ByteSource::read_into]
+	/// is made to check for "end of file", so `false` is returned
+	/// even if no data is left.  
+	/// (Unless this method is called on a `ReadBuffer<0>`)
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is carried over
+	/// to the following reads.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::Read;
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// // A reader which returns its data in two separate calls to Read::read
+	/// let mut reader = [1, 2].as_slice().chain([3, 4, 5].as_slice());
+	/// let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	/// 
+	/// assert_eq!(buffer.read_fill(&mut reader)?, ([1, 2, 3, 4].as_slice(), false));
+	/// assert_eq!(buffer.read_fill(&mut reader)?, ([5].as_slice(), true));
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_from`]: ReadBuffer::read_from
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_fill(&mut self, source: &mut impl ByteSource) -> Result<(&[u8], bool), io::Error> {
+		let eof = self.fill_carried(source, SIZE, SIZE)?;
+		Ok((self.take_carried(SIZE), eof))
+	}
+	
+	/// Reads from the given [ByteSource] until a delimiter is encountered,
+	/// filling the internal buffer, and returns a slice referencing
+	/// the read data up to and including the delimiter.
+	/// 
+	/// Any data read after the delimiter is carried over to the following reads.
+	/// No heap allocation is made, so the delimiter has to be encountered
+	/// before the buffer is full.
+	/// 
+	/// # Errors
+	/// 
+	/// If any error occurs, the data read so far is carried over
+	/// to the following reads.
+	/// 
+	/// If the buffer is full before the delimiter was encountered,
+	/// an error of the kind [ErrorKind::InvalidData][`InvalidData`]
+	/// wrapping a [BufferFull] is returned. The buffered data can then
+	/// be taken out using [`read_from`].
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before
+	/// the delimiter was encountered, an error of the kind
+	/// [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// let mut reader = "first\nsecond\n".as_bytes();
+	/// let mut buffer: ReadBuffer<64> = ReadBuffer::new();
+	/// 
+	/// assert_eq!(buffer.read_until(&mut reader, b'\n')?, b"first\n");
+	/// assert_eq!(buffer.read_until(&mut reader, b'\n')?, b"second\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_from`]: ReadBuffer::read_from
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_until(&mut self, source: &mut impl ByteSource, delimiter: u8) -> Result<&[u8], io::Error> {
+		let mut searched_length = 0;
+		
+		loop {
+			let carried_end = self.carried_start + self.carried_length;
+			let position = self.buffer[self.carried_start + searched_length..carried_end].iter()
+				.position(|byte| *byte == delimiter);
+			
+			if let Some(position) = position {
+				return Ok(self.take_carried(searched_length + position + 1));
+			}
+			
+			searched_length = self.carried_length;
+			
+			if searched_length == SIZE {
+				return Err(io::Error::new(ErrorKind::InvalidData, BufferFull { capacity: SIZE }));
+			}
+			
+			if self.fill_carried(source, searched_length + 1, SIZE)? {
+				return Err(ErrorKind::UnexpectedEof.into());
+			}
+		}
+	}
+	
+	/// Returns up to the specified amount of carried over bytes,
+	/// counting them as a frame.
+	fn take_carried(&mut self, limit: usize) -> &[u8] {
+		let range = self.take_carried_range(limit);
+		&self.buffer[range]
+	}
+	
+	fn take_carried_range(&mut self, limit: usize) -> Range<usize> {
+		let start = self.carried_start;
+		let length = self.carried_length.min(limit);
+		
+		self.carried_start += length;
+		self.carried_length -= length;
+		self.frames_returned += 1;
+		
+		start..start + length
+	}
+	
+	/// Moves the carried over data to the start of the buffer.
+	fn compact_carried(&mut self) {
+		let carried_end = self.carried_start + self.carried_length;
+		self.buffer.copy_within(self.carried_start..carried_end, 0);
+		self.carried_start = 0;
+	}
+	
+	/// Reads into the buffer after the carried over data, without reading past `limit`,
+	/// until at least `amount` bytes are carried over or "end of file" is reached,
+	/// returning whether "end of file" was reached.
+	fn fill_carried(&mut self, source: &mut impl ByteSource, amount: usize, limit: usize) -> Result<bool, io::Error> {
+		self.compact_carried();
+		
+		while self.carried_length < amount {
+			self.read_calls += 1;
+			
+			match source.read_into(&mut self.buffer[self.carried_length..limit]) {
+				Ok(0) => return Ok(true),
+				Ok(length) => {
+					self.carried_length += length;
+					self.total_bytes_read += length as u64;
+				},
+				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+				Err(err) => return Err(err),
+			}
+		}
+		
+		Ok(false)
 	}
 	
 	/// Returns the capacity of the internal buffer
 	/// which was set using the const generic.
 	/// 
-	/// This can be useful when checking whether a call to [Read::read]
+	/// This can be useful when checking whether a call to [ByteSource::read_into]
 	/// filled the buffer completely or stopped reading early.  
 	/// Using `capacity` in this case avoids having to repeat the capacity
 	/// and possibly forgetting to update it later on.
@@ -136,10 +622,136 @@ impl<const SIZE: usize> ReadBuffer<SIZE> {
 	pub const fn capacity(&self) -> usize {
 		SIZE
 	}
+	
+	/// Returns the total amount of bytes read using this **ReadBuffer**.
+	pub fn total_bytes_read(&self) -> u64 {
+		self.total_bytes_read
+	}
+	
+	/// Returns how many times [ByteSource::read_into] was called using this **ReadBuffer**,
+	/// including calls which returned an error.
+	/// 
+	/// Comparing this with [`total_bytes_read`] shows
+	/// how much data was returned by each call on average.
+	/// 
+	/// [`total_bytes_read`]: ReadBuffer::total_bytes_read
+	pub fn read_calls(&self) -> u64 {
+		self.read_calls
+	}
+	
+	/// Returns how many reads successfully returned data using this **ReadBuffer**,
+	/// counting every method which returns a slice, like [`read_from`],
+	/// [`read_while`], [`read_bytes`], [`read_until`] or [`read_fill`],
+	/// as well as the primitive readers like [`read_u32_le`] built on top of them.
+	/// 
+	/// [`read_from`]: ReadBuffer::read_from
+	/// [`read_while`]: ReadBuffer::read_while
+	/// [`read_bytes`]: ReadBuffer::read_bytes
+	/// [`read_until`]: ReadBuffer::read_until
+	/// [`read_fill`]: ReadBuffer::read_fill
+	/// [`read_u32_le`]: ReadBuffer::read_u32_le
+	pub fn frames_returned(&self) -> u64 {
+		self.frames_returned
+	}
+	
+	/// Discards all of the data carried over to the following reads.
+	/// 
+	/// With the `zeroize` feature, all of the internal buffer is overwritten
+	/// with zeroes, so data like keys and passwords doesn't linger in memory.
+	/// The internal buffer is also wiped when the **ReadBuffer** is dropped.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// let mut reader = "password\nrest".as_bytes();
+	/// let mut buffer: ReadBuffer<16> = ReadBuffer::new();
+	/// 
+	/// assert_eq!(buffer.read_until(&mut reader, b'\n')?, b"password\n");
+	/// buffer.clear();
+	/// 
+	/// assert_eq!(buffer.read_from(&mut reader)?, b"");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn clear(&mut self) {
+		self.carried_start = 0;
+		self.carried_length = 0;
+		
+		#[cfg(feature = "zeroize")]
+		zeroize::Zeroize::zeroize(&mut self.buffer[..]);
+	}
+	
+	/// Returns a value whose [Debug][fmt::Debug] implementation
+	/// includes the data carried over to the following reads.
+	/// 
+	/// The [Debug][fmt::Debug] implementation of **ReadBuffer** itself
+	/// never prints any of the contents of the buffer, so they don't end up in logs
+	/// by accident. This should only be used when the data is known not to be sensitive.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::ReadBuffer;
+	/// 
+	/// let mut reader = "secret\nrest".as_bytes();
+	/// let mut buffer: ReadBuffer<16> = ReadBuffer::new();
+	/// buffer.read_until(&mut reader, b'\n')?;
+	/// 
+	/// assert!(!format!("{buffer:?}").contains("114, 101, 115, 116"));
+	/// assert!(format!("{:?}", buffer.debug_with_contents()).contains("114, 101, 115, 116"));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn debug_with_contents(&self) -> impl fmt::Debug + '_ {
+		DebugFn(|f: &mut fmt::Formatter<'_>| self.fmt_debug(f, true))
+	}
+	
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, with_contents: bool) -> fmt::Result {
+		let mut debug = f.debug_struct("ReadBuffer");
+		debug.field("capacity", &SIZE)
+			.field("carried_start", &self.carried_start)
+			.field("carried_length", &self.carried_length)
+			.field("total_bytes_read", &self.total_bytes_read)
+			.field("read_calls", &self.read_calls)
+			.field("frames_returned", &self.frames_returned);
+		
+		if with_contents {
+			let carried = &self.buffer[self.carried_start..self.carried_start + self.carried_length];
+			debug.field("carried", &carried);
+		}
+		
+		debug.finish_non_exhaustive()
+	}
 }
 
-impl<const SIZE: usize> Default for ReadBuffer<SIZE> {
+#[cfg(feature = "zeroize")]
+impl<const SIZE: usize, A: Alignment> Drop for ReadBuffer<SIZE, A> {
+	fn drop(&mut self) {
+		zeroize::Zeroize::zeroize(&mut self.buffer[..]);
+	}
+}
+
+impl<const SIZE: usize, A: Alignment> fmt::Debug for ReadBuffer<SIZE, A> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
+	}
+}
+
+impl<const SIZE: usize, A: Alignment> Default for ReadBuffer<SIZE, A> {
 	fn default() -> Self {
-		Self::new()
+		Self::new_aligned()
 	}
+}
+
+/// The outcome of [`ReadBuffer::read_from_outcome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadOutcome<'a> {
+	/// Data was read.
+	Data(&'a [u8]),
+	/// The [ByteSource] has reached its "end of file".
+	Eof,
 }
\ No newline at end of file
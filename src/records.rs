@@ -0,0 +1,200 @@
+use std::io::{self, ErrorKind};
+use crate::{ByteSource, DynReadBuffer};
+
+/// A view of a [`DynReadBuffer`] returning the data in records
+/// separated by a delimiter, returned by [`records`].
+/// 
+/// Unlike repeated calls to [`read_until`], reaching the "end of file"
+/// isn't an error: the data after the last delimiter is returned as
+/// a final record if there is any, after which [`next_record`] returns [None].
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`records`]: DynReadBuffer::records
+/// [`read_until`]: DynReadBuffer::read_until
+/// [`next_record`]: Records::next_record
+pub struct Records<'a, R: ByteSource, const INLINE: usize = 0> {
+	buffer: &'a mut DynReadBuffer<R, INLINE>,
+	delimiter: u8,
+	finished: bool,
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Returns [Records] through which the data can be read
+	/// in records ending with the specified delimiter.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "first\nsecond\nlast".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let mut records = buffer.records(b'\n');
+	/// let mut count = 0;
+	/// 
+	/// while let Some(record) = records.next_record() {
+	///     println!("{:?}", record?);
+	///     count += 1;
+	/// }
+	/// 
+	/// assert_eq!(count, 3);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn records(&mut self, delimiter: u8) -> Records<'_, R, INLINE> {
+		Records {
+			buffer: self,
+			delimiter,
+			finished: false,
+		}
+	}
+	
+	/// Turns the **DynReadBuffer** into an [Iterator] of owned frames
+	/// ending with the specified delimiter.
+	/// 
+	/// Frames are returned the same way as by [Records],
+	/// but each of them is copied into a new [Vec],
+	/// so the iterator can be used with all [Iterator] adapters.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "3\n1\n2".as_bytes();
+	/// let buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let frames: Vec<Vec<u8>> = buffer.into_frames(b'\n').collect::<Result<_, _>>()?;
+	/// assert_eq!(frames, [b"3\n".to_vec(), b"1\n".to_vec(), b"2".to_vec()]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn into_frames(self, delimiter: u8) -> IntoFrames<R, INLINE> {
+		IntoFrames {
+			buffer: self,
+			delimiter,
+			finished: false,
+		}
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> Records<'_, R, INLINE> {
+	/// Reads the next record and returns a slice referencing it,
+	/// including the delimiter unless it is the final record
+	/// and the data doesn't end with the delimiter.
+	/// 
+	/// Returns [None] once all data has been returned.
+	/// 
+	/// # Errors
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// The data read so far is preserved, so calling **next_record** again
+	/// continues the record.
+	/// 
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn next_record(&mut self) -> Option<Result<&[u8], io::Error>> {
+		next_record(self.buffer, self.delimiter, &mut self.finished)
+	}
+}
+
+/// A [`DynReadBuffer`] returning the data in frames separated by a delimiter,
+/// created using [`framed`].
+/// 
+/// Frames are returned the same way as by [Records],
+/// but the **Framed** owns the [`DynReadBuffer`],
+/// so it can be created directly from a [Read][std::io::Read].
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`framed`]: crate::ReadBufferExt::framed
+pub struct Framed<R: ByteSource> {
+	buffer: DynReadBuffer<R>,
+	delimiter: u8,
+	finished: bool,
+}
+
+impl<R: ByteSource> Framed<R> {
+	pub(crate) fn new(buffer: DynReadBuffer<R>, delimiter: u8) -> Self {
+		Self {
+			buffer,
+			delimiter,
+			finished: false,
+		}
+	}
+	
+	/// Reads the next frame and returns a slice referencing it.
+	/// 
+	/// This works the same as [`Records::next_record`].
+	pub fn next_frame(&mut self) -> Option<Result<&[u8], io::Error>> {
+		next_record(&mut self.buffer, self.delimiter, &mut self.finished)
+	}
+	
+	/// Returns the underlying [`DynReadBuffer`],
+	/// which still holds all data that hasn't been returned as a frame.
+	/// 
+	/// [`DynReadBuffer`]: crate::DynReadBuffer
+	pub fn into_inner(self) -> DynReadBuffer<R> {
+		self.buffer
+	}
+}
+
+/// An [Iterator] of owned frames separated by a delimiter,
+/// created using [`into_frames`].
+/// 
+/// [`into_frames`]: DynReadBuffer::into_frames
+pub struct IntoFrames<R: ByteSource, const INLINE: usize = 0> {
+	buffer: DynReadBuffer<R, INLINE>,
+	delimiter: u8,
+	finished: bool,
+}
+
+impl<R: ByteSource, const INLINE: usize> IntoFrames<R, INLINE> {
+	/// Returns the underlying [`DynReadBuffer`],
+	/// which still holds all data that hasn't been returned as a frame.
+	/// 
+	/// [`DynReadBuffer`]: crate::DynReadBuffer
+	pub fn into_inner(self) -> DynReadBuffer<R, INLINE> {
+		self.buffer
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> Iterator for IntoFrames<R, INLINE> {
+	type Item = Result<Vec<u8>, io::Error>;
+	
+	/// Reads the next frame and returns it as a [Vec].
+	/// 
+	/// This works the same as [`Records::next_record`].
+	fn next(&mut self) -> Option<Self::Item> {
+		next_record(&mut self.buffer, self.delimiter, &mut self.finished)
+			.map(|record| record.map(<[u8]>::to_vec))
+	}
+}
+
+fn next_record<'a, R: ByteSource, const INLINE: usize>(
+	buffer: &'a mut DynReadBuffer<R, INLINE>,
+	delimiter: u8,
+	finished: &mut bool,
+) -> Option<Result<&'a [u8], io::Error>> {
+	if *finished {
+		return None;
+	}
+	
+	let length = match buffer.fill_buffer_until_with("records", delimiter, |_| Ok(())) {
+		Ok(position) => position + 1,
+		Err(err) if err.kind() == ErrorKind::UnexpectedEof => {
+			*finished = true;
+			
+			match buffer.available().len() {
+				0 => return None,
+				length => length,
+			}
+		},
+		Err(err) => return Some(Err(err)),
+	};
+	
+	Some(Ok(buffer.take(length)))
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use std::io::{self, ErrorKind};
+use regex_automata::Input;
+use regex_automata::dfa::Automaton;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads from the given [ByteSource] until the given DFA finds a match
+	/// and returns a slice referencing the data up to and including the match.
+	/// 
+	/// The DFA is run over the data as it is read, so every byte is only examined once
+	/// and matches spanning multiple calls to [ByteSource::read_into] are found as well.
+	/// The returned frame ends where the first match is detected, so for delimiters
+	/// which can match different lengths at the same position, like `\n+`,
+	/// the shortest one is used.
+	/// 
+	/// Any `regex_automata` DFA can be used, for example a
+	/// [dense DFA][`dense::DFA`] built using [`dense::DFA::new`].
+	/// 
+	/// Requires the `regex` feature.
+	/// 
+	/// # Errors
+	/// 
+	/// If the DFA can't search the data, for example because it was configured
+	/// to give up on certain bytes, an error of the kind
+	/// [ErrorKind::InvalidInput][`InvalidInput`] is returned.
+	/// 
+	/// All other errors are handled the same way as in [`read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// use regex_automata::dfa::dense::DFA;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "first\r\nsecond\nthird".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let delimiter = DFA::new(r"\r?\n")?;
+	/// 
+	/// assert_eq!(buffer.read_until_match(&delimiter)?, b"first\r\n");
+	/// assert_eq!(buffer.read_until_match(&delimiter)?, b"second\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`dense::DFA`]: regex_automata::dfa::dense::DFA
+	/// [`dense::DFA::new`]: regex_automata::dfa::dense::DFA::new
+	/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+	/// [`read_until`]: DynReadBuffer::read_until
+	pub fn read_until_match(&mut self, dfa: &impl Automaton) -> Result<&[u8], io::Error> {
+		let invalid = |err| io::Error::new(ErrorKind::InvalidInput, err);
+		let mut state = dfa.start_state_forward(&Input::new(&[])).map_err(invalid)?;
+		let mut searched_length = 0;
+		
+		loop {
+			// match states are entered one byte after the end of the match
+			for (index, byte) in self.buffer.filled().iter().enumerate().skip(searched_length) {
+				state = dfa.next_state(state, *byte);
+				
+				if dfa.is_match_state(state) {
+					return Ok(self.take(index));
+				}
+				
+				if dfa.is_quit_state(state) {
+					return Err(io::Error::new(ErrorKind::InvalidInput, "DFA gave up on the data"));
+				}
+			}
+			
+			searched_length = self.buffer.len();
+			
+			if self.fill_buffer()? == 0 {
+				if dfa.is_match_state(dfa.next_eoi_state(state)) {
+					return Ok(self.take(searched_length));
+				}
+				
+				return Err(self.unexpected_eof("read_until_match", None));
+			}
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,179 @@
+use std::io::{self, ErrorKind, Read, Write};
+use crate::DynReadBuffer;
+
+const DATA_TAG: u8 = 0;
+const ERROR_TAG: u8 = 1;
+
+/// The error kinds which can be recorded, indexed by their code.
+/// Any other kind is recorded as [ErrorKind::Other].
+const ERROR_KINDS: [ErrorKind; 20] = [
+	ErrorKind::Other,
+	ErrorKind::NotFound,
+	ErrorKind::PermissionDenied,
+	ErrorKind::ConnectionRefused,
+	ErrorKind::ConnectionReset,
+	ErrorKind::ConnectionAborted,
+	ErrorKind::NotConnected,
+	ErrorKind::AddrInUse,
+	ErrorKind::AddrNotAvailable,
+	ErrorKind::BrokenPipe,
+	ErrorKind::AlreadyExists,
+	ErrorKind::WouldBlock,
+	ErrorKind::InvalidInput,
+	ErrorKind::InvalidData,
+	ErrorKind::TimedOut,
+	ErrorKind::WriteZero,
+	ErrorKind::Interrupted,
+	ErrorKind::Unsupported,
+	ErrorKind::UnexpectedEof,
+	ErrorKind::OutOfMemory,
+];
+
+/// A [Read] adapter which records every chunk of data and every error
+/// returned by the wrapped [Read] to the given [Write],
+/// so it can be played back exactly using a [ReplayReader].
+/// 
+/// Only the [ErrorKind] of an error is recorded, not its message.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use read_buffer::{DynReadBuffer, RecordingReader, ReplayReader};
+/// 
+/// let reader = "Hello\nWorld\n".as_bytes();
+/// let mut recording = Vec::new();
+/// 
+/// let mut buffer = DynReadBuffer::new(RecordingReader::new(reader, &mut recording));
+/// assert_eq!(buffer.read_until(b'\n')?, b"Hello\n");
+/// assert_eq!(buffer.read_until(b'\n')?, b"World\n");
+/// drop(buffer);
+/// 
+/// let mut buffer = DynReadBuffer::new(ReplayReader::new(recording.as_slice()));
+/// assert_eq!(buffer.read_until(b'\n')?, b"Hello\n");
+/// assert_eq!(buffer.read_until(b'\n')?, b"World\n");
+/// # Ok(())
+/// # }
+/// ```
+pub struct RecordingReader<R: Read, W: Write> {
+	reader: R,
+	recording: W,
+}
+
+impl<R: Read, W: Write> RecordingReader<R, W> {
+	/// Creates a new **RecordingReader** reading from the given [Read]
+	/// and recording to the given [Write].
+	pub fn new(reader: R, recording: W) -> Self {
+		Self {
+			reader,
+			recording,
+		}
+	}
+	
+	/// Returns the wrapped [Read] and [Write].
+	pub fn into_inner(self) -> (R, W) {
+		(self.reader, self.recording)
+	}
+}
+
+impl<R: Read, W: Write> Read for RecordingReader<R, W> {
+	/// Reads from the wrapped [Read] and records the result.
+	/// 
+	/// If recording fails, the error from the [Write] is returned instead
+	/// and the read data is lost.
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		match self.reader.read(buf) {
+			Ok(length) => {
+				let data = &buf[..length];
+				let encoded_length = u32::try_from(length)
+					.map_err(|_| io::Error::new(ErrorKind::InvalidInput, "chunk is too large to be recorded"))?;
+				
+				self.recording.write_all(&[DATA_TAG])?;
+				self.recording.write_all(&encoded_length.to_le_bytes())?;
+				self.recording.write_all(data)?;
+				Ok(length)
+			},
+			Err(err) => {
+				let code = ERROR_KINDS.iter()
+					.position(|kind| *kind == err.kind())
+					.unwrap_or(0) as u8;
+				
+				self.recording.write_all(&[ERROR_TAG, code])?;
+				Err(err)
+			},
+		}
+	}
+}
+
+/// A [Read] which plays back a recording made by a [RecordingReader],
+/// returning the same chunks of data and the same kinds of errors
+/// in the same order.
+/// 
+/// Chunks are only split up further if they don't fit into
+/// the buffer passed to [Read::read]. Once the recording is exhausted,
+/// the **ReplayReader** reports "end of file".
+/// 
+/// # Errors
+/// 
+/// If the recording is malformed, an error of the kind
+/// [ErrorKind::InvalidData][`InvalidData`] is returned.
+/// Errors from reading the recording itself are passed on to the caller.
+/// 
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+pub struct ReplayReader<R: Read> {
+	recording: DynReadBuffer<R>,
+	remaining_chunk: usize,
+}
+
+impl<R: Read> ReplayReader<R> {
+	/// Creates a new **ReplayReader** playing back the given recording.
+	pub fn new(recording: R) -> Self {
+		Self {
+			recording: DynReadBuffer::new(recording),
+			remaining_chunk: 0,
+		}
+	}
+	
+	fn read_tag(&mut self) -> Result<Option<u8>, io::Error> {
+		match self.recording.read_u8() {
+			Ok(tag) => Ok(Some(tag)),
+			Err(err) if err.kind() == ErrorKind::UnexpectedEof && self.recording.available().is_empty() => Ok(None),
+			Err(err) => Err(err),
+		}
+	}
+}
+
+impl<R: Read> Read for ReplayReader<R> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let invalid = |err: io::Error| match err.kind() {
+			ErrorKind::UnexpectedEof => io::Error::new(ErrorKind::InvalidData, "recording ends in the middle of a record"),
+			_ => err,
+		};
+		
+		if self.remaining_chunk == 0 {
+			match self.read_tag()? {
+				None => return Ok(0),
+				Some(DATA_TAG) => {
+					self.remaining_chunk = self.recording.read_u32_le().map_err(invalid)? as usize;
+					
+					if self.remaining_chunk == 0 {
+						return Ok(0);
+					}
+				},
+				Some(ERROR_TAG) => {
+					let code = self.recording.read_u8().map_err(invalid)?;
+					let kind = ERROR_KINDS.get(usize::from(code))
+						.ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "recording contains an unknown error kind"))?;
+					return Err(io::Error::new(*kind, "replayed error"));
+				},
+				Some(_) => return Err(io::Error::new(ErrorKind::InvalidData, "recording contains an unknown record")),
+			}
+		}
+		
+		let length = buf.len().min(self.remaining_chunk);
+		let data = self.recording.read_bytes(length).map_err(invalid)?;
+		buf[..length].copy_from_slice(data);
+		self.remaining_chunk -= length;
+		Ok(length)
+	}
+}
\ No newline at end of file
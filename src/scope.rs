@@ -0,0 +1,118 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+/// A view of a [`DynReadBuffer`] limited to a specified amount of bytes,
+/// returned by [`take_scope`].
+/// 
+/// Reads through a **Scope** behave as if the given [ByteSource] reached its
+/// "end of file" at the end of the scope. Any data after the scope which
+/// has already been read into the buffer is preserved for future reads
+/// on the [`DynReadBuffer`].
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`take_scope`]: DynReadBuffer::take_scope
+pub struct Scope<'a, R: ByteSource, const INLINE: usize = 0> {
+	buffer: &'a mut DynReadBuffer<R, INLINE>,
+	remaining: usize,
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Returns a [Scope] through which at most the specified amount of bytes
+	/// can be read.
+	/// 
+	/// This is useful for safely parsing a length-prefixed section
+	/// made up of smaller fields, as no read can extend past its end.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::ErrorKind;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [6, b'a', b',', b'b', b'c', b',', b'd', b'e'].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let length = buffer.read_u8()?;
+	/// let mut scope = buffer.take_scope(length.into());
+	/// 
+	/// assert_eq!(scope.read_until(b',')?, b"a,");
+	/// assert_eq!(scope.read_until(b',')?, b"bc,");
+	/// assert_eq!(scope.read_until(b',').unwrap_err().kind(), ErrorKind::UnexpectedEof);
+	/// assert_eq!(scope.read_bytes(1)?, b"d");
+	/// 
+	/// assert_eq!(buffer.read_bytes(1)?, b"e");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn take_scope(&mut self, amount: usize) -> Scope<'_, R, INLINE> {
+		Scope {
+			buffer: self,
+			remaining: amount,
+		}
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> Scope<'_, R, INLINE> {
+	/// The amount of bytes which can still be read through this scope.
+	pub fn remaining(&self) -> usize {
+		self.remaining
+	}
+	
+	/// Like [`DynReadBuffer::read_bytes`], but fails if the requested amount of bytes
+	/// extends past the end of the scope.
+	/// 
+	/// # Errors
+	/// 
+	/// If the requested amount of bytes is more than [`remaining`],
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`]
+	/// is returned without reading anything.
+	/// 
+	/// All other errors are handled the same way as in [`DynReadBuffer::read_bytes`].
+	/// 
+	/// [`remaining`]: Scope::remaining
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	pub fn read_bytes(&mut self, amount: usize) -> Result<&[u8], io::Error> {
+		if amount > self.remaining {
+			return Err(self.buffer.unexpected_eof("read_bytes", Some(amount)));
+		}
+		
+		let data = self.buffer.read_bytes(amount)?;
+		self.remaining -= amount;
+		Ok(data)
+	}
+	
+	/// Like [`DynReadBuffer::read_until`], but fails if the delimiter
+	/// is not found before the end of the scope.
+	/// 
+	/// # Errors
+	/// 
+	/// If the end of the scope is reached before the delimiter,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`]
+	/// is returned. The data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// All other errors are handled the same way as in [`DynReadBuffer::read_until`].
+	/// 
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	pub fn read_until(&mut self, delimiter: u8) -> Result<&[u8], io::Error> {
+		let mut searched_length = 0;
+		
+		loop {
+			if let Some(position) = self.buffer.buffer.find(delimiter, searched_length) {
+				if position >= self.remaining {
+					return Err(self.buffer.unexpected_eof("read_until", None));
+				}
+				
+				self.remaining -= position + 1;
+				return Ok(self.buffer.take(position + 1));
+			}
+			
+			searched_length = self.buffer.buffer.len();
+			
+			if searched_length >= self.remaining || self.buffer.fill_buffer()? == 0 {
+				return Err(self.buffer.unexpected_eof("read_until", None));
+			}
+		}
+	}
+}
\ No newline at end of file
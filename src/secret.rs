@@ -0,0 +1,196 @@
+use std::alloc::Layout;
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+use crate::{ByteSource, DynReadBuffer};
+use crate::allocator::BufferAllocator;
+use crate::buffer::Buffer;
+
+/// A [`DynReadBuffer`] for reading sensitive data like passphrases and key material,
+/// whose internal buffer is locked into memory and wiped when it is no longer used.
+/// 
+/// The internal buffer is locked using `mlock` on Unix and `VirtualLock` on Windows,
+/// so it is never written to swap, and it is overwritten with zeroes
+/// when the **SecretReadBuffer** is dropped or [`clear`] is called.
+/// 
+/// All methods of the [`DynReadBuffer`] can be used through a **SecretReadBuffer**,
+/// so it can replace one without changing the code reading from it.
+/// Some methods like [`read_utf16_line`] use additional storage
+/// which is not locked into memory.
+/// 
+/// If the internal buffer needs to grow beyond the amount of memory
+/// the process is allowed to lock, the allocation fails
+/// and the process is aborted, like with any other failed allocation.
+/// 
+/// Requires the `secret` feature.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use read_buffer::SecretReadBuffer;
+/// 
+/// let reader = "hunter2\n".as_bytes();
+/// let mut buffer = SecretReadBuffer::new(reader)?;
+/// 
+/// assert_eq!(buffer.read_until(b'\n')?, b"hunter2\n");
+/// # Ok(())
+/// # }
+/// ```
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`clear`]: DynReadBuffer::clear
+/// [`read_utf16_line`]: DynReadBuffer::read_utf16_line
+pub struct SecretReadBuffer<R: ByteSource> {
+	buffer: DynReadBuffer<R>,
+}
+
+impl<R: ByteSource> SecretReadBuffer<R> {
+	/// Creates a new **SecretReadBuffer** to read from the given [ByteSource]
+	/// with an internal buffer of a single page.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error of the kind [ErrorKind::OutOfMemory][`OutOfMemory`]
+	/// if the internal buffer can't be allocated or locked into memory.
+	/// 
+	/// [`OutOfMemory`]: std::io::ErrorKind::OutOfMemory
+	pub fn new(reader: R) -> Result<Self, io::Error> {
+		Self::with_capacity(reader, page_size())
+	}
+	
+	/// Creates a new **SecretReadBuffer** to read from the given [ByteSource]
+	/// with an internal buffer of at least the specified capacity.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error of the kind [ErrorKind::OutOfMemory][`OutOfMemory`]
+	/// if the internal buffer can't be allocated or locked into memory.
+	/// 
+	/// [`OutOfMemory`]: std::io::ErrorKind::OutOfMemory
+	pub fn with_capacity(reader: R, capacity: usize) -> Result<Self, io::Error> {
+		let buffer = Buffer::try_with_capacity_in(capacity, BufferAllocator::new(LockedAllocator))
+			.map_err(|_| io::Error::new(ErrorKind::OutOfMemory, "failed to allocate locked memory"))?;
+		
+		Ok(Self {
+			buffer: DynReadBuffer::from_buffer(reader, buffer),
+		})
+	}
+}
+
+impl<R: ByteSource> Deref for SecretReadBuffer<R> {
+	type Target = DynReadBuffer<R>;
+	
+	fn deref(&self) -> &Self::Target {
+		&self.buffer
+	}
+}
+
+impl<R: ByteSource> DerefMut for SecretReadBuffer<R> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.buffer
+	}
+}
+
+impl<R: ByteSource> fmt::Debug for SecretReadBuffer<R> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("SecretReadBuffer")
+			.field(&self.buffer)
+			.finish()
+	}
+}
+
+/// An allocator which locks all of its allocations into memory.
+/// 
+/// Allocations are rounded up to whole pages, so unlocking one
+/// never unlocks memory belonging to another allocation.
+struct LockedAllocator;
+
+impl LockedAllocator {
+	fn page_layout(layout: Layout) -> Result<Layout, AllocError> {
+		let page_size = page_size();
+		let size = layout.size().checked_next_multiple_of(page_size).ok_or(AllocError)?;
+		Layout::from_size_align(size, layout.align().max(page_size)).map_err(|_| AllocError)
+	}
+}
+
+// SAFETY: all memory is allocated and deallocated by the global allocator
+// using the same page layout derived from the requested layout
+unsafe impl Allocator for LockedAllocator {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		let page_layout = Self::page_layout(layout)?;
+		let memory = Global.allocate(page_layout)?;
+		
+		if page_layout.size() > 0 && !lock(memory.cast(), page_layout.size()) {
+			// SAFETY: the memory was just allocated with this layout
+			unsafe {
+				Global.deallocate(memory.cast(), page_layout);
+			}
+			
+			return Err(AllocError);
+		}
+		
+		Ok(memory)
+	}
+	
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		let page_layout = Self::page_layout(layout)
+			.expect("layout was already rounded when allocating");
+		
+		if page_layout.size() > 0 {
+			unlock(ptr, page_layout.size());
+		}
+		
+		Global.deallocate(ptr, page_layout)
+	}
+}
+
+#[cfg(unix)]
+fn page_size() -> usize {
+	// SAFETY: sysconf has no preconditions
+	let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+	usize::try_from(page_size).unwrap_or(4096)
+}
+
+#[cfg(windows)]
+fn page_size() -> usize {
+	use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+	
+	// SAFETY: GetSystemInfo fills in the whole struct, for which all zeroes are valid
+	let info = unsafe {
+		let mut info: SYSTEM_INFO = std::mem::zeroed();
+		GetSystemInfo(&mut info);
+		info
+	};
+	info.dwPageSize as usize
+}
+
+#[cfg(unix)]
+fn lock(ptr: NonNull<u8>, length: usize) -> bool {
+	// SAFETY: the range lies within a single allocation
+	unsafe { libc::mlock(ptr.as_ptr().cast(), length) == 0 }
+}
+
+#[cfg(unix)]
+fn unlock(ptr: NonNull<u8>, length: usize) {
+	// SAFETY: the range lies within a single allocation
+	unsafe {
+		libc::munlock(ptr.as_ptr().cast(), length);
+	}
+}
+
+#[cfg(windows)]
+fn lock(ptr: NonNull<u8>, length: usize) -> bool {
+	// SAFETY: the range lies within a single allocation
+	unsafe { windows_sys::Win32::System::Memory::VirtualLock(ptr.as_ptr().cast(), length) != 0 }
+}
+
+#[cfg(windows)]
+fn unlock(ptr: NonNull<u8>, length: usize) {
+	// SAFETY: the range lies within a single allocation
+	unsafe {
+		windows_sys::Win32::System::Memory::VirtualUnlock(ptr.as_ptr().cast(), length);
+	}
+}
\ No newline at end of file
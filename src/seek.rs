@@ -0,0 +1,71 @@
+use std::io::{self, ErrorKind, Seek, SeekFrom};
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource + Seek, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Seeks relative to the current position in the data,
+	/// like [`BufReader::seek_relative`].
+	/// 
+	/// Seeks within the buffered data, as well as short backward seeks
+	/// into data which was already returned but is still stored
+	/// in the internal buffer, don't call [Seek::seek].
+	/// Otherwise the given [ByteSource] is seeked and the internal buffer is discarded,
+	/// along with the mark set using [`mark`].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors from [Seek::seek] are passed on to the caller.
+	/// If the resulting offset would overflow, an error of the kind
+	/// [ErrorKind::InvalidInput][`InvalidInput`] is returned.
+	/// In both cases the position is unchanged.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::Cursor;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = Cursor::new(b"Hello World".to_vec());
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_bytes(5)?, b"Hello");
+	/// buffer.seek_relative(-3)?;
+	/// assert_eq!(buffer.read_bytes(3)?, b"llo");
+	/// buffer.seek_relative(1)?;
+	/// assert_eq!(buffer.read_bytes(5)?, b"World");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`BufReader::seek_relative`]: std::io::BufReader::seek_relative
+	/// [`mark`]: DynReadBuffer::mark
+	/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+	pub fn seek_relative(&mut self, offset: i64) -> Result<(), io::Error> {
+		let buffered_length = self.buffer.len();
+		
+		match usize::try_from(offset) {
+			Ok(forward) if forward <= buffered_length => {
+				self.buffer.consume(forward);
+				return Ok(());
+			},
+			Err(_) => {
+				let backward = usize::try_from(offset.unsigned_abs()).unwrap_or(usize::MAX);
+				
+				if backward <= self.buffer.retained() {
+					self.buffer.unconsume(backward);
+					return Ok(());
+				}
+			},
+			Ok(_) => (),
+		}
+		
+		// the reader is positioned after the buffered data
+		let reader_offset = i64::try_from(buffered_length).ok()
+			.and_then(|buffered_length| offset.checked_sub(buffered_length))
+			.ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "seek offset overflows"))?;
+		
+		self.reader.seek(SeekFrom::Current(reader_offset))?;
+		self.buffer.discard();
+		Ok(())
+	}
+}
\ No newline at end of file
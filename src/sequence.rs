@@ -0,0 +1,97 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads from the given [ByteSource] until the delimiter sequence is found
+	/// and returns a slice referencing the data up to and including it.
+	/// 
+	/// The search is streaming: every byte is only examined once,
+	/// and matches spanning multiple calls to [ByteSource::read_into] are found as well.
+	/// An empty delimiter matches immediately, returning an empty slice.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_until`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\nbody".as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let head = buffer.read_until_seq(b"\r\n\r\n")?;
+	/// assert!(head.starts_with(b"GET / HTTP/1.1\r\n"));
+	/// assert_eq!(buffer.read_bytes(4)?, b"body");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	pub fn read_until_seq(&mut self, delimiter: &[u8]) -> Result<&[u8], io::Error> {
+		if delimiter.is_empty() {
+			return Ok(self.take(0));
+		}
+		
+		let failure = failure_table(delimiter);
+		let mut matched_length = 0;
+		let mut searched_length = 0;
+		
+		loop {
+			for (index, byte) in self.buffer.filled().iter().enumerate().skip(searched_length) {
+				while matched_length > 0 && delimiter[matched_length] != *byte {
+					matched_length = failure[matched_length - 1];
+				}
+				
+				if delimiter[matched_length] == *byte {
+					matched_length += 1;
+				}
+				
+				if matched_length == delimiter.len() {
+					return Ok(self.take(index + 1));
+				}
+			}
+			
+			searched_length = self.buffer.len();
+			
+			if self.fill_buffer()? == 0 {
+				return Err(self.unexpected_eof("read_until_seq", None));
+			}
+		}
+	}
+	
+	/// Like [`read_until_seq`], but takes the delimiter as a [str].
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_until`].
+	/// 
+	/// [`read_until_seq`]: DynReadBuffer::read_until_seq
+	/// [`read_until`]: DynReadBuffer::read_until
+	pub fn read_until_str(&mut self, delimiter: &str) -> Result<&[u8], io::Error> {
+		self.read_until_seq(delimiter.as_bytes())
+	}
+}
+
+/// The Knuth-Morris-Pratt failure table: for each prefix of the delimiter,
+/// the length of its longest proper prefix which is also a suffix of it.
+fn failure_table(delimiter: &[u8]) -> Vec<usize> {
+	let mut failure = vec![0; delimiter.len()];
+	let mut length = 0;
+	
+	for index in 1..delimiter.len() {
+		while length > 0 && delimiter[index] != delimiter[length] {
+			length = failure[length - 1];
+		}
+		
+		if delimiter[index] == delimiter[length] {
+			length += 1;
+		}
+		
+		failure[index] = length;
+	}
+	
+	failure
+}
\ No newline at end of file
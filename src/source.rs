@@ -0,0 +1,83 @@
+use std::io::{self, Read};
+#[cfg(feature = "nightly")]
+use std::io::BorrowedCursor;
+
+/// A source of bytes which the buffer types read from.
+/// 
+/// This is implemented for every [Read], so usually it doesn't need
+/// to be considered at all. Sources which don't fit [Read],
+/// like callbacks from a foreign library, ring buffers filled by a driver
+/// or the input of a fuzzer, can implement it directly to use
+/// all the framing logic of the buffer types.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use std::io;
+/// use read_buffer::{ByteSource, DynReadBuffer};
+/// 
+/// /// Returns the bytes of a counter, one at a time.
+/// struct Counter(u8);
+/// 
+/// impl ByteSource for Counter {
+///     fn read_into(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+///         if buf.is_empty() {
+///             return Ok(0);
+///         }
+/// 
+///         buf[0] = self.0;
+///         self.0 += 1;
+///         Ok(1)
+///     }
+/// }
+/// 
+/// let mut buffer = DynReadBuffer::new(Counter(0));
+/// assert_eq!(buffer.read_bytes(3)?, [0, 1, 2]);
+/// assert_eq!(buffer.read_until(4)?, [3, 4]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait ByteSource {
+	/// Reads some bytes into the given buffer and returns how many were read,
+	/// following the same contract as [Read::read].
+	/// 
+	/// Returning `Ok(0)` for a non-empty buffer signals the "end of file".
+	/// Errors of the kind [ErrorKind::Interrupted][`Interrupted`] are retried
+	/// and errors of the kind [ErrorKind::WouldBlock][`WouldBlock`] are handled
+	/// by the non-blocking methods, the same way as when they come from a [Read].
+	/// 
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	fn read_into(&mut self, buf: &mut [u8]) -> Result<usize, io::Error>;
+	
+	/// Reads some bytes into the given cursor, following the same contract
+	/// as [Read::read_buf].
+	/// 
+	/// The default implementation initializes the cursor and calls [`ByteSource::read_into`].
+	#[cfg(feature = "nightly")]
+	fn read_into_cursor(&mut self, mut cursor: BorrowedCursor<'_>) -> Result<(), io::Error> {
+		let capacity = cursor.capacity();
+		let amount_read = self.read_into(cursor.ensure_init())?;
+		assert!(amount_read <= capacity, "cannot read more bytes than fit into the buffer");
+		
+		// SAFETY: ensure_init initialized the whole cursor
+		// and no more bytes than its capacity are marked as filled
+		unsafe {
+			cursor.advance(amount_read);
+		}
+		
+		Ok(())
+	}
+}
+
+impl<R: Read + ?Sized> ByteSource for R {
+	fn read_into(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+		self.read(buf)
+	}
+	
+	#[cfg(feature = "nightly")]
+	fn read_into_cursor(&mut self, cursor: BorrowedCursor<'_>) -> Result<(), io::Error> {
+		self.read_buf(cursor)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,192 @@
+use std::ptr::NonNull;
+use crate::{ByteSource, DynReadBuffer};
+
+/// Spare capacity of a [`DynReadBuffer`] being filled by an asynchronous operation
+/// outside of Rust, like an overlapped read on Windows, created using
+/// [`DynReadBuffer::begin_fill`].
+/// 
+/// The **DynReadBuffer** stays mutably borrowed while the **PendingFill** exists,
+/// so the memory at [`as_mut_ptr`] is neither moved nor reallocated
+/// until the fill is completed using [`assume_filled`] or the **PendingFill** is dropped.
+/// 
+/// Dropping the **PendingFill** without calling [`assume_filled`]
+/// adds no data to the buffer.
+/// 
+/// [`as_mut_ptr`]: PendingFill::as_mut_ptr
+/// [`assume_filled`]: PendingFill::assume_filled
+pub struct PendingFill<'a, R: ByteSource, const INLINE: usize = 0> {
+	buffer: &'a mut DynReadBuffer<R, INLINE>,
+	start: NonNull<u8>,
+	length: usize,
+}
+
+impl<R: ByteSource, const INLINE: usize> PendingFill<'_, R, INLINE> {
+	/// Returns a pointer to the start of the spare capacity,
+	/// which stays valid for writes of [`len`] bytes
+	/// for as long as this **PendingFill** exists.
+	/// 
+	/// [`len`]: PendingFill::len
+	pub fn as_mut_ptr(&self) -> *mut u8 {
+		self.start.as_ptr()
+	}
+	
+	/// Returns the amount of bytes which can be written to [`as_mut_ptr`].
+	/// 
+	/// [`as_mut_ptr`]: PendingFill::as_mut_ptr
+	pub fn len(&self) -> usize {
+		self.length
+	}
+	
+	/// Returns `true` if no bytes can be written to [`as_mut_ptr`].
+	/// 
+	/// [`as_mut_ptr`]: PendingFill::as_mut_ptr
+	pub fn is_empty(&self) -> bool {
+		self.length == 0
+	}
+	
+	/// Completes the fill, adding the specified amount of bytes
+	/// at the start of the spare capacity to the buffered data.
+	/// 
+	/// # Safety
+	/// 
+	/// The first `amount` bytes at [`as_mut_ptr`] must have been written
+	/// since this **PendingFill** was created.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the specified amount of bytes is larger than [`len`].
+	/// 
+	/// [`as_mut_ptr`]: PendingFill::as_mut_ptr
+	/// [`len`]: PendingFill::len
+	pub unsafe fn assume_filled(self, amount: usize) {
+		assert!(amount <= self.length, "cannot fill more data than there is space");
+		
+		// SAFETY: the caller guarantees the bytes were written since begin_fill,
+		// which is when the spare capacity was last handed out
+		unsafe {
+			self.buffer.assume_filled(amount);
+		}
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Ensures that [`spare_capacity_mut`] is at least the specified amount of bytes long,
+	/// growing the internal buffer if necessary.
+	/// 
+	/// [`spare_capacity_mut`]: DynReadBuffer::spare_capacity_mut
+	pub fn reserve(&mut self, amount: usize) {
+		let capacity = self.buffer.capacity();
+		self.buffer.reserve(amount);
+		self.notify_grow(capacity);
+	}
+	
+	/// Returns the space in the internal buffer after the buffered data,
+	/// so it can be filled by code which doesn't go through a [ByteSource],
+	/// like a foreign function writing through a raw pointer.
+	/// 
+	/// After filling a prefix of the returned slice, call [`assume_filled`]
+	/// to add it to the buffered data, which can then be read
+	/// using any of the usual methods.
+	/// 
+	/// The returned slice is zeroed, so data which was already consumed
+	/// is never exposed through it.
+	/// Use [`reserve`] to ensure the slice is long enough.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let mut buffer = DynReadBuffer::new(io::empty());
+	/// 
+	/// buffer.reserve(5);
+	/// let spare = buffer.spare_capacity_mut();
+	/// spare[..5].copy_from_slice(b"ab\ncd");
+	/// // SAFETY: 4 bytes were written to the spare capacity
+	/// unsafe {
+	///     buffer.assume_filled(4);
+	/// }
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"ab\n");
+	/// assert_eq!(buffer.read_bytes(1)?, b"c");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`assume_filled`]: DynReadBuffer::assume_filled
+	/// [`reserve`]: DynReadBuffer::reserve
+	pub fn spare_capacity_mut(&mut self) -> &mut [u8] {
+		let spare = self.buffer.spare_mut();
+		spare.fill(0);
+		spare
+	}
+	
+	/// Adds the specified amount of bytes at the start of
+	/// [`spare_capacity_mut`] to the buffered data.
+	/// 
+	/// # Safety
+	/// 
+	/// The first `amount` bytes must have been written
+	/// since the last call to [`spare_capacity_mut`] or [`begin_fill`].
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the specified amount of bytes is larger than
+	/// the length of [`spare_capacity_mut`].
+	/// 
+	/// [`spare_capacity_mut`]: DynReadBuffer::spare_capacity_mut
+	/// [`begin_fill`]: DynReadBuffer::begin_fill
+	pub unsafe fn assume_filled(&mut self, amount: usize) {
+		self.buffer.commit(amount);
+	}
+	
+	/// Begins filling the spare capacity through an operation which
+	/// completes asynchronously, like an overlapped read on Windows,
+	/// ensuring that at least the specified amount of bytes can be filled.
+	/// 
+	/// The returned [PendingFill] provides a pointer which stays valid
+	/// until the operation is completed using [`PendingFill::assume_filled`],
+	/// allowing the operating system to write directly into the internal buffer
+	/// without any copies.
+	/// As with [`DynReadBuffer::spare_capacity_mut`], the space is zeroed first.
+	/// 
+	/// The operation has to be completed or cancelled before the [PendingFill]
+	/// is dropped, as the memory may be reused or deallocated afterwards.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let mut buffer = DynReadBuffer::new(io::empty());
+	/// 
+	/// let fill = buffer.begin_fill(3);
+	/// // a completion port would write to this pointer
+	/// let pointer = fill.as_mut_ptr();
+	/// // SAFETY: the pointer is valid for 3 bytes, which are all written
+	/// unsafe {
+	///     pointer.copy_from_nonoverlapping(b"ab\n".as_ptr(), 3);
+	///     fill.assume_filled(3);
+	/// }
+	/// 
+	/// assert_eq!(buffer.read_until(b'\n')?, b"ab\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn begin_fill(&mut self, amount: usize) -> PendingFill<'_, R, INLINE> {
+		self.reserve(amount);
+		let spare = self.spare_capacity_mut();
+		let length = spare.len();
+		let start = NonNull::from(spare).cast();
+		
+		PendingFill {
+			buffer: self,
+			start,
+			length,
+		}
+	}
+}
\ No newline at end of file
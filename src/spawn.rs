@@ -0,0 +1,66 @@
+use std::io;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use crate::{ByteSource, DynReadBuffer};
+
+/// The amount of frames which can be waiting in the channel
+/// before the reading thread blocks.
+const CHANNEL_CAPACITY: usize = 64;
+
+impl<R: ByteSource + Send + 'static, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Moves this **DynReadBuffer** into a new thread which reads frames
+	/// ending with the specified delimiter and sends copies of them over a channel.
+	/// 
+	/// Frames are split the same way as by [`records`], so the data after
+	/// the last delimiter is sent as a final frame.
+	/// Once all data has been sent, the channel is closed.
+	/// 
+	/// This makes it easy to do heavy processing of each frame
+	/// while the next ones are already being read.
+	/// At most 64 frames are held in the channel,
+	/// after which the thread waits for them to be received.
+	/// 
+	/// # Errors
+	/// 
+	/// If reading fails, the error is sent over the channel,
+	/// after which the channel is closed.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics on targets which don't support threads, like `wasm32-unknown-unknown`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "first\nsecond\nlast".as_bytes();
+	/// let buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let frames: Vec<_> = buffer.spawn_frames(b'\n')
+	///     .into_iter()
+	///     .map(|frame| frame.unwrap())
+	///     .collect();
+	/// 
+	/// assert_eq!(frames, [b"first\n".to_vec(), b"second\n".to_vec(), b"last".to_vec()]);
+	/// ```
+	/// 
+	/// [`records`]: DynReadBuffer::records
+	pub fn spawn_frames(mut self, delimiter: u8) -> Receiver<Result<Vec<u8>, io::Error>> {
+		let (sender, receiver) = mpsc::sync_channel(CHANNEL_CAPACITY);
+		
+		thread::spawn(move || {
+			let mut records = self.records(delimiter);
+			
+			while let Some(record) = records.next_record() {
+				let is_err = record.is_err();
+				
+				if sender.send(record.map(<[u8]>::to_vec)).is_err() || is_err {
+					break;
+				}
+			}
+		});
+		
+		receiver
+	}
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads the specified amount of bytes like [`read_bytes`], passes them to `parse`
+	/// and returns its result along with the **DynReadBuffer** to continue reading from.
+	/// 
+	/// This is meant for parsing a fixed-size header and then reading
+	/// a body depending on it: the header is only borrowed while it is parsed,
+	/// so the values extracted from it can be used to read the rest
+	/// without the slice of the header blocking further reads.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_bytes`].
+	/// If an error occurs, `parse` is not called.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [b'T', 0, 3, b'a', b'b', b'c'].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let ((kind, length), body) = buffer.split_first_n(3, |header| {
+	///     (header[0], u16::from_be_bytes([header[1], header[2]]))
+	/// })?;
+	/// 
+	/// assert_eq!(kind, b'T');
+	/// assert_eq!(body.read_bytes(length.into())?, b"abc");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn split_first_n<T>(&mut self, amount: usize, parse: impl FnOnce(&[u8]) -> T) -> Result<(T, &mut Self), io::Error> {
+		let header = parse(self.read_bytes(amount)?);
+		Ok((header, self))
+	}
+}
\ No newline at end of file
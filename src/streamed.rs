@@ -0,0 +1,199 @@
+use std::io::{self, Write};
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads the specified amount of bytes from the given [ByteSource]
+	/// and passes them to `on_chunk` in chunks, without buffering all of them at once.
+	/// 
+	/// Unlike [`read_bytes`], the internal buffer doesn't grow to hold
+	/// the requested amount of bytes, so exact-length data of any size
+	/// can be processed with constant memory. The chunks are at most
+	/// as large as the internal buffer and add up to exactly the requested amount.
+	/// The chunks aren't values of their own, so they aren't counted
+	/// in [`frames_returned`] and don't trigger [`on_frame`].
+	/// 
+	/// # Errors
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before enough bytes could be read,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into], as well as errors returned from `on_chunk`,
+	/// are passed on to the caller.
+	/// In any case, the chunks for which `on_chunk` succeeded stay consumed
+	/// and the rest of the data is preserved for future reads.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::Write;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [7; 100_000].as_slice();
+	/// let mut buffer = DynReadBuffer::with_capacity(reader, 4096);
+	/// let mut output = Vec::new();
+	/// 
+	/// buffer.read_bytes_streamed(100_000, |chunk| output.write_all(chunk))?;
+	/// 
+	/// assert_eq!(output.len(), 100_000);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	/// [`frames_returned`]: DynReadBuffer::frames_returned
+	/// [`on_frame`]: crate::ReadObserver::on_frame
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_bytes_streamed(
+		&mut self,
+		amount: usize,
+		mut on_chunk: impl FnMut(&[u8]) -> Result<(), io::Error>,
+	) -> Result<(), io::Error> {
+		let mut remaining = amount;
+		
+		while remaining > 0 {
+			if self.buffer.is_empty() && self.fill_buffer()? == 0 {
+				return Err(self.unexpected_eof("read_bytes_streamed", Some(amount)));
+			}
+			
+			let length = self.buffer.len().min(remaining);
+			on_chunk(&self.buffer.filled()[..length])?;
+			self.buffer.consume(length);
+			remaining -= length;
+		}
+		
+		Ok(())
+	}
+	
+	/// Copies exactly the specified amount of bytes from the given [ByteSource] to `writer`
+	/// through the internal buffer, without buffering all of them at once.
+	/// 
+	/// Data which is already buffered is written first. Afterwards, the data is copied
+	/// in chunks which are at most as large as the internal buffer, so any amount
+	/// can be copied with constant memory. As all data passes through the internal buffer,
+	/// quotas, [`total_bytes_read`] and [`on_read`] apply the same way as for all other reads.
+	/// The copied data isn't a single value though, so it isn't counted
+	/// in [`frames_returned`] and [`on_frame`] isn't called.
+	/// 
+	/// # Errors
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before enough bytes could be read,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] and from `writer` are passed on to the caller.
+	/// In any case, the chunks which were written completely stay consumed
+	/// and the rest of the data is preserved for future reads.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "hello, world".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// let mut output = Vec::new();
+	/// 
+	/// buffer.copy_to(&mut output, 5)?;
+	/// 
+	/// assert_eq!(output, b"hello");
+	/// assert_eq!(buffer.read_bytes(7)?, b", world");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`total_bytes_read`]: DynReadBuffer::total_bytes_read
+	/// [`on_read`]: crate::ReadObserver::on_read
+	/// [`frames_returned`]: DynReadBuffer::frames_returned
+	/// [`on_frame`]: crate::ReadObserver::on_frame
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn copy_to(&mut self, mut writer: impl Write, amount: u64) -> Result<(), io::Error> {
+		let mut remaining = amount;
+		
+		while remaining > 0 {
+			if self.buffer.is_empty() && self.fill_buffer()? == 0 {
+				return Err(self.unexpected_eof("copy_to", usize::try_from(amount).ok()));
+			}
+			
+			let length = self.buffer.len().min(usize::try_from(remaining).unwrap_or(usize::MAX));
+			writer.write_all(&self.buffer.filled()[..length])?;
+			self.buffer.consume(length);
+			remaining -= length as u64;
+		}
+		
+		Ok(())
+	}
+	
+	/// Reads from the given [ByteSource] until the delimiter is found and writes the data
+	/// up to and including the delimiter to `writer`, without buffering all of it at once,
+	/// returning the amount of bytes written.
+	/// 
+	/// Unlike [`read_until`], the internal buffer doesn't grow to hold the whole section,
+	/// so a delimited section of any size can be extracted with constant memory.
+	/// As the section is written out piece by piece instead of being returned,
+	/// it isn't counted in [`frames_returned`] and [`on_frame`] isn't called for it.
+	/// 
+	/// # Errors
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before the delimiter is found,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned
+	/// and all data has been written to `writer`.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] and from `writer` are passed on to the caller.
+	/// In any case, the chunks which were written completely stay consumed
+	/// and the rest of the data is preserved for future reads.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "a long section\0the rest".as_bytes();
+	/// let mut buffer = DynReadBuffer::with_capacity(reader, 4);
+	/// let mut output = Vec::new();
+	/// 
+	/// assert_eq!(buffer.read_until_into(0, &mut output)?, 15);
+	/// assert_eq!(output, b"a long section\0");
+	/// assert_eq!(buffer.read_bytes(8)?, b"the rest");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	/// [`frames_returned`]: DynReadBuffer::frames_returned
+	/// [`on_frame`]: crate::ReadObserver::on_frame
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_until_into(&mut self, delimiter: u8, mut writer: impl Write) -> Result<u64, io::Error> {
+		let mut written_length = 0;
+		
+		loop {
+			if let Some(position) = self.buffer.find(delimiter, 0) {
+				writer.write_all(&self.buffer.filled()[..position + 1])?;
+				self.buffer.consume(position + 1);
+				return Ok(written_length + position as u64 + 1);
+			}
+			
+			let length = self.buffer.len();
+			writer.write_all(self.buffer.filled())?;
+			self.buffer.consume(length);
+			written_length += length as u64;
+			
+			if self.fill_buffer()? == 0 {
+				return Err(self.unexpected_eof("read_until_into", None));
+			}
+		}
+	}
+}
\ No newline at end of file
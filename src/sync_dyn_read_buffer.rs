@@ -0,0 +1,99 @@
+use std::io;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use crate::{ByteSource, DynReadBuffer};
+
+/// A [`DynReadBuffer`] which can be shared between threads,
+/// for example as part of a connection object behind an [Arc].
+/// 
+/// Every read locks the internal buffer for its duration
+/// and returns an owned copy of the read data.
+/// To read several frames without other threads interleaving their reads,
+/// or to avoid the copies, use [`lock`] and read from the returned guard.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # fn main() -> Result<(), std::io::Error> {
+/// use std::sync::Arc;
+/// use std::thread;
+/// use read_buffer::SyncDynReadBuffer;
+/// 
+/// let reader = "one\ntwo\n".as_bytes();
+/// let buffer = Arc::new(SyncDynReadBuffer::new(reader));
+/// 
+/// let shared = Arc::clone(&buffer);
+/// let first = thread::spawn(move || shared.read_until(b'\n')).join().unwrap()?;
+/// let second = buffer.read_until(b'\n')?;
+/// 
+/// assert_eq!(first, b"one\n");
+/// assert_eq!(second, b"two\n");
+/// # Ok(())
+/// # }
+/// ```
+/// 
+/// [Arc]: std::sync::Arc
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`lock`]: SyncDynReadBuffer::lock
+pub struct SyncDynReadBuffer<R: ByteSource> {
+	buffer: Mutex<DynReadBuffer<R>>,
+}
+
+impl<R: ByteSource> SyncDynReadBuffer<R> {
+	/// Creates a new **SyncDynReadBuffer** to read from the given [ByteSource].
+	pub fn new(reader: R) -> Self {
+		Self::from(DynReadBuffer::new(reader))
+	}
+	
+	/// Locks the internal [`DynReadBuffer`], blocking until no other thread
+	/// holds the lock, and returns a guard through which it can be read from.
+	/// 
+	/// If another thread panicked while holding the lock,
+	/// the buffer is still returned, as it never holds a partially updated state.
+	/// 
+	/// [`DynReadBuffer`]: crate::DynReadBuffer
+	pub fn lock(&self) -> MutexGuard<'_, DynReadBuffer<R>> {
+		self.buffer.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+	
+	/// Like [`DynReadBuffer::read_bytes`], but returns an owned copy of the data.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`DynReadBuffer::read_bytes`].
+	pub fn read_bytes(&self, amount: usize) -> Result<Vec<u8>, io::Error> {
+		self.lock().read_bytes(amount).map(<[u8]>::to_vec)
+	}
+	
+	/// Like [`DynReadBuffer::read_array`], but returns an owned copy of the data.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`DynReadBuffer::read_array`].
+	pub fn read_array<const N: usize>(&self) -> Result<[u8; N], io::Error> {
+		self.lock().read_array_copied()
+	}
+	
+	/// Like [`DynReadBuffer::read_until`], but returns an owned copy of the data.
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`DynReadBuffer::read_until`].
+	pub fn read_until(&self, delimiter: u8) -> Result<Vec<u8>, io::Error> {
+		self.lock().read_until(delimiter).map(<[u8]>::to_vec)
+	}
+	
+	/// Returns the wrapped [`DynReadBuffer`].
+	/// 
+	/// [`DynReadBuffer`]: crate::DynReadBuffer
+	pub fn into_inner(self) -> DynReadBuffer<R> {
+		self.buffer.into_inner().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+impl<R: ByteSource> From<DynReadBuffer<R>> for SyncDynReadBuffer<R> {
+	fn from(buffer: DynReadBuffer<R>) -> Self {
+		Self {
+			buffer: Mutex::new(buffer),
+		}
+	}
+}
\ No newline at end of file
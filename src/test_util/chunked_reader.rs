@@ -0,0 +1,77 @@
+use std::collections::VecDeque;
+use std::io;
+use std::io::Read;
+
+/// A [Read] which returns a predetermined sequence of chunks and errors,
+/// returning each chunk from as few calls to [Read::read] as possible.
+/// 
+/// This allows testing code against short reads and data split
+/// at arbitrary points. Once all chunks are returned,
+/// it reports "end of file".
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::io::{ErrorKind, Read};
+/// use read_buffer::test_util::ChunkedReader;
+/// 
+/// let mut reader = ChunkedReader::new();
+/// reader.add_chunk(vec![1, 2]);
+/// reader.add_error(ErrorKind::Interrupted.into());
+/// reader.add_chunk(vec![3]);
+/// 
+/// let mut buf = [0; 8];
+/// assert_eq!(reader.read(&mut buf).unwrap(), 2);
+/// assert_eq!(reader.read(&mut buf).unwrap_err().kind(), ErrorKind::Interrupted);
+/// assert_eq!(reader.read(&mut buf).unwrap(), 1);
+/// assert_eq!(reader.read(&mut buf).unwrap(), 0);
+/// ```
+pub struct ChunkedReader {
+	chunks: VecDeque<Result<Vec<u8>, io::Error>>,
+}
+
+impl ChunkedReader {
+	/// Creates a new **ChunkedReader** without any chunks.
+	pub fn new() -> Self {
+		Self {
+			chunks: VecDeque::new(),
+		}
+	}
+	
+	/// Adds a chunk of data to be returned.
+	pub fn add_chunk(&mut self, chunk: Vec<u8>) {
+		self.chunks.push_back(Ok(chunk));
+	}
+	
+	/// Adds an "end of file" to be reported
+	/// before continuing with the following chunks.
+	pub fn add_eof(&mut self) {
+		self.chunks.push_back(Ok(Vec::new()));
+	}
+	
+	/// Adds an error to be returned.
+	pub fn add_error(&mut self, error: io::Error) {
+		self.chunks.push_back(Err(error));
+	}
+}
+
+impl Default for ChunkedReader {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Read for ChunkedReader {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let chunk = self.chunks.pop_front()
+			.unwrap_or(Ok(Vec::new()))?;
+		let mut slice = chunk.as_slice();
+		let amount_read = slice.read(buf)?;
+		
+		if !slice.is_empty() {
+			self.chunks.push_front(Ok(slice.to_vec()));
+		}
+		
+		Ok(amount_read)
+	}
+}
\ No newline at end of file
@@ -1,6 +1,10 @@
 use std::io;
 use std::io::{ErrorKind, Read};
 
+/// A [Read] which always returns an error of the kind
+/// [ErrorKind::NotFound][`NotFound`].
+/// 
+/// [`NotFound`]: std::io::ErrorKind::NotFound
 pub struct ErrorReader;
 
 impl Read for ErrorReader {
@@ -0,0 +1,10 @@
+//! Readers for testing code which reads from a [Read][std::io::Read]
+//! against short reads and errors.
+//! 
+//! This module is only available with the `test-util` feature enabled.
+
+mod error_reader;
+mod chunked_reader;
+
+pub use error_reader::ErrorReader;
+pub use chunked_reader::ChunkedReader;
\ No newline at end of file
@@ -0,0 +1,207 @@
+use std::io::{self, ErrorKind, Read};
+use std::net::TcpStream;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::time::{Duration, Instant};
+use crate::DynReadBuffer;
+
+/// A [Read] which can optionally limit how long a single call to
+/// [Read::read] may block, used by [`read_bytes_timeout`] and [`read_until_timeout`].
+/// 
+/// Sources with a native read timeout, like [TcpStream] and [UnixStream],
+/// implement this by setting that timeout, so a read which blocks for too long
+/// is cut short. Any other [Read] can opt in using the default methods,
+/// in which case the deadline is only checked between calls to [Read::read]:
+/// 
+/// ```
+/// use std::io::{self, Read};
+/// use read_buffer::TimedRead;
+/// 
+/// struct MyReader;
+/// 
+/// impl Read for MyReader {
+///     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+///         Ok(0)
+///     }
+/// }
+/// 
+/// impl TimedRead for MyReader {}
+/// ```
+/// 
+/// [`read_bytes_timeout`]: DynReadBuffer::read_bytes_timeout
+/// [`read_until_timeout`]: DynReadBuffer::read_until_timeout
+pub trait TimedRead: Read {
+	/// Sets how long a single call to [Read::read] may block,
+	/// with [None] meaning it may block indefinitely.
+	/// 
+	/// The default implementation does nothing.
+	fn set_read_timeout(&mut self, _timeout: Option<Duration>) -> Result<(), io::Error> {
+		Ok(())
+	}
+	
+	/// Returns the timeout set with [`TimedRead::set_read_timeout`].
+	/// 
+	/// The default implementation always returns [None].
+	fn read_timeout(&self) -> Result<Option<Duration>, io::Error> {
+		Ok(None)
+	}
+}
+
+impl<T: TimedRead + ?Sized> TimedRead for &mut T {
+	fn set_read_timeout(&mut self, timeout: Option<Duration>) -> Result<(), io::Error> {
+		(**self).set_read_timeout(timeout)
+	}
+	
+	fn read_timeout(&self) -> Result<Option<Duration>, io::Error> {
+		(**self).read_timeout()
+	}
+}
+
+impl TimedRead for &[u8] {}
+
+macro_rules! native_timed_read {
+	($type:ty, $stream:ty) => {
+		impl TimedRead for $type {
+			fn set_read_timeout(&mut self, timeout: Option<Duration>) -> Result<(), io::Error> {
+				<$stream>::set_read_timeout(self, timeout)
+			}
+			
+			fn read_timeout(&self) -> Result<Option<Duration>, io::Error> {
+				<$stream>::read_timeout(self)
+			}
+		}
+	}
+}
+
+native_timed_read!(TcpStream, TcpStream);
+native_timed_read!(&TcpStream, TcpStream);
+#[cfg(unix)]
+native_timed_read!(UnixStream, UnixStream);
+#[cfg(unix)]
+native_timed_read!(&UnixStream, UnixStream);
+
+impl<R: TimedRead, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Like [`read_bytes`], but gives up with an error of the kind
+	/// [ErrorKind::TimedOut][`TimedOut`] if the data couldn't be read
+	/// within the specified timeout.
+	/// 
+	/// If the given [Read] has a native read timeout, it is set to the remaining
+	/// time before every call to [Read::read] and restored afterwards.
+	/// Otherwise the timeout is only checked between calls to [Read::read],
+	/// so a single call which blocks indefinitely isn't cut short.
+	/// 
+	/// # Errors
+	/// 
+	/// If the timeout elapses, or the given [Read] returns an error of the kind
+	/// [ErrorKind::WouldBlock][`WouldBlock`] or [ErrorKind::TimedOut][`TimedOut`],
+	/// an error of the kind [ErrorKind::TimedOut][`TimedOut`] is returned.
+	/// The data read so far is preserved in the internal buffer for future reads.
+	/// 
+	/// All other errors are handled the same way as in [`read_bytes`],
+	/// including errors from setting or restoring the native read timeout.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::time::Duration;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [1, 2, 3].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let data = buffer.read_bytes_timeout(2, Duration::from_secs(1))?;
+	/// assert_eq!(data, [1, 2]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	/// [`TimedOut`]: std::io::ErrorKind::TimedOut
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	pub fn read_bytes_timeout(&mut self, amount: usize, timeout: Duration) -> Result<&[u8], io::Error> {
+		self.fill_with_timeout(timeout, |buffer, before_read| {
+			buffer.fill_buffer_to_with("read_bytes_timeout", amount, before_read)
+		})?;
+		
+		Ok(self.take(amount))
+	}
+	
+	/// Like [`read_until`], but gives up with an error of the kind
+	/// [ErrorKind::TimedOut][`TimedOut`] if the delimiter couldn't be read
+	/// within the specified timeout.
+	/// 
+	/// The timeout is applied the same way as in [`read_bytes_timeout`].
+	/// 
+	/// # Errors
+	/// 
+	/// If the timeout elapses, or the given [Read] returns an error of the kind
+	/// [ErrorKind::WouldBlock][`WouldBlock`] or [ErrorKind::TimedOut][`TimedOut`],
+	/// an error of the kind [ErrorKind::TimedOut][`TimedOut`] is returned.
+	/// The data read so far is preserved in the internal buffer for future reads.
+	/// 
+	/// All other errors are handled the same way as in [`read_until`],
+	/// including errors from setting or restoring the native read timeout.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::time::Duration;
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "Hello\nWorld".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let line = buffer.read_until_timeout(b'\n', Duration::from_secs(1))?;
+	/// assert_eq!(line, b"Hello\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_until`]: DynReadBuffer::read_until
+	/// [`read_bytes_timeout`]: DynReadBuffer::read_bytes_timeout
+	/// [`TimedOut`]: std::io::ErrorKind::TimedOut
+	/// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+	pub fn read_until_timeout(&mut self, delimiter: u8, timeout: Duration) -> Result<&[u8], io::Error> {
+		let position = self.fill_with_timeout(timeout, |buffer, before_read| {
+			buffer.fill_buffer_until_with("read_until_timeout", delimiter, before_read)
+		})?;
+		
+		Ok(self.take(position + 1))
+	}
+	
+	fn fill_with_timeout<T>(
+		&mut self,
+		timeout: Duration,
+		fill: impl FnOnce(&mut Self, &mut dyn FnMut(&mut R) -> Result<(), io::Error>) -> Result<T, io::Error>,
+	) -> Result<T, io::Error> {
+		let previous_timeout = self.reader.read_timeout()?;
+		// a deadline too far in the future to be represented is as good as none
+		let deadline = Instant::now().checked_add(timeout);
+		
+		let result = fill(self, &mut |reader| {
+			let Some(deadline) = deadline else {
+				return reader.set_read_timeout(previous_timeout);
+			};
+			
+			let remaining = deadline.saturating_duration_since(Instant::now());
+			
+			if remaining.is_zero() {
+				return Err(ErrorKind::TimedOut.into());
+			}
+			
+			reader.set_read_timeout(Some(remaining))
+		});
+		
+		let restored = self.reader.set_read_timeout(previous_timeout);
+		
+		let value = result.map_err(|err| match err.kind() {
+			ErrorKind::WouldBlock | ErrorKind::TimedOut => ErrorKind::TimedOut.into(),
+			_ => err,
+		})?;
+		
+		restored?;
+		Ok(value)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use std::io;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Skips any ASCII whitespace and returns a slice referencing
+	/// the following bytes up to, but not including, the next ASCII whitespace.
+	/// 
+	/// The whitespace after the token is left in the buffer
+	/// and skipped by the next call to **read_token**.
+	/// A token ending at the "end of file" is returned as well.
+	/// 
+	/// # Errors
+	/// 
+	/// If the given [ByteSource] reaches its "end of file" before any token is found,
+	/// an error of the kind [ErrorKind::UnexpectedEof][`UnexpectedEof`] is returned.
+	/// 
+	/// If an error of the kind [ErrorKind::Interrupted][`Interrupted`]
+	/// is encountered, it is ignored.
+	/// 
+	/// All other errors from [ByteSource::read_into] are passed on to the caller.
+	/// In any case, the data read so far is preserved in the internal buffer
+	/// for future reads.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "  3\n10 20\t30".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_token()?, b"3");
+	/// assert_eq!(buffer.read_token()?, b"10");
+	/// assert_eq!(buffer.read_token()?, b"20");
+	/// assert_eq!(buffer.read_token()?, b"30");
+	/// assert!(buffer.read_token().is_err());
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+	/// [`Interrupted`]: std::io::ErrorKind::Interrupted
+	pub fn read_token(&mut self) -> Result<&[u8], io::Error> {
+		self.skip_ascii_whitespace()?;
+		
+		let mut searched_length = 0;
+		
+		loop {
+			let position = self.buffer.filled()[searched_length..].iter()
+				.position(u8::is_ascii_whitespace);
+			
+			if let Some(position) = position {
+				return Ok(self.take(searched_length + position));
+			}
+			
+			searched_length = self.buffer.len();
+			
+			if self.fill_buffer()? == 0 {
+				return Ok(self.take(searched_length));
+			}
+		}
+	}
+	
+	/// Consumes ASCII whitespace until the buffered data starts with something else.
+	/// 
+	/// Fails with [ErrorKind::UnexpectedEof] if nothing else follows.
+	pub(crate) fn skip_ascii_whitespace(&mut self) -> Result<(), io::Error> {
+		loop {
+			let whitespace = self.buffer.filled().iter()
+				.take_while(|byte| byte.is_ascii_whitespace())
+				.count();
+			self.buffer.consume(whitespace);
+			
+			if !self.buffer.is_empty() {
+				return Ok(());
+			}
+			
+			if self.fill_buffer()? == 0 {
+				return Err(self.unexpected_eof("read_token", None));
+			}
+		}
+	}
+}
\ No newline at end of file
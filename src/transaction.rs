@@ -0,0 +1,84 @@
+use std::ops::{Deref, DerefMut};
+use crate::{ByteSource, DynReadBuffer};
+
+/// A handle to a [`DynReadBuffer`] whose reads are only committed
+/// once the transaction succeeds, passed to the closure given to [`transaction`].
+/// 
+/// All methods of the [`DynReadBuffer`] can be used through a **Transaction**,
+/// including starting nested transactions.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+/// [`transaction`]: DynReadBuffer::transaction
+pub struct Transaction<'a, R: ByteSource, const INLINE: usize = 0> {
+	buffer: &'a mut DynReadBuffer<R, INLINE>,
+}
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Calls the given closure with a [Transaction] and, if it returns [Err],
+	/// returns all data read through the [Transaction] to the buffer,
+	/// so the following reads return it again.
+	/// 
+	/// This makes it easy to try parsing one format and fall back
+	/// to another if it doesn't match, as failed attempts don't consume anything.
+	/// All data read during the transaction stays in the internal buffer
+	/// until it ends.
+	/// 
+	/// Seeking past the buffered data using [`seek_relative`]
+	/// can't be rolled back.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::io::{self, ErrorKind};
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = "PING 42\n".as_bytes();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// let pong = buffer.transaction(|txn| {
+	///     match txn.read_bytes(5)? {
+	///         b"PONG " => Ok(()),
+	///         _ => Err(io::Error::from(ErrorKind::InvalidData)),
+	///     }
+	/// });
+	/// 
+	/// assert!(pong.is_err());
+	/// assert_eq!(buffer.read_until(b'\n')?, b"PING 42\n");
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`seek_relative`]: DynReadBuffer::seek_relative
+	pub fn transaction<T, E>(&mut self, f: impl FnOnce(&mut Transaction<'_, R, INLINE>) -> Result<T, E>) -> Result<T, E> {
+		let outer_hold = self.buffer.hold();
+		let start = self.buffer.position();
+		let hold = outer_hold.map_or(start, |outer_hold| outer_hold.min(start));
+		self.buffer.set_hold(Some(hold));
+		
+		let result = f(&mut Transaction {
+			buffer: self,
+		});
+		
+		if result.is_err() {
+			self.buffer.rewind_to(start);
+		}
+		
+		self.buffer.set_hold(outer_hold);
+		result
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> Deref for Transaction<'_, R, INLINE> {
+	type Target = DynReadBuffer<R, INLINE>;
+	
+	fn deref(&self) -> &Self::Target {
+		self.buffer
+	}
+}
+
+impl<R: ByteSource, const INLINE: usize> DerefMut for Transaction<'_, R, INLINE> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.buffer
+	}
+}
\ No newline at end of file
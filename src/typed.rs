@@ -0,0 +1,73 @@
+use std::io::{self, ErrorKind};
+use std::mem;
+use bytemuck::AnyBitPattern;
+use crate::{ByteSource, DynReadBuffer};
+
+impl<R: ByteSource, const INLINE: usize> DynReadBuffer<R, INLINE> {
+	/// Reads `size_of::<T>()` bytes from the given [ByteSource] and returns
+	/// a reference to them reinterpreted as a `T`.
+	/// 
+	/// The returned reference points into the internal buffer. If the data
+	/// isn't suitably aligned for `T` there, the buffered data is moved first,
+	/// so values can be read at any offset in the stream.
+	/// 
+	/// *This method is only available with the `bytemuck` feature.*
+	/// 
+	/// # Errors
+	/// 
+	/// Errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use read_buffer::DynReadBuffer;
+	/// 
+	/// let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	/// let mut buffer = DynReadBuffer::new(reader);
+	/// 
+	/// assert_eq!(buffer.read_bytes(2)?, [1, 2]);
+	/// let value: &u32 = buffer.read_as()?;
+	/// 
+	/// assert_eq!(*value, u32::from_ne_bytes([3, 4, 5, 6]));
+	/// # Ok(())
+	/// # }
+	/// ```
+	/// 
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_as<T: AnyBitPattern>(&mut self) -> Result<&T, io::Error> {
+		let size = mem::size_of::<T>();
+		self.lookahead(size)?;
+		self.buffer.align_filled(mem::align_of::<T>());
+		
+		let data = self.read_bytes(size)?;
+		Ok(bytemuck::from_bytes(data))
+	}
+	
+	/// Reads `count * size_of::<T>()` bytes from the given [ByteSource] and returns
+	/// a reference to them reinterpreted as a slice of `T`.
+	/// 
+	/// The returned slice points into the internal buffer, which is moved first
+	/// if the data isn't suitably aligned for `T` there.
+	/// 
+	/// *This method is only available with the `bytemuck` feature.*
+	/// 
+	/// # Errors
+	/// 
+	/// If `count * size_of::<T>()` overflows, an error of the kind
+	/// [ErrorKind::InvalidInput][`InvalidInput`] is returned.
+	/// 
+	/// All other errors are handled the same way as in [`read_bytes`].
+	/// 
+	/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+	/// [`read_bytes`]: DynReadBuffer::read_bytes
+	pub fn read_slice_as<T: AnyBitPattern>(&mut self, count: usize) -> Result<&[T], io::Error> {
+		let size = mem::size_of::<T>().checked_mul(count)
+			.ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "requested size overflows usize"))?;
+		self.lookahead(size)?;
+		self.buffer.align_filled(mem::align_of::<T>());
+		
+		let data = self.read_bytes(size)?;
+		Ok(bytemuck::cast_slice(data))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,104 @@
+use std::io::{self, Read};
+use std::os::fd::AsRawFd;
+use io_uring::{opcode, types, IoUring};
+use crate::DynReadBuffer;
+
+/// A [Read] which submits every read of the wrapped file descriptor
+/// through its own io_uring, reading directly into the given buffer,
+/// and waits for it to complete.
+/// 
+/// Reads use and advance the position of the file descriptor,
+/// like the `read(2)` system call does.
+/// 
+/// Requires the `io-uring` feature and is only available on Linux.
+pub struct UringReader<F: AsRawFd> {
+	ring: IoUring,
+	file: F,
+}
+
+impl<F: AsRawFd> UringReader<F> {
+	/// Creates a new **UringReader** reading from the given file descriptor,
+	/// for example a [File] or a [TcpStream].
+	/// 
+	/// # Errors
+	/// 
+	/// If the io_uring can't be set up, for example because the kernel
+	/// doesn't support it, the error is returned.
+	/// 
+	/// [File]: std::fs::File
+	/// [TcpStream]: std::net::TcpStream
+	pub fn new(file: F) -> Result<Self, io::Error> {
+		Ok(Self {
+			ring: IoUring::new(2)?,
+			file,
+		})
+	}
+	
+	/// Returns the wrapped file descriptor.
+	pub fn into_inner(self) -> F {
+		self.file
+	}
+}
+
+impl<F: AsRawFd> Read for UringReader<F> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let length = u32::try_from(buf.len()).unwrap_or(u32::MAX);
+		let entry = opcode::Read::new(types::Fd(self.file.as_raw_fd()), buf.as_mut_ptr(), length)
+			.offset(u64::MAX)
+			.build();
+		
+		// SAFETY: the buffer outlives the read, as it is waited for before returning
+		unsafe {
+			self.ring.submission()
+				.push(&entry)
+				.map_err(|_| io::Error::other("io_uring submission queue is full"))?;
+		}
+		
+		self.ring.submit_and_wait(1)?;
+		
+		let completion = self.ring.completion()
+			.next()
+			.ok_or_else(|| io::Error::other("io_uring read didn't complete"))?;
+		
+		match completion.result() {
+			result if result < 0 => Err(io::Error::from_raw_os_error(-result)),
+			result => Ok(result as usize),
+		}
+	}
+}
+
+/// A [`DynReadBuffer`] reading through io_uring,
+/// created using [`DynReadBuffer::with_uring`].
+/// 
+/// Requires the `io-uring` feature and is only available on Linux.
+/// 
+/// [`DynReadBuffer`]: crate::DynReadBuffer
+pub type UringReadBuffer<F> = DynReadBuffer<UringReader<F>>;
+
+impl<F: AsRawFd> DynReadBuffer<UringReader<F>> {
+	/// Creates a new **DynReadBuffer** to read from the given file descriptor
+	/// through io_uring, using an [UringReader].
+	/// 
+	/// # Errors
+	/// 
+	/// If the io_uring can't be set up, for example because the kernel
+	/// doesn't support it, the error is returned.
+	/// 
+	/// # Examples
+	/// 
+	/// ```no_run
+	/// # fn main() -> Result<(), std::io::Error> {
+	/// use std::fs::File;
+	/// use read_buffer::UringReadBuffer;
+	/// 
+	/// let file = File::open("log.txt")?;
+	/// let mut buffer = UringReadBuffer::with_uring(file)?;
+	/// 
+	/// let line = buffer.read_until(b'\n')?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_uring(file: F) -> Result<Self, io::Error> {
+		Ok(Self::new(UringReader::new(file)?))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,124 @@
+use std::future::Future;
+use std::io::{self, ErrorKind};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use futures_io::AsyncRead;
+use js_sys::{Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ReadableStream, ReadableStreamDefaultReader};
+use crate::AsyncDynReadBuffer;
+
+/// An [AsyncRead] reading the chunks of a JavaScript [ReadableStream],
+/// so the framing of an [`AsyncDynReadBuffer`] can be used in the browser,
+/// for example on the body of a `fetch` response.
+/// 
+/// All chunks of the stream need to be `Uint8Array`s.
+/// The stream is locked to the **ReadableStreamReader** until it is dropped.
+/// 
+/// Requires the `web` feature and the `wasm32-unknown-unknown` target.
+/// 
+/// [`AsyncDynReadBuffer`]: crate::AsyncDynReadBuffer
+pub struct ReadableStreamReader {
+	reader: ReadableStreamDefaultReader,
+	pending: Option<JsFuture>,
+	chunk: Vec<u8>,
+	chunk_start: usize,
+	done: bool,
+}
+
+impl ReadableStreamReader {
+	/// Creates a new **ReadableStreamReader** reading from the given [ReadableStream].
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the stream is already locked to another reader.
+	pub fn new(stream: &ReadableStream) -> Self {
+		Self {
+			reader: stream.get_reader().unchecked_into(),
+			pending: None,
+			chunk: Vec::new(),
+			chunk_start: 0,
+			done: false,
+		}
+	}
+}
+
+impl AsyncDynReadBuffer<ReadableStreamReader> {
+	/// Creates a new **AsyncDynReadBuffer** to read from the given [ReadableStream]
+	/// using a [ReadableStreamReader].
+	/// 
+	/// Requires the `web` feature and the `wasm32-unknown-unknown` target.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the stream is already locked to another reader.
+	/// 
+	/// # Examples
+	/// 
+	/// ```no_run
+	/// # async fn example(body: web_sys::ReadableStream) -> Result<(), std::io::Error> {
+	/// use read_buffer::AsyncDynReadBuffer;
+	/// 
+	/// let mut buffer = AsyncDynReadBuffer::from_readable_stream(&body);
+	/// let mut lines = 0;
+	/// 
+	/// while buffer.read_until(b'\n').await.is_ok() {
+	///     lines += 1;
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn from_readable_stream(stream: &ReadableStream) -> Self {
+		Self::new(ReadableStreamReader::new(stream))
+	}
+}
+
+impl AsyncRead for ReadableStreamReader {
+	fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+		let this = &mut *self;
+		
+		loop {
+			if this.chunk_start < this.chunk.len() {
+				let remaining = &this.chunk[this.chunk_start..];
+				let length = remaining.len().min(buf.len());
+				buf[..length].copy_from_slice(&remaining[..length]);
+				this.chunk_start += length;
+				return Poll::Ready(Ok(length));
+			}
+			
+			if this.done || buf.is_empty() {
+				return Poll::Ready(Ok(0));
+			}
+			
+			// kept across calls, so no chunk is lost if a read is cancelled
+			let pending = this.pending.get_or_insert_with(|| JsFuture::from(this.reader.read()));
+			let result = ready!(Pin::new(pending).poll(cx));
+			this.pending = None;
+			
+			let result = result.map_err(js_error)?;
+			
+			if Reflect::get(&result, &"done".into()).map_err(js_error)?.is_truthy() {
+				this.done = true;
+				continue;
+			}
+			
+			let value = Reflect::get(&result, &"value".into()).map_err(js_error)?;
+			let chunk = value.dyn_into::<Uint8Array>()
+				.map_err(|_| io::Error::new(ErrorKind::InvalidData, "chunk of the stream is not a Uint8Array"))?;
+			
+			this.chunk = chunk.to_vec();
+			this.chunk_start = 0;
+		}
+	}
+}
+
+impl Drop for ReadableStreamReader {
+	fn drop(&mut self) {
+		self.reader.release_lock();
+	}
+}
+
+fn js_error(error: JsValue) -> io::Error {
+	io::Error::other(format!("{error:?}"))
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use std::io::{self, Read};
+
+use read_buffer::DynReadBuffer;
+
+/// A reader which behaves like a file opened with `O_DIRECT`,
+/// only accepting aligned reads of whole blocks.
+struct BlockReader {
+	data: Vec<u8>,
+	position: usize,
+	block_size: usize,
+}
+
+impl Read for BlockReader {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		assert_eq!(buf.as_ptr().align_offset(self.block_size), 0, "read into unaligned buffer");
+		assert_eq!(buf.len() % self.block_size, 0, "read length is not a multiple of the block size");
+		
+		let length = buf.len().min(self.block_size * 2).min(self.data.len() - self.position);
+		buf[..length].copy_from_slice(&self.data[self.position..self.position + length]);
+		self.position += length;
+		Ok(length)
+	}
+}
+
+fn block_reader(length: usize, block_size: usize) -> BlockReader {
+	BlockReader {
+		data: (0..length).map(|i| i as u8).collect(),
+		position: 0,
+		block_size,
+	}
+}
+
+#[test]
+fn aligned_reads() {
+	let reader = block_reader(10_000, 512);
+	let mut buffer = DynReadBuffer::with_alignment(reader, 1024, 512);
+	let mut position = 0;
+	
+	for amount in [1, 700, 100, 2000, 3, 5000] {
+		let result = buffer.read_bytes(amount).unwrap();
+		let expected: Vec<u8> = (position..position + amount).map(|i| i as u8).collect();
+		assert_eq!(result, expected);
+		position += amount;
+	}
+}
+
+#[test]
+fn aligned_read_until() {
+	let mut reader = block_reader(0, 64);
+	reader.data = b"first line\nsecond line\n".repeat(20);
+	let mut buffer = DynReadBuffer::with_alignment(reader, 0, 64);
+	
+	for _ in 0..20 {
+		assert_eq!(buffer.read_until(b'\n').unwrap(), b"first line\n");
+		assert_eq!(buffer.read_until(b'\n').unwrap(), b"second line\n");
+	}
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[should_panic]
+fn alignment_not_power_of_two() {
+	DynReadBuffer::with_alignment([].as_slice(), 1024, 500);
+}
\ No newline at end of file
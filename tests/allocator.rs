@@ -0,0 +1,53 @@
+#![cfg(feature = "allocator-api2")]
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
+
+use std::alloc::Layout;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+use read_buffer::DynReadBuffer;
+
+#[derive(Clone, Default)]
+struct CountingAllocator {
+	allocated: Arc<AtomicUsize>,
+}
+
+unsafe impl Allocator for CountingAllocator {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.allocated.fetch_add(layout.size(), Ordering::Relaxed);
+		Global.allocate(layout)
+	}
+	
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		self.allocated.fetch_sub(layout.size(), Ordering::Relaxed);
+		Global.deallocate(ptr, layout)
+	}
+}
+
+#[test]
+fn uses_allocator() {
+	let allocator = CountingAllocator::default();
+	let reader = b"Hello\nWorld\n".repeat(100);
+	let mut buffer = DynReadBuffer::with_capacity_in(reader.as_slice(), 16, allocator.clone());
+	assert!(allocator.allocated.load(Ordering::Relaxed) >= 16);
+	
+	for _ in 0..100 {
+		assert_eq!(buffer.read_until(b'\n').unwrap(), b"Hello\n");
+		assert_eq!(buffer.read_until(b'\n').unwrap(), b"World\n");
+	}
+	
+	drop(buffer);
+	assert_eq!(allocator.allocated.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn grows_in_allocator() {
+	let allocator = CountingAllocator::default();
+	let reader = [7; 1000];
+	let mut buffer = DynReadBuffer::new_in(reader.as_slice(), allocator.clone());
+	
+	assert_eq!(buffer.read_bytes(1000).unwrap(), [7; 1000]);
+	assert!(allocator.allocated.load(Ordering::Relaxed) >= 1000);
+}
\ No newline at end of file
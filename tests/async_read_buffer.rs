@@ -0,0 +1,321 @@
+#![cfg(feature = "futures-io")]
+
+use std::future::{poll_fn, Future};
+use std::io::{self, ErrorKind};
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+use futures_core::Stream;
+use futures_io::AsyncRead;
+use read_buffer::{AsyncDynReadBuffer, FrameStream, LineTooLong, LinesStream};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+	fn wake(self: Arc<Self>) {
+		self.0.unpark();
+	}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+	let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+	let mut context = Context::from_waker(&waker);
+	let mut future = pin!(future);
+	
+	loop {
+		if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+			return output;
+		}
+		
+		thread::park();
+	}
+}
+
+/// Returns its chunks one at a time, being pending before each of them.
+struct PendingReader {
+	chunks: Vec<io::Result<Vec<u8>>>,
+	ready: bool,
+}
+
+impl AsyncRead for PendingReader {
+	fn poll_read(mut self: Pin<&mut Self>, context: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+		if !self.ready {
+			self.ready = true;
+			context.waker().wake_by_ref();
+			return Poll::Pending;
+		}
+		
+		self.ready = false;
+		
+		if self.chunks.is_empty() {
+			return Poll::Ready(Ok(0));
+		}
+		
+		let mut chunk = self.chunks.remove(0)?;
+		
+		if chunk.len() > buf.len() {
+			let rest = chunk.split_off(buf.len());
+			self.chunks.insert(0, Ok(rest));
+		}
+		
+		buf[..chunk.len()].copy_from_slice(&chunk);
+		Poll::Ready(Ok(chunk.len()))
+	}
+}
+
+#[test]
+fn read_bytes_and_until() {
+	let reader = PendingReader {
+		chunks: vec![
+			Ok(b"ab".to_vec()),
+			Err(ErrorKind::Interrupted.into()),
+			Ok(b"c\nde".to_vec()),
+			Ok(b"f\n".to_vec()),
+		],
+		ready: false,
+	};
+	let mut buffer = AsyncDynReadBuffer::new(reader);
+	
+	block_on(async {
+		assert_eq!(buffer.read_bytes(1).await.unwrap(), b"a");
+		assert_eq!(buffer.read_until(b'\n').await.unwrap(), b"bc\n");
+		assert_eq!(buffer.read_until(b'\n').await.unwrap(), b"def\n");
+		
+		let error = buffer.read_bytes(1).await.unwrap_err();
+		assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	});
+}
+
+#[test]
+fn cancelled_read_keeps_data() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"Hel".to_vec()), Ok(b"lo\n".to_vec())],
+		ready: true,
+	};
+	let mut buffer = AsyncDynReadBuffer::new(reader);
+	let mut context = Context::from_waker(Waker::noop());
+	
+	{
+		let mut future = pin!(buffer.read_until(b'\n'));
+		assert!(future.as_mut().poll(&mut context).is_pending());
+	}
+	
+	let line = block_on(buffer.read_until(b'\n')).unwrap();
+	assert_eq!(line, b"Hello\n");
+}
+
+#[test]
+fn pending_exact_after_cancel() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"abc".to_vec()), Ok(b"def".to_vec())],
+		ready: true,
+	};
+	let mut buffer = AsyncDynReadBuffer::new(reader);
+	let mut context = Context::from_waker(Waker::noop());
+	assert_eq!(buffer.pending_exact(), 0);
+	
+	{
+		let mut future = pin!(buffer.read_bytes(5));
+		assert!(future.as_mut().poll(&mut context).is_pending());
+	}
+	
+	assert_eq!(buffer.pending_exact(), 3);
+	assert_eq!(block_on(buffer.read_bytes(5)).unwrap(), b"abcde");
+	assert_eq!(buffer.pending_exact(), 0);
+}
+/// Returns its data and then never becomes ready again.
+struct StallingReader {
+	data: Option<Vec<u8>>,
+}
+
+impl AsyncRead for StallingReader {
+	fn poll_read(mut self: Pin<&mut Self>, _context: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+		match self.data.take() {
+			Some(data) => {
+				buf[..data.len()].copy_from_slice(&data);
+				Poll::Ready(Ok(data.len()))
+			},
+			None => Poll::Pending,
+		}
+	}
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn read_until_deadline() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"Hel".to_vec()), Ok(b"lo\n".to_vec())],
+		ready: false,
+	};
+	let mut buffer = AsyncDynReadBuffer::new(reader);
+	let deadline = Instant::now() + Duration::from_secs(10);
+	
+	let line = block_on(buffer.read_until_deadline(b'\n', deadline)).unwrap();
+	assert_eq!(line, b"Hello\n");
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn read_until_deadline_times_out() {
+	let reader = StallingReader {
+		data: Some(b"partial".to_vec()),
+	};
+	let mut buffer = AsyncDynReadBuffer::new(reader);
+	let start = Instant::now();
+	
+	let error = block_on(buffer.read_until_deadline(b'\n', start + Duration::from_millis(50))).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::TimedOut);
+	assert!(start.elapsed() >= Duration::from_millis(50));
+	assert_eq!(buffer.pending_exact(), 7);
+}
+/// Reads a line followed by a frame of the length given by the first byte of the line,
+/// using the poll methods.
+struct LineAndFrame<'a, R: AsyncRead + Unpin> {
+	buffer: &'a mut AsyncDynReadBuffer<R>,
+	line: Option<Vec<u8>>,
+}
+
+impl<R: AsyncRead + Unpin> Future for LineAndFrame<'_, R> {
+	type Output = io::Result<(Vec<u8>, Vec<u8>)>;
+	
+	fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.line.is_none() {
+			match self.buffer.poll_read_until(context, b'\n') {
+				Poll::Ready(Ok(())) => (),
+				Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+				Poll::Pending => return Poll::Pending,
+			}
+			
+			let line = self.buffer.take_frame().unwrap().to_vec();
+			self.line = Some(line);
+		}
+		
+		let length = (self.line.as_ref().unwrap()[0] - b'0') as usize;
+		
+		match self.buffer.poll_read_bytes(context, length) {
+			Poll::Ready(Ok(())) => (),
+			Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+			Poll::Pending => return Poll::Pending,
+		}
+		
+		let frame = self.buffer.take_frame().unwrap().to_vec();
+		Poll::Ready(Ok((self.line.take().unwrap(), frame)))
+	}
+}
+
+#[test]
+fn poll_methods() {
+	let reader = PendingReader {
+		chunks: vec![
+			Ok(b"3".to_vec()),
+			Ok(b"\nab".to_vec()),
+			Err(ErrorKind::Interrupted.into()),
+			Ok(b"c2\nd".to_vec()),
+		],
+		ready: false,
+	};
+	let mut buffer = AsyncDynReadBuffer::new(reader);
+	
+	let result = block_on(LineAndFrame { buffer: &mut buffer, line: None }).unwrap();
+	assert_eq!(result, (b"3\n".to_vec(), b"abc".to_vec()));
+	assert!(buffer.take_frame().is_none());
+	
+	let error = block_on(LineAndFrame { buffer: &mut buffer, line: None }).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.pending_exact(), 1);
+}
+
+#[test]
+fn stale_frame() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"abcd".to_vec())],
+		ready: true,
+	};
+	let mut buffer = AsyncDynReadBuffer::new(reader);
+	let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+	let mut context = Context::from_waker(&waker);
+	
+	assert!(buffer.poll_read_bytes(&mut context, 2).is_ready());
+	assert_eq!(block_on(buffer.read_bytes(1)).unwrap(), b"a");
+	assert!(buffer.take_frame().is_none());
+}
+
+fn next_frame<R: AsyncRead + Unpin>(frames: &mut FrameStream<R>) -> Option<io::Result<Vec<u8>>> {
+	block_on(poll_fn(|context| Pin::new(&mut *frames).poll_next(context)))
+}
+
+#[test]
+fn frame_stream() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"ab\nc".to_vec()), Ok(b"d\n\nef".to_vec())],
+		ready: false,
+	};
+	let mut frames = AsyncDynReadBuffer::new(reader).into_frame_stream(b'\n');
+	
+	assert_eq!(next_frame(&mut frames).unwrap().unwrap(), b"ab\n");
+	assert_eq!(next_frame(&mut frames).unwrap().unwrap(), b"cd\n");
+	assert_eq!(next_frame(&mut frames).unwrap().unwrap(), b"\n");
+	assert_eq!(next_frame(&mut frames).unwrap().unwrap(), b"ef");
+	assert!(next_frame(&mut frames).is_none());
+}
+
+#[test]
+fn frame_stream_high_watermark() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"ab\ncd\nabcdef\n".to_vec())],
+		ready: false,
+	};
+	let mut frames = AsyncDynReadBuffer::new(reader).into_frame_stream(b'\n');
+	frames.set_high_watermark(4);
+	
+	assert_eq!(next_frame(&mut frames).unwrap().unwrap(), b"ab\n");
+	assert_eq!(next_frame(&mut frames).unwrap().unwrap(), b"cd\n");
+	
+	let error = next_frame(&mut frames).unwrap().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	assert!(error.get_ref().unwrap().is::<LineTooLong>());
+	assert!(next_frame(&mut frames).is_none());
+	
+	// the rest of the data was never read
+	let mut buffer = frames.into_inner();
+	assert_eq!(buffer.pending_exact(), 4);
+	assert_eq!(block_on(buffer.read_until(b'\n')).unwrap(), b"abcdef\n");
+}
+
+fn next_line<R: AsyncRead + Unpin>(lines: &mut LinesStream<R>) -> Option<io::Result<String>> {
+	block_on(poll_fn(|context| Pin::new(&mut *lines).poll_next(context)))
+}
+
+#[test]
+fn lines_stream() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"first\r".to_vec()), Ok(b"\nsecond\n\xff\nlast\r".to_vec())],
+		ready: false,
+	};
+	let mut lines = AsyncDynReadBuffer::new(reader).lines_stream(8);
+	
+	assert_eq!(next_line(&mut lines).unwrap().unwrap(), "first");
+	assert_eq!(next_line(&mut lines).unwrap().unwrap(), "second");
+	assert_eq!(next_line(&mut lines).unwrap().unwrap_err().kind(), ErrorKind::InvalidData);
+	assert_eq!(next_line(&mut lines).unwrap().unwrap(), "last\r");
+	assert!(next_line(&mut lines).is_none());
+}
+
+#[test]
+fn lines_stream_too_long() {
+	let reader = PendingReader {
+		chunks: vec![Ok(b"1234\r\n12345\nrest".to_vec())],
+		ready: false,
+	};
+	let mut lines = AsyncDynReadBuffer::new(reader).lines_stream(4);
+	
+	assert_eq!(next_line(&mut lines).unwrap().unwrap(), "1234");
+	
+	let error = next_line(&mut lines).unwrap().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	assert_eq!(error.get_ref().unwrap().downcast_ref::<LineTooLong>().unwrap().max_length(), 4);
+	assert!(next_line(&mut lines).is_none());
+}
\ No newline at end of file
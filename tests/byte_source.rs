@@ -0,0 +1,61 @@
+use std::collections::VecDeque;
+use std::io::{self, ErrorKind};
+
+use read_buffer::{ByteSource, DynReadBuffer, ReadBuffer};
+
+/// A ring of chunks like the ones a driver might fill,
+/// reporting WouldBlock once it's empty until it's closed.
+struct ChunkRing {
+	chunks: VecDeque<Vec<u8>>,
+	closed: bool,
+}
+
+impl ByteSource for ChunkRing {
+	fn read_into(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let Some(mut chunk) = self.chunks.pop_front() else {
+			return match self.closed {
+				true => Ok(0),
+				false => Err(ErrorKind::WouldBlock.into()),
+			};
+		};
+		
+		if chunk.len() > buf.len() {
+			let rest = chunk.split_off(buf.len());
+			self.chunks.push_front(rest);
+		}
+		
+		buf[..chunk.len()].copy_from_slice(&chunk);
+		Ok(chunk.len())
+	}
+}
+
+#[test]
+fn dyn_read_buffer() {
+	let ring = ChunkRing {
+		chunks: VecDeque::from([b"ab\nc".to_vec(), b"d\nef".to_vec()]),
+		closed: false,
+	};
+	let mut buffer = DynReadBuffer::new(ring);
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"ab\n");
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"cd\n");
+	assert!(buffer.try_read_until(b'\n').unwrap().is_none());
+	
+	buffer.get_mut().closed = true;
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn read_buffer() {
+	let mut ring = ChunkRing {
+		chunks: VecDeque::from([vec![1, 2, 3], vec![4]]),
+		closed: true,
+	};
+	let mut buffer: ReadBuffer<2> = ReadBuffer::new();
+	
+	assert_eq!(buffer.read_from(&mut ring).unwrap(), [1, 2]);
+	assert_eq!(buffer.read_from(&mut ring).unwrap(), [3]);
+	assert_eq!(buffer.read_from(&mut ring).unwrap(), [4]);
+	assert!(buffer.read_from(&mut ring).unwrap().is_empty());
+}
\ No newline at end of file
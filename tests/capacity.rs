@@ -1,4 +1,4 @@
-use read_buffer::ReadBuffer;
+use read_buffer::{ReadBuffer, Align4096};
 
 #[test]
 fn capacity() {
@@ -9,4 +9,16 @@ fn capacity() {
 	assert_eq!(buffer_1.capacity(), 1);
 	assert_eq!(buffer_30.capacity(), 30);
 	assert_eq!(buffer_128.capacity(), 128);
+}
+
+#[test]
+fn aligned_capacity() {
+	let buffer: ReadBuffer<4096, Align4096> = ReadBuffer::new_aligned();
+	assert_eq!(buffer.capacity(), 4096);
+}
+
+#[test]
+fn const_new() {
+	static BUFFER: ReadBuffer<16> = ReadBuffer::new();
+	assert_eq!(BUFFER.capacity(), 16);
 }
\ No newline at end of file
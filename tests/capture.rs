@@ -0,0 +1,77 @@
+use std::io::{self, ErrorKind, Write};
+use std::sync::{Arc, Mutex};
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[derive(Clone, Default)]
+struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+impl SharedSink {
+	fn contents(&self) -> Vec<u8> {
+		self.0.lock().unwrap().clone()
+	}
+}
+
+impl Write for SharedSink {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.0.lock().unwrap().write(buf)
+	}
+	
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+struct FailingSink;
+
+impl Write for FailingSink {
+	fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+		Err(ErrorKind::BrokenPipe.into())
+	}
+	
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+#[test]
+fn captures_consumed_data() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"first\nsec".to_vec());
+	reader.add_chunk(b"ond\nthird\n".to_vec());
+	let sink = SharedSink::default();
+	let mut buffer = DynReadBuffer::with_capture(reader, sink.clone());
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"first\n");
+	assert_eq!(sink.contents(), b"first\n");
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"second\n");
+	assert_eq!(buffer.read_bytes(2).unwrap(), b"th");
+	assert_eq!(sink.contents(), b"first\nsecond\nth");
+}
+
+#[test]
+fn does_not_capture_cleared_data() {
+	let reader = "data\nsecret\n".as_bytes();
+	let sink = SharedSink::default();
+	let mut buffer = DynReadBuffer::with_capture(reader, sink.clone());
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"data\n");
+	buffer.lookahead(7).unwrap();
+	buffer.clear();
+	
+	assert_eq!(sink.contents(), b"data\n");
+}
+
+#[test]
+fn capture_error() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::with_capture(reader, FailingSink);
+	
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+	assert_eq!(buffer.read_bytes(2).unwrap(), [3, 4]);
+	
+	assert_eq!(buffer.take_capture_error().unwrap().kind(), ErrorKind::BrokenPipe);
+	assert!(buffer.take_capture_error().is_none());
+}
\ No newline at end of file
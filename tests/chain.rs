@@ -0,0 +1,41 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_across_seam() {
+	let first = [1, 2, 3].as_slice();
+	let second = [4, 5].as_slice();
+	let mut buffer = DynReadBuffer::new(first).chain(second);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, [1, 2]);
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [3, 4, 5]);
+	
+	let error = buffer.read_bytes(1).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn keeps_buffered_data() {
+	let mut first = ChunkedReader::new();
+	first.add_chunk(b"one\ntw".to_vec());
+	first.add_eof();
+	let mut buffer = DynReadBuffer::new(first);
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"one\n");
+	
+	let mut buffer = buffer
+		.chain("o\nth".as_bytes())
+		.chain("ree\n".as_bytes());
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"two\n");
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"three\n");
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use read_buffer::{CheckpointExpired, DynReadBuffer};
+
+#[test]
+fn restore() {
+	let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::with_capacity(reader, 16);
+	
+	assert_eq!(buffer.read_bytes(1).unwrap(), [1]);
+	let first = buffer.checkpoint();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [2, 3]);
+	let second = buffer.checkpoint();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [4, 5]);
+	
+	buffer.restore(second).unwrap();
+	assert_eq!(buffer.read_bytes(1).unwrap(), [4]);
+	buffer.restore(first).unwrap();
+	assert_eq!(buffer.read_bytes(3).unwrap(), [2, 3, 4]);
+	buffer.restore(second).unwrap();
+	assert_eq!(buffer.read_bytes(3).unwrap(), [4, 5, 6]);
+}
+
+#[test]
+fn expired_after_compaction() {
+	let reader: Vec<u8> = (0..32).collect();
+	let mut buffer = DynReadBuffer::with_capacity(reader.as_slice(), 8);
+	
+	assert_eq!(buffer.read_bytes(2).unwrap(), [0, 1]);
+	let checkpoint = buffer.checkpoint();
+	assert_eq!(buffer.read_bytes(4).unwrap(), [2, 3, 4, 5]);
+	assert_eq!(buffer.read_bytes(6).unwrap(), [6, 7, 8, 9, 10, 11]);
+	
+	assert_eq!(buffer.restore(checkpoint), Err(CheckpointExpired));
+	assert_eq!(buffer.read_bytes(1).unwrap(), [12]);
+}
\ No newline at end of file
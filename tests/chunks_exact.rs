@@ -0,0 +1,50 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn exact_chunks() {
+	let reader: Vec<u8> = (0..12).collect();
+	let mut buffer = DynReadBuffer::new(reader.as_slice());
+	let mut chunks = buffer.chunks_exact(4);
+	let mut read_chunks = Vec::new();
+	
+	while let Some(chunk) = chunks.next_chunk() {
+		read_chunks.push(chunk.unwrap().to_vec());
+	}
+	
+	assert_eq!(read_chunks, [[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]]);
+	assert!(chunks.remainder().is_empty());
+}
+
+#[test]
+fn partial_chunk() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_chunk(vec![3, 4, 5]);
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut chunks = buffer.chunks_exact(2);
+	
+	assert_eq!(chunks.next_chunk().unwrap().unwrap(), [1, 2]);
+	assert_eq!(chunks.next_chunk().unwrap().unwrap(), [3, 4]);
+	assert!(chunks.next_chunk().is_none());
+	assert!(chunks.next_chunk().is_none());
+	assert_eq!(chunks.remainder(), [5]);
+	
+	assert_eq!(buffer.read_bytes(1).unwrap(), [5]);
+}
+
+#[test]
+fn error_continues_chunk() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1]);
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	reader.add_chunk(vec![2, 3]);
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut chunks = buffer.chunks_exact(3);
+	
+	assert_eq!(chunks.next_chunk().unwrap().unwrap_err().kind(), ErrorKind::ConnectionReset);
+	assert_eq!(chunks.next_chunk().unwrap().unwrap(), [1, 2, 3]);
+	assert!(chunks.next_chunk().is_none());
+}
\ No newline at end of file
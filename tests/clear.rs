@@ -0,0 +1,25 @@
+use read_buffer::{DynReadBuffer, ReadBuffer};
+
+#[test]
+fn clear_dyn_read_buffer() {
+	let reader = "password\nrest\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.lookahead(4).unwrap(), b"pass");
+	buffer.mark();
+	buffer.clear();
+	
+	assert!(buffer.available().is_empty());
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"word\n");
+}
+
+#[test]
+fn clear_read_buffer() {
+	let mut reader = "password\nrest\n".as_bytes();
+	let mut buffer: ReadBuffer<10> = ReadBuffer::new();
+	
+	assert_eq!(buffer.read_until(&mut reader, b'\n').unwrap(), b"password\n");
+	buffer.clear();
+	
+	assert_eq!(buffer.read_until(&mut reader, b'\n').unwrap(), b"est\n");
+}
\ No newline at end of file
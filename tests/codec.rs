@@ -0,0 +1,72 @@
+#![cfg(feature = "tokio-util")]
+
+use std::io::ErrorKind;
+
+use bytes::BytesMut;
+use read_buffer::{DelimitedMessageCodec, DelimiterCodec, DynReadBuffer};
+use tokio_util::codec::{Decoder, LinesCodec};
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_decoded_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"Hel".to_vec());
+	reader.add_chunk(b"lo\nWor".to_vec());
+	reader.add_chunk(b"ld\n".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut codec = LinesCodec::new();
+	
+	assert_eq!(buffer.read_decoded(&mut codec).unwrap(), "Hello");
+	assert_eq!(buffer.read_decoded(&mut codec).unwrap(), "World");
+	assert_eq!(buffer.frames_returned(), 2);
+}
+
+#[test]
+fn read_decoded_eof() {
+	let reader = b"first\nlast".as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_decoded(&mut LinesCodec::new()).unwrap(), "first");
+	assert_eq!(buffer.read_decoded(&mut LinesCodec::new()).unwrap(), "last");
+	
+	let error = buffer.read_decoded(&mut DelimiterCodec::new(b'\n')).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn delimiter_codec() {
+	let mut codec = DelimiterCodec::new(b';');
+	let mut data = BytesMut::from(&b"a;bc;d"[..]);
+	
+	assert_eq!(codec.decode(&mut data).unwrap().unwrap(), &b"a;"[..]);
+	assert_eq!(codec.decode(&mut data).unwrap().unwrap(), &b"bc;"[..]);
+	assert_eq!(codec.decode(&mut data).unwrap(), None);
+	assert_eq!(data, &b"d"[..]);
+}
+
+#[test]
+fn delimited_message_codec() {
+	let mut codec = DelimitedMessageCodec::new(16);
+	let mut data = BytesMut::from(&[3, 1, 2][..]);
+	
+	assert_eq!(codec.decode(&mut data).unwrap(), None);
+	
+	data.extend_from_slice(&[3, 1, 4]);
+	assert_eq!(codec.decode(&mut data).unwrap().unwrap(), &[1, 2, 3][..]);
+	assert_eq!(codec.decode(&mut data).unwrap().unwrap(), &[4][..]);
+	
+	let mut data = BytesMut::from(&[17][..]);
+	let error = codec.decode(&mut data).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn delimited_message_codec_with_buffer() {
+	let reader = [2, 7, 8, 0, 1, 9].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut codec = DelimitedMessageCodec::new(16);
+	
+	assert_eq!(buffer.read_decoded(&mut codec).unwrap(), &[7, 8][..]);
+	assert_eq!(buffer.read_decoded(&mut codec).unwrap(), &[][..]);
+	assert_eq!(buffer.read_decoded(&mut codec).unwrap(), &[9][..]);
+}
\ No newline at end of file
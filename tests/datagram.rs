@@ -0,0 +1,40 @@
+use std::net::UdpSocket;
+
+use read_buffer::DatagramReadBuffer;
+
+fn connected_pair() -> (UdpSocket, UdpSocket) {
+	let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+	let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+	sender.connect(receiver.local_addr().unwrap()).unwrap();
+	receiver.connect(sender.local_addr().unwrap()).unwrap();
+	(sender, receiver)
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires sockets")]
+fn keeps_boundaries() {
+	let (sender, receiver) = connected_pair();
+	sender.send(b"abc").unwrap();
+	sender.send(b"").unwrap();
+	sender.send(b"defg").unwrap();
+	
+	let mut buffer = DatagramReadBuffer::new(receiver);
+	assert_eq!(buffer.recv().unwrap(), b"abc");
+	assert_eq!(buffer.recv().unwrap(), b"");
+	assert_eq!(buffer.recv().unwrap(), b"defg");
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires sockets")]
+fn grows_to_fit() {
+	let (sender, receiver) = connected_pair();
+	let large: Vec<u8> = (0..5000).map(|i| i as u8).collect();
+	sender.send(b"ab").unwrap();
+	sender.send(&large).unwrap();
+	sender.send(b"abcd").unwrap();
+	
+	let mut buffer = DatagramReadBuffer::with_capacity(&receiver, 4);
+	assert_eq!(buffer.recv().unwrap(), b"ab");
+	assert_eq!(buffer.recv().unwrap(), large);
+	assert_eq!(buffer.recv().unwrap(), b"abcd");
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use read_buffer::{DynReadBuffer, ReadBuffer};
+
+#[test]
+fn dyn_read_buffer_redacts_contents() {
+	let reader = "password=hunter2\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.lookahead(17).unwrap();
+	assert_eq!(buffer.read_bytes(9).unwrap(), b"password=");
+	
+	let debug = format!("{buffer:?}");
+	assert!(debug.contains("buffered: 8"));
+	assert!(debug.contains("position: 9"));
+	assert!(debug.ends_with(", .. }"));
+	assert!(!debug.contains("104, 117"));
+	
+	let debug = format!("{:?}", buffer.debug_with_contents());
+	assert!(debug.contains("buffered_data: [104, 117, 110, 116, 101, 114, 50, 10]"));
+}
+
+#[test]
+fn read_buffer_redacts_contents() {
+	let mut reader = "password=hunter2\n".as_bytes();
+	let mut buffer: ReadBuffer<32> = ReadBuffer::new();
+	
+	assert_eq!(buffer.read_until(&mut reader, b'=').unwrap(), b"password=");
+	
+	let debug = format!("{buffer:?}");
+	assert!(debug.contains("capacity: 32"));
+	assert!(debug.contains("carried_length: 8"));
+	assert!(debug.ends_with(", .. }"));
+	assert!(!debug.contains("104, 117"));
+	
+	let debug = format!("{:?}", buffer.debug_with_contents());
+	assert!(debug.contains("carried: [104, 117, 110, 116, 101, 114, 50, 10]"));
+}
\ No newline at end of file
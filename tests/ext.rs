@@ -0,0 +1,31 @@
+use read_buffer::ReadBufferExt;
+
+#[test]
+fn into_dyn_buffer() {
+	let mut buffer = [1, 2, 3].as_slice().into_dyn_buffer();
+	
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+	assert_eq!(buffer.read_bytes(1).unwrap(), [3]);
+}
+
+#[test]
+fn framed() {
+	let mut frames = "a;bc;;d".as_bytes().framed(b';');
+	let mut collected = Vec::new();
+	
+	while let Some(frame) = frames.next_frame() {
+		collected.push(frame.unwrap().to_vec());
+	}
+	
+	assert_eq!(collected, [b"a;".to_vec(), b"bc;".to_vec(), b";".to_vec(), b"d".to_vec()]);
+}
+
+#[test]
+fn framed_into_inner() {
+	let mut frames = "header\n\x01\x02".as_bytes().framed(b'\n');
+	
+	assert_eq!(frames.next_frame().unwrap().unwrap(), b"header\n");
+	
+	let mut buffer = frames.into_inner();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+}
\ No newline at end of file
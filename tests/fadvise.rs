@@ -0,0 +1,44 @@
+#![cfg(feature = "fadvise")]
+
+use std::fs::{self, File};
+use std::io::{Seek, SeekFrom};
+use std::path::PathBuf;
+
+use read_buffer::{DynReadBuffer, SequentialFile, SequentialReadBuffer};
+
+fn create_file(name: &str, data: &[u8]) -> PathBuf {
+	let path = std::env::temp_dir().join(format!("read_buffer_{}_{name}", std::process::id()));
+	fs::write(&path, data).unwrap();
+	path
+}
+
+#[test]
+fn reads_whole_file() {
+	let data: Vec<u8> = (0..100_000).map(|i| i as u8).collect();
+	let path = create_file("sequential", &data);
+	let mut buffer = SequentialReadBuffer::sequential(File::open(&path).unwrap());
+	
+	let mut read = Vec::new();
+	
+	while let Ok(chunk) = buffer.read_bytes(4096) {
+		read.extend_from_slice(chunk);
+	}
+	
+	read.extend_from_slice(buffer.available());
+	assert_eq!(read, data);
+	
+	fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn starts_at_current_position() {
+	let path = create_file("sequential_position", b"Hello World");
+	let mut file = SequentialFile::open(&path).unwrap().into_inner();
+	file.seek(SeekFrom::Start(6)).unwrap();
+	let mut buffer = DynReadBuffer::new(SequentialFile::new(file));
+	
+	let result = buffer.read_bytes(5).unwrap();
+	assert_eq!(result, b"World");
+	
+	fs::remove_file(path).unwrap();
+}
\ No newline at end of file
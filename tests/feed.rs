@@ -0,0 +1,40 @@
+use std::io::{ErrorKind, Write};
+
+use read_buffer::DynReadBuffer;
+
+#[test]
+fn feed_in_pieces() {
+	let mut buffer = DynReadBuffer::new_fed();
+	
+	buffer.feeder().write_all(&[1, 2]).unwrap();
+	assert_eq!(buffer.try_read_bytes(3).unwrap(), None);
+	
+	buffer.feeder().write_all(&[3, 4]).unwrap();
+	assert_eq!(buffer.try_read_bytes(3).unwrap(), Some([1, 2, 3].as_slice()));
+	assert_eq!(buffer.read_bytes(2).unwrap_err().kind(), ErrorKind::WouldBlock);
+	assert_eq!(buffer.available(), [4]);
+}
+
+#[test]
+fn finish_feeding() {
+	let mut buffer = DynReadBuffer::new_fed();
+	
+	write!(buffer.feeder(), "first\nlast").unwrap();
+	buffer.finish_feeding();
+	
+	let mut records = buffer.records(b'\n');
+	assert_eq!(records.next_record().unwrap().unwrap(), b"first\n");
+	assert_eq!(records.next_record().unwrap().unwrap(), b"last");
+	assert!(records.next_record().is_none());
+}
+
+#[test]
+fn feed_after_finishing() {
+	let mut buffer = DynReadBuffer::new_fed();
+	buffer.finish_feeding();
+	
+	assert_eq!(buffer.read_bytes(1).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+	
+	buffer.feeder().write_all(b"late").unwrap();
+	assert_eq!(buffer.read_bytes(4).unwrap(), b"late");
+}
\ No newline at end of file
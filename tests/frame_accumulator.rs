@@ -0,0 +1,71 @@
+use read_buffer::FrameAccumulator;
+
+#[test]
+fn next_until() {
+	let mut accumulator = FrameAccumulator::new();
+	
+	accumulator.feed(&[1, 2, 0, 3]);
+	
+	let result = accumulator.next_until(0).unwrap();
+	assert_eq!(result, [1, 2, 0]);
+	
+	assert!(accumulator.next_until(0).is_none());
+	
+	accumulator.feed(&[4, 0, 5, 0]);
+	
+	let result = accumulator.next_until(0).unwrap();
+	assert_eq!(result, [3, 4, 0]);
+	
+	let result = accumulator.next_until(0).unwrap();
+	assert_eq!(result, [5, 0]);
+	
+	assert!(accumulator.is_empty());
+}
+
+#[test]
+fn next_bytes() {
+	let mut accumulator = FrameAccumulator::new();
+	
+	assert!(accumulator.next_bytes(2).is_none());
+	
+	accumulator.feed(&[1]);
+	assert!(accumulator.next_bytes(2).is_none());
+	
+	accumulator.feed(&[2, 3]);
+	
+	let result = accumulator.next_bytes(2).unwrap();
+	assert_eq!(result, [1, 2]);
+	assert_eq!(accumulator.len(), 1);
+	
+	let result = accumulator.next_bytes(0).unwrap();
+	assert!(result.is_empty());
+}
+
+#[test]
+fn mixed() {
+	let mut accumulator = FrameAccumulator::default();
+	
+	accumulator.feed(b"3:abcline\n");
+	
+	let result = accumulator.next_until(b':').unwrap();
+	assert_eq!(result, b"3:");
+	
+	let result = accumulator.next_bytes(3).unwrap();
+	assert_eq!(result, b"abc");
+	
+	let result = accumulator.next_until(b'\n').unwrap();
+	assert_eq!(result, b"line\n");
+}
+
+#[test]
+fn with_capacity() {
+	let mut accumulator = FrameAccumulator::with_capacity(64);
+	
+	accumulator.feed(&[1; 40]);
+	let original_address = accumulator.next_bytes(40).unwrap().as_ptr();
+	
+	accumulator.feed(&[2; 64]);
+	let new_address = accumulator.next_bytes(64).unwrap().as_ptr();
+	
+	assert_eq!(original_address, new_address);
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use read_buffer::DynReadBuffer;
+
+#[test]
+fn from_vec() {
+	let mut buffer = DynReadBuffer::from_bytes(vec![1, 2, 3, 4, 5]);
+	
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+	assert_eq!(buffer.read_array::<3>().unwrap(), &[3, 4, 5]);
+	assert!(buffer.read_bytes(1).is_err());
+	assert_eq!(buffer.total_bytes_read(), 0);
+}
+
+#[test]
+fn from_slice() {
+	let data = b"first\nsecond\n".as_slice();
+	let mut buffer = DynReadBuffer::from_bytes(data);
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"first\n");
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"second\n");
+	assert!(buffer.read_until(b'\n').is_err());
+}
+
+#[test]
+fn from_empty() {
+	let mut buffer = DynReadBuffer::from_bytes(Vec::new());
+	
+	assert!(buffer.read_bytes(1).is_err());
+	assert!(buffer.available().is_empty());
+}
+
+#[test]
+fn records_from_bytes() {
+	let mut buffer = DynReadBuffer::from_bytes("a\nb".as_bytes());
+	let mut records = buffer.records(b'\n');
+	
+	assert_eq!(records.next_record().unwrap().unwrap(), b"a\n");
+	assert_eq!(records.next_record().unwrap().unwrap(), b"b");
+	assert!(records.next_record().is_none());
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::sync::{Arc, Mutex};
+
+use read_buffer::{DynReadBuffer, GrowthPolicy, ReadObserver};
+
+struct GrowLog(Arc<Mutex<Vec<usize>>>);
+
+impl ReadObserver for GrowLog {
+	fn on_grow(&mut self, _old_capacity: usize, new_capacity: usize) {
+		self.0.lock().unwrap().push(new_capacity);
+	}
+}
+
+fn capacities(growth_policy: GrowthPolicy) -> Vec<usize> {
+	let reader = [7; 400].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	let log = Arc::new(Mutex::new(Vec::new()));
+	buffer.set_observer(GrowLog(log.clone()));
+	buffer.set_growth_policy(growth_policy);
+	assert_eq!(buffer.growth_policy(), growth_policy);
+	
+	assert_eq!(buffer.read_bytes(100).unwrap(), [7; 100]);
+	assert_eq!(buffer.read_bytes(150).unwrap(), [7; 150]);
+	
+	let capacities = log.lock().unwrap().clone();
+	capacities
+}
+
+#[test]
+fn default_is_amortized() {
+	let buffer = DynReadBuffer::new([].as_slice());
+	assert_eq!(buffer.growth_policy(), GrowthPolicy::Amortized);
+}
+
+#[test]
+fn exact() {
+	assert_eq!(capacities(GrowthPolicy::Exact), [100, 150]);
+}
+
+#[test]
+fn amortized() {
+	assert_eq!(capacities(GrowthPolicy::Amortized), [100, 200]);
+}
+
+#[test]
+fn chunked() {
+	assert_eq!(capacities(GrowthPolicy::Chunked(64)), [128, 192]);
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+#![cfg(all(feature = "huge-pages", target_os = "linux"))]
+
+use read_buffer::{DynReadBuffer, HugePages};
+
+fn read_through(huge_pages: HugePages) {
+	let data: Vec<u8> = (0..5 * 1024 * 1024).map(|i: u32| (i % 251) as u8).collect();
+	let mut buffer = DynReadBuffer::with_huge_pages(data.as_slice(), 1024 * 1024, huge_pages);
+	
+	let result = buffer.read_bytes(10).unwrap();
+	assert_eq!(result, &data[..10]);
+	
+	// grows beyond the initial allocation
+	let result = buffer.read_bytes(3 * 1024 * 1024).unwrap();
+	assert_eq!(result, &data[10..3 * 1024 * 1024 + 10]);
+}
+
+#[test]
+fn transparent() {
+	read_through(HugePages::Transparent);
+}
+
+#[test]
+fn explicit() {
+	read_through(HugePages::Explicit);
+}
\ No newline at end of file
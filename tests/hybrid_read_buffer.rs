@@ -0,0 +1,47 @@
+use std::io::ErrorKind;
+
+use read_buffer::HybridReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_from_inline() {
+	let mut reader = [1, 2, 3].as_slice();
+	let mut buffer: HybridReadBuffer<8> = HybridReadBuffer::new();
+	
+	assert_eq!(buffer.read_from(&mut reader).unwrap(), [1, 2, 3]);
+	assert!(buffer.read_from(&mut reader).unwrap().is_empty());
+	assert_eq!(buffer.capacity(), 8);
+}
+
+#[test]
+fn read_bytes_spills() {
+	let data: Vec<u8> = (0..100).collect();
+	let mut reader = data.as_slice();
+	let mut buffer: HybridReadBuffer<16> = HybridReadBuffer::new();
+	
+	assert_eq!(buffer.read_bytes(&mut reader, 10).unwrap(), &data[..10]);
+	assert_eq!(buffer.capacity(), 16);
+	
+	assert_eq!(buffer.read_bytes(&mut reader, 80).unwrap(), &data[10..90]);
+	assert!(buffer.capacity() >= 80);
+	
+	let error = buffer.read_bytes(&mut reader, 20).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.read_from(&mut reader).unwrap(), &data[90..]);
+}
+
+#[test]
+fn read_until_carries_over() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab\ncd".to_vec());
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(b"e\nf".to_vec());
+	let mut buffer: HybridReadBuffer<4> = HybridReadBuffer::new();
+	
+	assert_eq!(buffer.read_until(&mut reader, b'\n').unwrap(), b"ab\n");
+	assert_eq!(buffer.read_until(&mut reader, b'\n').unwrap(), b"cde\n");
+	
+	let error = buffer.read_until(&mut reader, b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.read_from(&mut reader).unwrap(), b"f");
+}
\ No newline at end of file
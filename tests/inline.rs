@@ -0,0 +1,58 @@
+use std::sync::{Arc, Mutex};
+
+use read_buffer::{DynReadBuffer, ReadObserver};
+
+#[derive(Default)]
+struct GrowRecorder {
+	grown: Arc<Mutex<Vec<(usize, usize)>>>,
+}
+
+impl ReadObserver for GrowRecorder {
+	fn on_grow(&mut self, old_capacity: usize, new_capacity: usize) {
+		self.grown.lock().unwrap().push((old_capacity, new_capacity));
+	}
+}
+
+#[test]
+fn small_messages_stay_inline() {
+	let reader = b"PING\n".repeat(100);
+	let mut buffer: DynReadBuffer<_, 64> = DynReadBuffer::new_inline(reader.as_slice());
+	let recorder = GrowRecorder::default();
+	let grown = recorder.grown.clone();
+	buffer.set_observer(recorder);
+	
+	for _ in 0..100 {
+		assert_eq!(buffer.read_until(b'\n').unwrap(), b"PING\n");
+	}
+	
+	assert!(grown.lock().unwrap().is_empty());
+}
+
+#[test]
+fn spills_to_heap() {
+	let mut data = vec![1; 40];
+	data.extend([2; 100]);
+	data.extend([3; 10]);
+	let mut buffer: DynReadBuffer<_, 64> = DynReadBuffer::new_inline(data.as_slice());
+	let recorder = GrowRecorder::default();
+	let grown = recorder.grown.clone();
+	buffer.set_observer(recorder);
+	
+	assert_eq!(buffer.read_bytes(40).unwrap(), [1; 40]);
+	assert_eq!(buffer.read_bytes(100).unwrap(), [2; 100]);
+	assert_eq!(buffer.read_bytes(10).unwrap(), [3; 10]);
+	
+	let grown = grown.lock().unwrap();
+	assert_eq!(grown.len(), 1);
+	assert_eq!(grown[0].0, 64);
+	assert!(grown[0].1 >= 100);
+}
+
+#[test]
+fn spilling_keeps_buffered_data() {
+	let reader = b"short\na much longer line which doesn't fit inline anymore\n";
+	let mut buffer: DynReadBuffer<_, 16> = DynReadBuffer::new_inline(reader.as_slice());
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"short\n");
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"a much longer line which doesn't fit inline anymore\n");
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+#![cfg(feature = "thread-local-pool")]
+
+use std::thread;
+
+use read_buffer::DynReadBuffer;
+
+#[test]
+fn reuse_pooled_buffers() {
+	for index in 0..100 {
+		let data = format!("request {index}\n");
+		let mut buffer = DynReadBuffer::new_pooled(data.as_bytes());
+		
+		assert_eq!(buffer.read_until(b'\n').unwrap(), data.as_bytes());
+	}
+}
+
+#[test]
+fn pooled_buffers_grow() {
+	let data = [3; 100_000];
+	
+	for _ in 0..3 {
+		let mut buffer = DynReadBuffer::new_pooled(data.as_slice());
+		assert_eq!(buffer.read_bytes(100_000).unwrap(), data);
+	}
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn drop_on_other_thread() {
+	let mut buffer = DynReadBuffer::new_pooled("moved\n".as_bytes());
+	assert_eq!(buffer.read_bytes(3).unwrap(), b"mov");
+	
+	thread::spawn(move || {
+		assert_eq!(buffer.read_until(b'\n').unwrap(), b"ed\n");
+	}).join().unwrap();
+	
+	let mut buffer = DynReadBuffer::new_pooled("again\n".as_bytes());
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"again\n");
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn lookahead_does_not_consume() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.lookahead(2).unwrap(), [1, 2]);
+	assert_eq!(buffer.lookahead(3).unwrap(), [1, 2, 3]);
+	assert_eq!(buffer.read_bytes(4).unwrap(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn lookahead_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1]);
+	reader.add_chunk(vec![2, 3]);
+	reader.add_chunk(vec![4, 5, 6]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.lookahead(5).unwrap(), [1, 2, 3, 4, 5]);
+	buffer.consume(4);
+	assert_eq!(buffer.lookahead(2).unwrap(), [5, 6]);
+	assert_eq!(buffer.frames_returned(), 1);
+}
+
+#[test]
+fn lookahead_eof() {
+	let reader = [1, 2].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.lookahead(3).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn consume_too_much() {
+	let reader = [1, 2].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.lookahead(1).unwrap();
+	buffer.consume(3);
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn reset_to_mark() {
+	let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_bytes(1).unwrap(), [1]);
+	buffer.mark();
+	assert_eq!(buffer.read_bytes(3).unwrap(), [2, 3, 4]);
+	buffer.reset_to_mark();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [2, 3]);
+	buffer.reset_to_mark();
+	assert_eq!(buffer.read_bytes(5).unwrap(), [2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn marked_data_survives_growth() {
+	let mut reader = ChunkedReader::new();
+	
+	for chunk in (0..100u8).collect::<Vec<_>>().chunks(7) {
+		reader.add_chunk(chunk.to_vec());
+	}
+	
+	let mut buffer = DynReadBuffer::with_capacity(reader, 8);
+	
+	assert_eq!(buffer.read_bytes(5).unwrap(), [0, 1, 2, 3, 4]);
+	buffer.mark();
+	
+	for start in (5..95).step_by(10) {
+		let expected: Vec<u8> = (start..start + 10).collect();
+		assert_eq!(buffer.read_bytes(10).unwrap(), expected);
+	}
+	
+	buffer.reset_to_mark();
+	let expected: Vec<u8> = (5..100).collect();
+	assert_eq!(buffer.read_bytes(95).unwrap(), expected);
+}
+
+#[test]
+fn marked_data_survives_alignment() {
+	let mut reader = ChunkedReader::new();
+	
+	for chunk in (0..64u8).collect::<Vec<_>>().chunks(5) {
+		reader.add_chunk(chunk.to_vec());
+	}
+	
+	let mut buffer = DynReadBuffer::with_alignment(reader, 8, 16);
+	
+	assert_eq!(buffer.read_bytes(3).unwrap(), [0, 1, 2]);
+	buffer.mark();
+	assert_eq!(buffer.read_bytes(40).unwrap(), (3..43).collect::<Vec<_>>());
+	buffer.reset_to_mark();
+	assert_eq!(buffer.read_bytes(61).unwrap(), (3..64).collect::<Vec<_>>());
+}
+
+#[test]
+fn clear_mark() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.mark();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+	buffer.clear_mark();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn reset_without_mark() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.read_bytes(2).unwrap();
+	buffer.reset_to_mark();
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn try_read_bytes() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_error(ErrorKind::WouldBlock.into());
+	reader.add_chunk(vec![3, 4]);
+	reader.add_error(ErrorKind::WouldBlock.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.try_read_bytes(3).unwrap();
+	assert!(result.is_none());
+	
+	let result = buffer.try_read_bytes(3).unwrap();
+	assert_eq!(result, Some([1, 2, 3].as_slice()));
+	
+	let result = buffer.try_read_bytes(2).unwrap();
+	assert!(result.is_none());
+	
+	let result = buffer.try_read_bytes(1).unwrap();
+	assert_eq!(result, Some([4].as_slice()));
+}
+
+#[test]
+fn try_read_until() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_error(ErrorKind::WouldBlock.into());
+	reader.add_error(ErrorKind::WouldBlock.into());
+	reader.add_chunk(vec![3, 0, 4]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.try_read_until(0).unwrap();
+	assert!(result.is_none());
+	
+	let result = buffer.try_read_until(0).unwrap();
+	assert!(result.is_none());
+	
+	let result = buffer.try_read_until(0).unwrap();
+	assert_eq!(result, Some([1, 2, 3, 0].as_slice()));
+	
+	let result = buffer.read_bytes(1).unwrap();
+	assert_eq!(result, [4]);
+}
+
+#[test]
+fn other_errors() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1]);
+	reader.add_error(ErrorKind::NotFound.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.try_read_until(0).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+	
+	let error = buffer.try_read_bytes(2).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn fill_once_and_extract() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 0, 2, 0, 3]);
+	reader.add_error(ErrorKind::WouldBlock.into());
+	reader.add_chunk(vec![4, 5, 0]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.fill_once().unwrap(), 5);
+	assert_eq!(buffer.next_until(0), Some([1, 0].as_slice()));
+	assert_eq!(buffer.next_until(0), Some([2, 0].as_slice()));
+	assert_eq!(buffer.next_until(0), None);
+	
+	let error = buffer.fill_once().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::WouldBlock);
+	
+	assert_eq!(buffer.fill_once().unwrap(), 3);
+	assert_eq!(buffer.next_bytes(5), None);
+	assert_eq!(buffer.next_bytes(3), Some([3, 4, 5].as_slice()));
+	assert_eq!(buffer.next_until(0), Some([0].as_slice()));
+	
+	assert_eq!(buffer.fill_once().unwrap(), 0);
+}
+
+#[test]
+fn fill_once_and_consume() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 3]);
+	reader.add_chunk(vec![4]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert!(buffer.available().is_empty());
+	assert_eq!(buffer.fill_once().unwrap(), 3);
+	assert_eq!(buffer.available(), [1, 2, 3]);
+	
+	buffer.consume(2);
+	assert_eq!(buffer.available(), [3]);
+	
+	assert_eq!(buffer.fill_once().unwrap(), 1);
+	assert_eq!(buffer.available(), [3, 4]);
+	assert_eq!(buffer.fill_once().unwrap(), 0);
+}
+
+#[test]
+fn buffered_slices() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.fill_once().unwrap();
+	buffer.consume(1);
+	
+	let (front, back) = buffer.buffered_slices();
+	assert_eq!([front, back].concat(), [2, 3]);
+}#[test]
+fn read_available_frames() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 0, 2, 0, 3]);
+	reader.add_error(ErrorKind::WouldBlock.into());
+	reader.add_chunk(vec![4, 0, 5, 0]);
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut frames = Vec::new();
+	
+	let result = buffer.read_available_frames(0, |frame| frames.push(frame.to_vec()));
+	assert_eq!(result.unwrap(), 5);
+	assert_eq!(frames, [vec![1, 0], vec![2, 0]]);
+	
+	let error = buffer.read_available_frames(0, |frame| frames.push(frame.to_vec())).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::WouldBlock);
+	assert_eq!(frames.len(), 2);
+	
+	let result = buffer.read_available_frames(0, |frame| frames.push(frame.to_vec()));
+	assert_eq!(result.unwrap(), 4);
+	assert_eq!(frames, [vec![1, 0], vec![2, 0], vec![3, 4, 0], vec![5, 0]]);
+	
+	let result = buffer.read_available_frames(0, |frame| frames.push(frame.to_vec()));
+	assert_eq!(result.unwrap(), 0);
+	assert_eq!(frames.len(), 4);
+}
+
+#[test]
+fn drain_buffered() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 3]);
+	reader.add_error(ErrorKind::NotFound.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert!(buffer.drain_buffered(2).is_empty());
+	
+	buffer.fill_once().unwrap();
+	assert_eq!(buffer.drain_buffered(2), [1, 2]);
+	assert_eq!(buffer.drain_buffered(2), [3]);
+	
+	// never reaches the error
+	assert!(buffer.drain_buffered(2).is_empty());
+	assert_eq!(buffer.read_calls(), 1);
+}
+
+#[test]
+fn get_ref() {
+	let mut buffer = DynReadBuffer::new([1, 2, 3, 4].as_slice());
+	
+	assert_eq!(buffer.read_bytes(1).unwrap(), [1]);
+	assert_eq!(*buffer.get_ref(), [2, 3, 4]);
+	
+	*buffer.get_mut() = [5, 6].as_slice();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [5, 6]);
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::io::{self, ErrorKind};
+use std::sync::{Arc, Mutex};
+
+use read_buffer::{DynReadBuffer, ReadObserver};
+use read_buffer::test_util::ChunkedReader;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Event {
+	Read(Result<usize, ErrorKind>),
+	Grow(usize, usize),
+	Frame(usize),
+}
+
+#[derive(Default)]
+struct Recorder {
+	events: Arc<Mutex<Vec<Event>>>,
+	fail_reads: bool,
+}
+
+impl ReadObserver for Recorder {
+	fn on_read(&mut self, result: &io::Result<usize>) -> io::Result<()> {
+		let result = result.as_ref().map(|length| *length).map_err(|err| err.kind());
+		self.events.lock().unwrap().push(Event::Read(result));
+		
+		if self.fail_reads {
+			return Err(ErrorKind::ConnectionReset.into());
+		}
+		
+		Ok(())
+	}
+	
+	fn on_grow(&mut self, old_capacity: usize, new_capacity: usize) {
+		self.events.lock().unwrap().push(Event::Grow(old_capacity, new_capacity));
+	}
+	
+	fn on_frame(&mut self, length: usize) {
+		self.events.lock().unwrap().push(Event::Frame(length));
+	}
+}
+
+#[test]
+fn records_lifecycle() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(b"c\nd".to_vec());
+	let mut buffer = DynReadBuffer::with_capacity(reader, 4);
+	
+	let events = Arc::new(Mutex::new(Vec::new()));
+	buffer.set_observer(Recorder {
+		events: Arc::clone(&events),
+		fail_reads: false,
+	});
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, b"ab");
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"c\n");
+	
+	assert_eq!(*events.lock().unwrap(), [
+		Event::Read(Ok(2)),
+		Event::Frame(2),
+		Event::Grow(4, 8192),
+		Event::Read(Err(ErrorKind::Interrupted)),
+		Event::Read(Ok(3)),
+		Event::Frame(2),
+	]);
+}
+
+#[test]
+fn injected_fault() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.set_observer(Recorder {
+		fail_reads: true,
+		..Default::default()
+	});
+	
+	let error = buffer.read_bytes(2).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::ConnectionReset);
+	
+	buffer.set_observer(Recorder::default());
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [1, 2, 3]);
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+#![cfg(feature = "rayon")]
+
+use std::io::ErrorKind;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+fn numbers(count: u64) -> Vec<u8> {
+	(0..count).map(|number| format!("{number}\n")).collect::<String>().into_bytes()
+}
+
+fn parse(frame: &[u8]) -> u64 {
+	std::str::from_utf8(frame).unwrap().trim().parse().unwrap()
+}
+
+#[test]
+fn processes_all_frames() {
+	let data = numbers(1000);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	let seen = Mutex::new(Vec::new());
+	
+	buffer.process_frames_parallel(b'\n', |frame| {
+		seen.lock().unwrap().push(parse(&frame));
+	}).unwrap();
+	
+	let mut seen = seen.into_inner().unwrap();
+	seen.sort_unstable();
+	assert_eq!(seen, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn ordered_output() {
+	let data = numbers(50);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	let mut output = Vec::new();
+	
+	buffer.process_frames_parallel_ordered(
+		b'\n',
+		|frame| {
+			let number = parse(&frame);
+			// later frames finish first
+			thread::sleep(Duration::from_micros(50 - number));
+			number * 2
+		},
+		|doubled| output.push(doubled),
+	).unwrap();
+	
+	assert_eq!(output, (0..50).map(|number| number * 2).collect::<Vec<_>>());
+}
+
+#[test]
+fn read_error() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"1\n2\n3".to_vec());
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut output = Vec::new();
+	
+	let error = buffer.process_frames_parallel_ordered(b'\n', |frame| parse(&frame), |number| output.push(number))
+		.unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::ConnectionReset);
+	assert_eq!(output, [1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "bad frame")]
+fn panic_is_resumed() {
+	let data = numbers(100);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	let _ = buffer.process_frames_parallel(b'\n', |frame| {
+		if parse(&frame) == 42 {
+			panic!("bad frame");
+		}
+	});
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+
+use read_buffer::DynReadBuffer;
+
+fn connected_pair() -> (TcpStream, TcpStream) {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+	let (server, _) = listener.accept().unwrap();
+	(client, server)
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires sockets")]
+fn peek_keeps_data_in_socket() {
+	let (mut client, server) = connected_pair();
+	client.write_all(b"GET /").unwrap();
+	client.shutdown(Shutdown::Write).unwrap();
+	
+	let mut buffer = DynReadBuffer::new(&server);
+	let result = buffer.peek_from_socket(3).unwrap();
+	assert_eq!(result, b"GET");
+	drop(buffer);
+	
+	let mut data = Vec::new();
+	(&server).read_to_end(&mut data).unwrap();
+	assert_eq!(data, b"GET /");
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires sockets")]
+fn peek_after_buffered() {
+	let (mut client, server) = connected_pair();
+	client.write_all(b"ab\ncd").unwrap();
+	client.shutdown(Shutdown::Write).unwrap();
+	
+	let mut buffer = DynReadBuffer::new(&server);
+	buffer.lookahead(2).unwrap();
+	
+	let result = buffer.peek_from_socket(5).unwrap();
+	assert_eq!(result, b"ab\ncd");
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, b"cd");
+	
+	let result = buffer.peek_from_socket(1).unwrap();
+	assert_eq!(result, b"");
+}
\ No newline at end of file
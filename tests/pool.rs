@@ -0,0 +1,66 @@
+use read_buffer::BufferPool;
+
+#[test]
+fn reuses_buffers() {
+	let pool = BufferPool::new(&[64, 1024], 4096);
+	
+	let mut buffer = pool.buffer("first\n".as_bytes(), 10);
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"first\n");
+	drop(buffer);
+	assert_eq!(pool.retained(), 64);
+	
+	let mut buffer = pool.buffer("second\n".as_bytes(), 64);
+	assert_eq!(pool.retained(), 0);
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"second\n");
+	drop(buffer);
+	assert_eq!(pool.retained(), 64);
+}
+
+#[test]
+fn size_classes() {
+	let pool = BufferPool::new(&[1024, 64], 4096);
+	
+	let small = pool.buffer([].as_slice(), 0);
+	let large = pool.buffer([].as_slice(), 65);
+	drop(small);
+	drop(large);
+	assert_eq!(pool.retained(), 1088);
+	
+	let _large = pool.buffer([].as_slice(), 1000);
+	assert_eq!(pool.retained(), 64);
+}
+
+#[test]
+fn oversized_buffers_are_not_pooled() {
+	let pool = BufferPool::new(&[64], 4096);
+	
+	let buffer = pool.buffer([].as_slice(), 100);
+	drop(buffer);
+	assert_eq!(pool.retained(), 0);
+}
+
+#[test]
+fn limits_retained_memory() {
+	let pool = BufferPool::new(&[64], 100);
+	
+	let first = pool.buffer([].as_slice(), 64);
+	let second = pool.buffer([].as_slice(), 64);
+	drop(first);
+	drop(second);
+	assert_eq!(pool.retained(), 64);
+}
+
+#[test]
+fn grown_buffers_are_returned() {
+	let pool = BufferPool::new(&[16], 1 << 20);
+	let data = [7; 1000];
+	
+	let mut buffer = pool.buffer(data.as_slice(), 16);
+	assert_eq!(buffer.read_bytes(1000).unwrap(), [7; 1000]);
+	drop(buffer);
+	assert!(pool.retained() >= 1000);
+	
+	let mut buffer = pool.buffer(data.as_slice(), 16);
+	assert_eq!(pool.retained(), 0);
+	assert_eq!(buffer.read_bytes(1000).unwrap(), [7; 1000]);
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use std::io::{self, Cursor, ErrorKind, Read};
+
+use read_buffer::{DynReadBuffer, PrefetchReader};
+
+struct FailingReader {
+	remaining: Vec<io::Result<Vec<u8>>>,
+}
+
+impl Read for FailingReader {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		if self.remaining.is_empty() {
+			return Ok(0);
+		}
+		
+		let chunk = self.remaining.remove(0)?;
+		buf[..chunk.len()].copy_from_slice(&chunk);
+		Ok(chunk.len())
+	}
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn reads_everything() {
+	let data: Vec<u8> = (0..10_000).map(|i| i as u8).collect();
+	let mut reader = PrefetchReader::new(Cursor::new(data.clone()), 64);
+	
+	let mut result = Vec::new();
+	reader.read_to_end(&mut result).unwrap();
+	assert_eq!(result, data);
+	assert_eq!(reader.read(&mut [0; 8]).unwrap(), 0);
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn passes_on_errors() {
+	let reader = FailingReader {
+		remaining: vec![
+			Ok(b"ab\n".to_vec()),
+			Err(ErrorKind::Interrupted.into()),
+			Ok(b"c".to_vec()),
+			Err(ErrorKind::ConnectionReset.into()),
+			Ok(b"d\n".to_vec()),
+		],
+	};
+	let mut buffer = DynReadBuffer::prefetching(reader, 16);
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"ab\n");
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::ConnectionReset);
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"cd\n");
+	
+	let error = buffer.read_bytes(1).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
\ No newline at end of file
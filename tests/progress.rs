@@ -0,0 +1,37 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn reports_progress() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1; 4]);
+	reader.add_chunk(vec![2; 3]);
+	reader.add_chunk(vec![3; 5]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut progress = Vec::new();
+	let result = buffer.read_bytes_with_progress(10, |read, total| progress.push((read, total))).unwrap();
+	assert_eq!(result, [1, 1, 1, 1, 2, 2, 2, 3, 3, 3]);
+	assert_eq!(progress, [(0, 10), (4, 10), (7, 10), (10, 10)]);
+	
+	let mut progress = Vec::new();
+	let result = buffer.read_bytes_with_progress(2, |read, total| progress.push((read, total))).unwrap();
+	assert_eq!(result, [3, 3]);
+	assert_eq!(progress, [(0, 2), (2, 2)]);
+}
+
+#[test]
+fn unexpected_eof() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut progress = Vec::new();
+	let error = buffer.read_bytes_with_progress(5, |read, total| progress.push((read, total))).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(progress, [(0, 5), (3, 5)]);
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [1, 2, 3]);
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, QuotaExceeded};
+use read_buffer::test_util::ChunkedReader;
+
+fn quota_of(error: &std::io::Error) -> u64 {
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	error.get_ref()
+		.and_then(|err| err.downcast_ref::<QuotaExceeded>())
+		.expect("error should wrap QuotaExceeded")
+		.quota()
+}
+
+#[test]
+fn read_within_quota() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	buffer.set_quota(4);
+	
+	let result = buffer.read_bytes(4).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+}
+
+#[test]
+fn quota_exceeded() {
+	let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	buffer.set_quota(4);
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [1, 2, 3]);
+	
+	let error = buffer.read_bytes(2).unwrap_err();
+	assert_eq!(quota_of(&error), 4);
+	
+	let result = buffer.read_bytes(1).unwrap();
+	assert_eq!(result, [4]);
+	
+	let error = buffer.read_bytes(1).unwrap_err();
+	assert_eq!(quota_of(&error), 4);
+}
+
+#[test]
+fn quota_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_chunk(b"cd".to_vec());
+	reader.add_chunk(b"ef\n".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	buffer.set_quota(5);
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(quota_of(&error), 5);
+	
+	let result = buffer.read_bytes(5).unwrap();
+	assert_eq!(result, b"abcde");
+}
+
+#[test]
+fn quota_includes_earlier_reads() {
+	let reader = "first\nsecond\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_bytes(6).unwrap();
+	assert_eq!(result, b"first\n");
+	
+	buffer.set_quota(6);
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(quota_of(&error), 6);
+}
\ No newline at end of file
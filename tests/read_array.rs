@@ -0,0 +1,51 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read() {
+	let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_array::<4>().unwrap();
+	assert_eq!(result, &[1, 2, 3, 4]);
+	
+	let [a, b] = buffer.read_array().unwrap();
+	assert_eq!((*a, *b), (5, 6));
+}
+
+#[test]
+fn read_copied() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![9, 8]);
+	reader.add_chunk(vec![7]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: [u8; 3] = buffer.read_array_copied().unwrap();
+	assert_eq!(result, [9, 8, 7]);
+}
+
+#[test]
+fn read_empty() {
+	let reader = [1].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_array::<0>().unwrap();
+	assert!(result.is_empty());
+	
+	let result = buffer.read_array::<1>().unwrap();
+	assert_eq!(result, &[1]);
+}
+
+#[test]
+fn unexpected_eof() {
+	let reader = [1, 2].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_array::<3>().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = buffer.read_array_copied::<2>().unwrap();
+	assert_eq!(result, [1, 2]);
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+#![cfg(feature = "bytemuck")]
+
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read() {
+	let reader = [1, 2, 3, 4, 5].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: &[u8; 4] = buffer.read_as().unwrap();
+	assert_eq!(result, &[1, 2, 3, 4]);
+	
+	let result: &u8 = buffer.read_as().unwrap();
+	assert_eq!(*result, 5);
+}
+
+#[test]
+fn read_slice() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 3]);
+	reader.add_chunk(vec![4, 5, 6]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: &[[u8; 2]] = buffer.read_slice_as(3).unwrap();
+	assert_eq!(result, [[1, 2], [3, 4], [5, 6]]);
+}
+
+#[test]
+fn read_at_any_offset() {
+	let data: Vec<u8> = (0..64).collect();
+	
+	for offset in 0..8 {
+		let mut buffer = DynReadBuffer::with_capacity(data.as_slice(), 64);
+		buffer.read_bytes(offset).unwrap();
+		
+		let result: &u32 = buffer.read_as().unwrap();
+		let expected = u32::from_ne_bytes(data[offset..offset + 4].try_into().unwrap());
+		assert_eq!(*result, expected);
+		
+		let result: &u64 = buffer.read_as().unwrap();
+		let expected = u64::from_ne_bytes(data[offset + 4..offset + 12].try_into().unwrap());
+		assert_eq!(*result, expected);
+		
+		let result: &[u16] = buffer.read_slice_as(2).unwrap();
+		let expected = [
+			u16::from_ne_bytes([data[offset + 12], data[offset + 13]]),
+			u16::from_ne_bytes([data[offset + 14], data[offset + 15]]),
+		];
+		assert_eq!(result, expected);
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+struct Header {
+	magic: u32,
+	version: u16,
+	flags: u16,
+	length: u64,
+}
+
+// SAFETY: Header is repr(C), has no padding and only contains integers
+unsafe impl bytemuck::Zeroable for Header {}
+unsafe impl bytemuck::Pod for Header {}
+
+#[test]
+fn read_struct_at_odd_offset() {
+	let header = Header {
+		magic: 0xcafe_babe,
+		version: 3,
+		flags: 0x8001,
+		length: 1 << 40,
+	};
+	
+	let mut data = vec![0xff; 3];
+	data.extend_from_slice(bytemuck::bytes_of(&header));
+	data.push(0xee);
+	
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(data[..5].to_vec());
+	reader.add_chunk(data[5..].to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.read_bytes(3).unwrap();
+	
+	let result: &Header = buffer.read_as().unwrap();
+	assert_eq!(*result, header);
+	
+	let result = buffer.read_bytes(1).unwrap();
+	assert_eq!(result, [0xee]);
+}
+
+#[test]
+fn aligning_keeps_marked_data() {
+	let reader = [1, 2, 3, 4, 5, 6, 7].as_slice();
+	let mut buffer = DynReadBuffer::with_capacity(reader, 64);
+	
+	buffer.read_bytes(1).unwrap();
+	buffer.mark();
+	buffer.read_bytes(2).unwrap();
+	
+	let result: &u32 = buffer.read_as().unwrap();
+	assert_eq!(*result, u32::from_ne_bytes([4, 5, 6, 7]));
+	
+	buffer.reset_to_mark();
+	let result = buffer.read_bytes(6).unwrap();
+	assert_eq!(result, [2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn size_overflow() {
+	let reader = [0; 8].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_slice_as::<u32>(usize::MAX).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn unexpected_eof() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_as::<[u8; 4]>().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [1, 2, 3]);
+}
\ No newline at end of file
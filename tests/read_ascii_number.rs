@@ -0,0 +1,81 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn unsigned() {
+	let reader = "42 +7 18446744073709551615".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_ascii_u64().unwrap(), 42);
+	buffer.read_bytes(1).unwrap();
+	assert_eq!(buffer.read_ascii_u64().unwrap(), 7);
+	buffer.read_bytes(1).unwrap();
+	assert_eq!(buffer.read_ascii_u64().unwrap(), u64::MAX);
+}
+
+#[test]
+fn signed() {
+	let reader = "-12,+3,4".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_ascii_i64().unwrap(), -12);
+	buffer.read_bytes(1).unwrap();
+	assert_eq!(buffer.read_ascii_i64().unwrap(), 3);
+	buffer.read_bytes(1).unwrap();
+	assert_eq!(buffer.read_ascii_i64().unwrap(), 4);
+}
+
+#[test]
+fn invalid() {
+	let reader = "-5 x 18446744073709551616".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_ascii_u64().unwrap_err().kind(), ErrorKind::InvalidData);
+	assert_eq!(buffer.read_bytes(3).unwrap(), b"-5 ");
+	assert_eq!(buffer.read_ascii_i64().unwrap_err().kind(), ErrorKind::InvalidData);
+	assert_eq!(buffer.read_bytes(2).unwrap(), b"x ");
+	assert_eq!(buffer.read_ascii_u64().unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn float() {
+	let reader = "3.25 -.5 1e3 2E-2 7. 1east".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut read = || {
+		let number = buffer.read_ascii_f64().unwrap();
+		buffer.read_bytes(1).unwrap();
+		number
+	};
+	
+	assert_eq!(read(), 3.25);
+	assert_eq!(read(), -0.5);
+	assert_eq!(read(), 1000.0);
+	assert_eq!(read(), 0.02);
+	assert_eq!(read(), 7.0);
+	assert_eq!(read(), 1.0);
+	assert_eq!(buffer.read_bytes(3).unwrap(), b"ast");
+}
+
+#[test]
+fn float_f32() {
+	let reader = "0.5".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_ascii_f32().unwrap(), 0.5);
+}
+
+#[test]
+fn number_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"12".to_vec());
+	reader.add_chunk(b"34".to_vec());
+	reader.add_chunk(b"5.".to_vec());
+	reader.add_chunk(b"5e".to_vec());
+	reader.add_chunk(b"1;".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_ascii_f64().unwrap(), 123455.0);
+	assert_eq!(buffer.read_bytes(1).unwrap(), b";");
+}
\ No newline at end of file
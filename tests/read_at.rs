@@ -0,0 +1,69 @@
+#![cfg(any(unix, windows))]
+
+use std::fs::{self, File};
+use std::io::{ErrorKind, Read};
+use std::path::PathBuf;
+use std::thread;
+
+use read_buffer::{OffsetReader, ReadAtBuffer};
+
+fn create_file(name: &str, data: &[u8]) -> PathBuf {
+	let path = std::env::temp_dir().join(format!("read_buffer_{}_{name}", std::process::id()));
+	fs::write(&path, data).unwrap();
+	path
+}
+
+#[test]
+fn offset_reader_advances() {
+	let path = create_file("offset_reader", b"Hello World");
+	let file = File::open(&path).unwrap();
+	let mut reader = OffsetReader::new(&file, 6);
+	
+	let mut data = String::new();
+	reader.read_to_string(&mut data).unwrap();
+	assert_eq!(data, "World");
+	assert_eq!(reader.offset(), 11);
+	
+	fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn independent_offsets() {
+	let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+	let path = create_file("independent_offsets", &data);
+	let file = File::open(&path).unwrap();
+	
+	thread::scope(|scope| {
+		for offset in [0, 1000, 2000, 3000] {
+			let file = &file;
+			let data = &data;
+			
+			scope.spawn(move || {
+				let mut buffer = ReadAtBuffer::at(file, offset);
+				
+				for chunk in 0..10 {
+					let start = offset as usize + chunk * 100;
+					let result = buffer.read_bytes(100).unwrap();
+					assert_eq!(result, &data[start..start + 100]);
+				}
+			});
+		}
+	});
+	
+	fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn end_of_file() {
+	let path = create_file("end_of_file", b"line\npartial");
+	let file = File::open(&path).unwrap();
+	let mut buffer = ReadAtBuffer::at(&file, 2);
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ne\n");
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	fs::remove_file(path).unwrap();
+}
\ No newline at end of file
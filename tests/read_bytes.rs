@@ -1,9 +1,7 @@
-pub mod utils;
-
 use std::io::ErrorKind;
 
-use read_buffer::DynReadBuffer;
-use crate::utils::{ErrorReader, ChunkedReader};
+use read_buffer::{DynReadBuffer, ReadBuffer};
+use read_buffer::test_util::{ErrorReader, ChunkedReader};
 
 #[test]
 fn read() {
@@ -91,6 +89,34 @@ fn with_capacity() {
 	assert!(original_address <= new_address && new_address < original_address + 2048);
 }
 
+#[test]
+fn with_capacity_initialized_on_demand() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1]);
+	reader.add_chunk(vec![5; 1024]);
+	reader.add_chunk(vec![6; 1023]);
+	let mut buffer = DynReadBuffer::with_capacity(reader, 2048);
+	
+	let original_address = buffer.read_bytes(1).unwrap().as_ptr() as usize;
+	
+	let result = buffer.read_bytes(1024).unwrap();
+	assert_eq!(result, [5; 1024]);
+	assert_eq!(result.as_ptr() as usize, original_address + 1);
+	
+	let result = buffer.read_bytes(1023).unwrap();
+	assert_eq!(result, [6; 1023]);
+	assert_eq!(result.as_ptr() as usize, original_address + 1025);
+}
+
+#[test]
+fn large_capacity_initialized_lazily() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::with_capacity(reader, 1 << 30);
+	
+	assert_eq!(buffer.read_bytes(4).unwrap(), [1, 2, 3, 4]);
+	assert!(buffer.spare_capacity_mut().len() < 1 << 16);
+}
+
 #[test]
 fn unexpected_eof() {
 	let reader = [1, 2, 4, 8, 16].as_slice();
@@ -116,4 +142,72 @@ fn error_result() {
 	
 	let error = buffer.read_bytes(1).unwrap_err();
 	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn fixed_read_records() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 3]);
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(vec![4, 5, 6, 7]);
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let result = buffer.read_bytes(&mut reader, 2).unwrap();
+	assert_eq!(result, [1, 2]);
+	
+	let result = buffer.read_bytes(&mut reader, 4).unwrap();
+	assert_eq!(result, [3, 4, 5, 6]);
+	
+	let result = buffer.read_bytes(&mut reader, 1).unwrap();
+	assert_eq!(result, [7]);
+	
+	let error = buffer.read_bytes(&mut reader, 1).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn fixed_data_after_error() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_error(ErrorKind::NotFound.into());
+	reader.add_chunk(vec![3]);
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let error = buffer.read_bytes(&mut reader, 3).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+	
+	let result = buffer.read_bytes(&mut reader, 3).unwrap();
+	assert_eq!(result, [1, 2, 3]);
+	
+	let error = buffer.read_bytes(&mut ErrorReader, 1).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn read_mut() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 3]);
+	reader.add_chunk(vec![4, 5]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_bytes_mut(4).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	result.swap(0, 3);
+	assert_eq!(result, [4, 2, 3, 1]);
+	
+	let result = buffer.read_bytes(1).unwrap();
+	assert_eq!(result, [5]);
+}
+
+#[test]
+fn partial_data_after_eof() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_chunk(vec![3]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_bytes(5).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.available(), [1, 2, 3]);
 }
\ No newline at end of file
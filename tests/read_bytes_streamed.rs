@@ -0,0 +1,132 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn bounded_chunks() {
+	let data: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+	let mut buffer = DynReadBuffer::with_capacity(data.as_slice(), 64);
+	let mut output = Vec::new();
+	let mut largest_chunk = 0;
+	
+	buffer.read_bytes_streamed(9_999, |chunk| {
+		largest_chunk = largest_chunk.max(chunk.len());
+		output.extend_from_slice(chunk);
+		Ok(())
+	}).unwrap();
+	
+	assert_eq!(output, data[..9_999]);
+	assert!(largest_chunk <= 64);
+	assert_eq!(buffer.read_bytes(1).unwrap(), [data[9_999]]);
+}
+
+#[test]
+fn buffered_data_first() {
+	let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut output = Vec::new();
+	
+	assert_eq!(buffer.read_bytes(1).unwrap(), [1]);
+	buffer.read_bytes_streamed(4, |chunk| {
+		output.extend_from_slice(chunk);
+		Ok(())
+	}).unwrap();
+	
+	assert_eq!(output, [2, 3, 4, 5]);
+	assert_eq!(buffer.read_bytes(1).unwrap(), [6]);
+}
+
+#[test]
+fn unexpected_eof() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut output = Vec::new();
+	
+	let error = buffer.read_bytes_streamed(3, |chunk| {
+		output.extend_from_slice(chunk);
+		Ok(())
+	}).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(output, [1, 2]);
+}
+
+#[test]
+fn callback_error() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_bytes_streamed(3, |_| Err(ErrorKind::WriteZero.into())).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::WriteZero);
+	assert_eq!(buffer.read_bytes(3).unwrap(), [1, 2, 3]);
+}
+
+#[test]
+fn copy_to() {
+	let data: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+	let mut buffer = DynReadBuffer::with_capacity(data.as_slice(), 64);
+	let mut output = Vec::new();
+	
+	assert_eq!(buffer.read_bytes(1).unwrap(), [0]);
+	buffer.copy_to(&mut output, 9_998).unwrap();
+	
+	assert_eq!(output, data[1..9_999]);
+	assert_eq!(buffer.total_bytes_read(), 10_000);
+	assert_eq!(buffer.frames_returned(), 1);
+	assert_eq!(buffer.read_bytes(1).unwrap(), [data[9_999]]);
+}
+
+#[test]
+fn copy_to_unexpected_eof() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut output = Vec::new();
+	
+	let error = buffer.copy_to(&mut output, 3).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(output, [1, 2]);
+}
+
+#[test]
+fn copy_to_write_error() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut output = [0; 2];
+	
+	let error = buffer.copy_to(output.as_mut_slice(), 3).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::WriteZero);
+	assert_eq!(buffer.read_bytes(3).unwrap(), [1, 2, 3]);
+}
+
+#[test]
+fn read_until_into() {
+	let mut data: Vec<u8> = (1..=255).cycle().take(10_000).collect();
+	data.extend_from_slice(&[0, 1, 2]);
+	let mut buffer = DynReadBuffer::with_capacity(data.as_slice(), 64);
+	let mut output = Vec::new();
+	
+	assert_eq!(buffer.read_until_into(0, &mut output).unwrap(), 10_001);
+	
+	assert_eq!(output, data[..10_001]);
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+}
+
+#[test]
+fn read_until_into_unexpected_eof() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_chunk(vec![3]);
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut output = Vec::new();
+	
+	let error = buffer.read_until_into(0, &mut output).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(output, [1, 2, 3]);
+}
\ No newline at end of file
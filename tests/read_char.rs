@@ -0,0 +1,112 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read() {
+	let reader = "aé€🦀".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_char().unwrap(), 'a');
+	assert_eq!(buffer.read_char().unwrap(), 'é');
+	assert_eq!(buffer.read_char().unwrap(), '€');
+	assert_eq!(buffer.read_char().unwrap(), '🦀');
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn read_chunks() {
+	let bytes = "🦀".as_bytes();
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(bytes[..1].to_vec());
+	reader.add_chunk(bytes[1..2].to_vec());
+	reader.add_chunk(bytes[2..].to_vec());
+	reader.add_chunk(vec![b'!']);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_char().unwrap(), '🦀');
+	assert_eq!(buffer.read_char().unwrap(), '!');
+}
+
+#[test]
+fn invalid_start_byte() {
+	let reader = [0x80, b'a'].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(
+		result,
+		[0x80, b'a']
+	);
+}
+
+#[test]
+fn invalid_continuation_byte() {
+	let reader = [0xe2, 0x82, b'a'].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn invalid_byte_before_eof() {
+	let reader = [0xe2, b'a'].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	let reader = [0xc3, b'a'].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn invalid_byte_without_waiting() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![0xf0, b'a']);
+	reader.add_error(ErrorKind::WouldBlock.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn invalid_second_byte_range() {
+	for bytes in [[0xe0, 0x80, 0x80], [0xed, 0xa0, 0x80]] {
+		let reader = bytes.as_slice();
+		let mut buffer = DynReadBuffer::new(reader);
+		
+		let error = buffer.read_char().unwrap_err();
+		assert_eq!(error.kind(), ErrorKind::InvalidData);
+	}
+	
+	let reader = [0xf4, 0x90].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn unexpected_eof() {
+	let bytes = "€".as_bytes();
+	let reader = &bytes[..2];
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_char().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, &bytes[..2]);
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+use read_buffer::DynReadBuffer;
+
+fn read_large_frame(read_chunk_size: Option<usize>) -> u64 {
+	let mut data = vec![1; 1024 * 1024];
+	data.push(0);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	if let Some(read_chunk_size) = read_chunk_size {
+		buffer.set_read_chunk_size(read_chunk_size);
+		assert_eq!(buffer.read_chunk_size(), read_chunk_size);
+	}
+	
+	assert_eq!(buffer.read_until(0).unwrap().len(), 1024 * 1024 + 1);
+	buffer.read_calls()
+}
+
+#[test]
+fn default_size() {
+	let buffer = DynReadBuffer::new([].as_slice());
+	assert_eq!(buffer.read_chunk_size(), 8 * 1024);
+	
+	assert!(read_large_frame(None) <= 128);
+}
+
+#[test]
+fn larger_size() {
+	assert!(read_large_frame(Some(1024 * 1024)) <= 2);
+}
+
+#[test]
+fn smaller_size() {
+	assert!(read_large_frame(Some(1)) > read_large_frame(Some(64 * 1024)));
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_cstr() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"\0ab".to_vec());
+	reader.add_chunk(b"c\0de".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_cstr().unwrap(), c"");
+	assert_eq!(buffer.read_cstr().unwrap(), c"abc");
+	assert_eq!(buffer.read_cstr().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.read_bytes(2).unwrap(), b"de");
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read() {
+	let reader = [2, 7, 8, 0, 1, 9].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_delimited_message(16).unwrap();
+	assert_eq!(result, [7, 8]);
+	
+	let result = buffer.read_delimited_message(16).unwrap();
+	assert!(result.is_empty());
+	
+	let result = buffer.read_delimited_message(16).unwrap();
+	assert_eq!(result, [9]);
+	
+	assert_eq!(buffer.frames_returned(), 3);
+}
+
+#[test]
+fn multi_byte_length() {
+	let mut data = vec![0xac, 0x02]; // 300
+	data.extend_from_slice(&[5; 300]);
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(data[..1].to_vec());
+	reader.add_chunk(data[1..100].to_vec());
+	reader.add_chunk(data[100..].to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_delimited_message(300).unwrap();
+	assert_eq!(result, [5; 300]);
+}
+
+#[test]
+fn exceeds_max_size() {
+	let reader = [0xac, 0x02, 1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_delimited_message(299).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, [0xac, 0x02]);
+}
+
+#[test]
+fn invalid_varint() {
+	let reader = [0xff; 11].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_delimited_message(usize::MAX).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn unexpected_eof() {
+	let reader = [4, 1, 2].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_delimited_message(16).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [4, 1, 2]);
+}
\ No newline at end of file
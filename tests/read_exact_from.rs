@@ -0,0 +1,56 @@
+use std::io::ErrorKind;
+use read_buffer::ReadBuffer;
+use read_buffer::test_util::{ChunkedReader, ErrorReader};
+
+#[test]
+fn read_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(vec![3, 4, 5]);
+	let mut buffer: ReadBuffer<8> = ReadBuffer::new();
+	
+	let result = buffer.read_exact_from(&mut reader, 4).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	
+	let result = buffer.read_exact_from(&mut reader, 1).unwrap();
+	assert_eq!(result, [5]);
+}
+
+#[test]
+fn read_full_capacity() {
+	let mut reader = [1, 2, 3, 4].as_slice();
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let result = buffer.read_exact_from(&mut reader, 4).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	
+	let result = buffer.read_exact_from(&mut reader, 0).unwrap();
+	assert!(result.is_empty());
+}
+
+#[test]
+fn unexpected_eof() {
+	let mut reader = [1, 2, 3].as_slice();
+	let mut buffer: ReadBuffer<8> = ReadBuffer::new();
+	
+	let error = buffer.read_exact_from(&mut reader, 4).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn error() {
+	let mut buffer: ReadBuffer<8> = ReadBuffer::new();
+	
+	let error = buffer.read_exact_from(&mut ErrorReader, 4).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+#[should_panic]
+fn larger_than_capacity() {
+	let mut reader = [1, 2, 3, 4, 5].as_slice();
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let _ = buffer.read_exact_from(&mut reader, 5);
+}
\ No newline at end of file
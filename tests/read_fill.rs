@@ -0,0 +1,47 @@
+use std::io::ErrorKind;
+use read_buffer::ReadBuffer;
+use read_buffer::test_util::{ChunkedReader, ErrorReader};
+
+#[test]
+fn fill_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(vec![3, 4, 5]);
+	reader.add_chunk(vec![6]);
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let (result, eof) = buffer.read_fill(&mut reader).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	assert!(!eof);
+	
+	let (result, eof) = buffer.read_fill(&mut reader).unwrap();
+	assert_eq!(result, [5, 6]);
+	assert!(eof);
+	
+	let (result, eof) = buffer.read_fill(&mut reader).unwrap();
+	assert!(result.is_empty());
+	assert!(eof);
+}
+
+#[test]
+fn exactly_full() {
+	let mut reader = [1, 2, 3, 4].as_slice();
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let (result, eof) = buffer.read_fill(&mut reader).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	assert!(!eof);
+	
+	let (result, eof) = buffer.read_fill(&mut reader).unwrap();
+	assert!(result.is_empty());
+	assert!(eof);
+}
+
+#[test]
+fn error() {
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let error = buffer.read_fill(&mut ErrorReader).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
\ No newline at end of file
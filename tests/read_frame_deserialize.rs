@@ -0,0 +1,81 @@
+#![cfg(feature = "serde")]
+
+use std::io::{self, ErrorKind};
+
+use read_buffer::{DynReadBuffer, FrameFormat};
+use serde::de::DeserializeOwned;
+
+struct Utf8Lossy;
+
+impl FrameFormat for Utf8Lossy {
+	fn deserialize<T: DeserializeOwned>(&self, frame: &[u8]) -> Result<T, io::Error> {
+		let string = String::from_utf8_lossy(frame);
+		let value = serde::de::value::StrDeserializer::<serde::de::value::Error>::new(&string);
+		T::deserialize(value)
+			.map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+	}
+}
+
+#[test]
+fn custom_format() {
+	let reader = b"helloworld".as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: String = buffer.read_frame_deserialize(5, Utf8Lossy).unwrap();
+	assert_eq!(result, "hello");
+	
+	let result: String = buffer.read_frame_deserialize(5, Utf8Lossy).unwrap();
+	assert_eq!(result, "world");
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn json() {
+	use std::collections::HashMap;
+	use read_buffer::Json;
+	
+	let reader = br#"{"a":1,"b":2}[true]"#.as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: HashMap<String, u32> = buffer.read_frame_deserialize(13, Json).unwrap();
+	assert_eq!(result, HashMap::from([("a".into(), 1), ("b".into(), 2)]));
+	
+	let result: Vec<bool> = buffer.read_frame_deserialize(6, Json).unwrap();
+	assert_eq!(result, [true]);
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn invalid_frame_is_consumed() {
+	use read_buffer::Json;
+	
+	let reader = b"{oops}[]".as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_frame_deserialize::<Vec<u8>, _>(6, Json).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	let result: Vec<u8> = buffer.read_frame_deserialize(2, Json).unwrap();
+	assert!(result.is_empty());
+}
+
+#[test]
+#[cfg(feature = "postcard")]
+fn postcard() {
+	use read_buffer::Postcard;
+	
+	let reader = [3, 1, 2, 3, 0x2a].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: (Vec<u8>, u8) = buffer.read_frame_deserialize(5, Postcard).unwrap();
+	assert_eq!(result, (vec![1, 2, 3], 42));
+}
+
+#[test]
+fn unexpected_eof() {
+	let reader = b"abc".as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_frame_deserialize::<String, _>(4, Utf8Lossy).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
\ No newline at end of file
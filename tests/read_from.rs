@@ -1,8 +1,6 @@
-pub mod utils;
-
 use std::io::ErrorKind;
-use read_buffer::ReadBuffer;
-use crate::utils::ErrorReader;
+use read_buffer::{ReadBuffer, ReadOutcome, Align512};
+use read_buffer::test_util::ErrorReader;
 
 #[test]
 fn read() {
@@ -38,6 +36,7 @@ fn read_partial() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_literal_unwrap)]
 fn default_construction() {
 	let buffer: Option<ReadBuffer<16>> = None;
 	let mut buffer = buffer.unwrap_or_default();
@@ -60,7 +59,7 @@ fn out_of_bounds_access() {
 	let Ok(result) = buffer.read_from(&mut reader) else {
 		return; // don't panic so test will fail
 	};
-	result[4];
+	let _ = result[4];
 }
 
 #[test]
@@ -72,7 +71,7 @@ fn out_of_bounds_with_empty_data() {
 	let Ok(result) = buffer.read_from(&mut reader) else {
 		return; // don't panic so test will fail
 	};
-	result[0];
+	let _ = result[0];
 }
 
 #[test]
@@ -82,4 +81,59 @@ fn error_result() {
 	
 	let error = buffer.read_from(&mut reader).err().unwrap();
 	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn outcome() {
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	let mut reader = [1, 2, 3, 4, 5].as_slice();
+	
+	let result = buffer.read_from_outcome(&mut reader).unwrap();
+	assert_eq!(result, ReadOutcome::Data(&[1, 2, 3, 4]));
+	
+	let result = buffer.read_from_outcome(&mut reader).unwrap();
+	assert_eq!(result, ReadOutcome::Data(&[5]));
+	
+	let result = buffer.read_from_outcome(&mut reader).unwrap();
+	assert_eq!(result, ReadOutcome::Eof);
+}
+
+#[test]
+fn outcome_zero_capacity() {
+	let mut buffer: ReadBuffer<0> = ReadBuffer::new();
+	let mut reader = [].as_slice();
+	
+	let result = buffer.read_from_outcome(&mut reader).unwrap();
+	assert_eq!(result, ReadOutcome::Data(&[]));
+}
+
+#[test]
+fn outcome_error() {
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let error = buffer.read_from_outcome(&mut ErrorReader).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn read_mut() {
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	let mut reader = [1, 2, 3, 4, 5].as_slice();
+	
+	let result = buffer.read_from_mut(&mut reader).unwrap();
+	result.reverse();
+	assert_eq!(result, [4, 3, 2, 1]);
+	
+	let result = buffer.read_from_mut(&mut reader).unwrap();
+	assert_eq!(result, [5]);
+}
+
+#[test]
+fn read_aligned() {
+	let mut reader = [1, 2, 3, 4].as_slice();
+	let mut buffer: ReadBuffer<1024, Align512> = ReadBuffer::default();
+	
+	let result = buffer.read_from(&mut reader).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	assert_eq!(result.as_ptr().align_offset(512), 0);
 }
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::io::ErrorKind;
+use read_buffer::ReadBuffer;
+use read_buffer::test_util::ErrorReader;
+
+#[test]
+fn read_limited() {
+	let mut reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer: ReadBuffer<8> = ReadBuffer::new();
+	
+	let result = buffer.read_from_at_most(&mut reader, 4).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	
+	let result = buffer.read_from_at_most(&mut reader, 4).unwrap();
+	assert_eq!(result, [5, 6]);
+	
+	let result = buffer.read_from_at_most(&mut reader, 4).unwrap();
+	assert!(result.is_empty());
+}
+
+#[test]
+fn limit_larger_than_capacity() {
+	let mut reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let result = buffer.read_from_at_most(&mut reader, 100).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+}
+
+#[test]
+fn error() {
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let error = buffer.read_from_at_most(&mut ErrorReader, 2).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
\ No newline at end of file
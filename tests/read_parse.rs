@@ -0,0 +1,89 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, Incomplete};
+use read_buffer::test_util::ChunkedReader;
+
+fn parse_length_prefixed(input: &[u8]) -> Result<(usize, Vec<u8>), Incomplete> {
+	let (&length, rest) = input.split_first().ok_or(Incomplete)?;
+	let length = usize::from(length);
+	let data = rest.get(..length).ok_or(Incomplete)?;
+	Ok((length + 1, data.to_vec()))
+}
+
+#[test]
+fn parse() {
+	let reader = [2, 1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_parse(parse_length_prefixed).unwrap();
+	assert_eq!(result, [1, 2]);
+	
+	let result = buffer.read_parse(parse_length_prefixed).unwrap();
+	assert_eq!(result, [4, 5, 6]);
+}
+
+#[test]
+fn parse_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![5]);
+	reader.add_chunk(vec![1, 2]);
+	reader.add_chunk(vec![3, 4]);
+	reader.add_chunk(vec![5, 0]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut calls = 0;
+	let result = buffer.read_parse(|input| {
+		calls += 1;
+		parse_length_prefixed(input)
+	}).unwrap();
+	assert_eq!(result, [1, 2, 3, 4, 5]);
+	assert_eq!(calls, 5);
+	
+	let result = buffer.read_parse(parse_length_prefixed).unwrap();
+	assert!(result.is_empty());
+}
+
+#[test]
+fn consume_nothing() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_parse(|input| {
+		if input.len() < 2 {
+			return Err(Incomplete);
+		}
+		
+		Ok((0, input[1]))
+	}).unwrap();
+	assert_eq!(result, 2);
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [1, 2, 3]);
+}
+
+#[test]
+fn unexpected_eof() {
+	let reader = [4, 1, 2].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_parse(parse_length_prefixed).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = buffer.read_bytes(3).unwrap();
+	assert_eq!(result, [4, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn consume_too_much() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let _ = buffer.read_parse(|input| {
+		if input.is_empty() {
+			return Err(Incomplete);
+		}
+		
+		Ok((input.len() + 1, ()))
+	});
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, ReadBuffer};
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_single_bytes() {
+	let reader = [0x7f, 0xff].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_u8().unwrap(), 0x7f);
+	assert_eq!(buffer.read_i8().unwrap(), -1);
+}
+
+#[test]
+fn read_little_endian() {
+	let mut data = Vec::new();
+	data.extend_from_slice(&0x1234u16.to_le_bytes());
+	data.extend_from_slice(&(-2i32).to_le_bytes());
+	data.extend_from_slice(&0x0102_0304_0506_0708u64.to_le_bytes());
+	data.extend_from_slice(&1.5f32.to_le_bytes());
+	data.extend_from_slice(&(-0.25f64).to_le_bytes());
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	assert_eq!(buffer.read_u16_le().unwrap(), 0x1234);
+	assert_eq!(buffer.read_i32_le().unwrap(), -2);
+	assert_eq!(buffer.read_u64_le().unwrap(), 0x0102_0304_0506_0708);
+	assert_eq!(buffer.read_f32_le().unwrap(), 1.5);
+	assert_eq!(buffer.read_f64_le().unwrap(), -0.25);
+}
+
+#[test]
+fn read_big_endian() {
+	let mut data = Vec::new();
+	data.extend_from_slice(&(-300i16).to_be_bytes());
+	data.extend_from_slice(&0xdead_beefu32.to_be_bytes());
+	data.extend_from_slice(&(-5i64).to_be_bytes());
+	data.extend_from_slice(&u128::MAX.to_be_bytes());
+	data.extend_from_slice(&i128::MIN.to_be_bytes());
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	assert_eq!(buffer.read_i16_be().unwrap(), -300);
+	assert_eq!(buffer.read_u32_be().unwrap(), 0xdead_beef);
+	assert_eq!(buffer.read_i64_be().unwrap(), -5);
+	assert_eq!(buffer.read_u128_be().unwrap(), u128::MAX);
+	assert_eq!(buffer.read_i128_be().unwrap(), i128::MIN);
+}
+
+#[test]
+fn read_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![0x01]);
+	reader.add_chunk(vec![0x02, 0x03]);
+	reader.add_chunk(vec![0x04, 0x05]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_u32_be().unwrap(), 0x0102_0304);
+	assert_eq!(buffer.read_u8().unwrap(), 0x05);
+}
+
+#[test]
+fn unexpected_eof() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_u32_le().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	assert_eq!(buffer.read_u16_le().unwrap(), 0x0201);
+}
+
+#[test]
+fn fixed_read_primitives() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![0x01]);
+	reader.add_chunk(vec![0x02, 0x03, 0xff]);
+	reader.add_chunk(vec![0x04, 0x05]);
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	assert_eq!(buffer.read_u16_le(&mut reader).unwrap(), 0x0201);
+	assert_eq!(buffer.read_u8(&mut reader).unwrap(), 0x03);
+	assert_eq!(buffer.read_i8(&mut reader).unwrap(), -1);
+	assert_eq!(buffer.read_u16_be(&mut reader).unwrap(), 0x0405);
+	
+	let error = buffer.read_u32_le(&mut reader).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, TooLong};
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn within_limit() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(b"cd".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_to_end_limited(4).unwrap(), b"abcd");
+	assert_eq!(buffer.read_to_end_limited(4).unwrap(), b"");
+}
+
+#[test]
+fn too_long() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"abc".to_vec());
+	reader.add_chunk(b"defgh".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_to_end_limited(4).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	let too_long = error.get_ref().unwrap().downcast_ref::<TooLong>().unwrap();
+	assert_eq!(too_long.max_length(), 4);
+	
+	assert_eq!(buffer.available(), b"abcde");
+	assert_eq!(buffer.read_bytes(8).unwrap(), b"abcdefgh");
+}
+
+#[test]
+fn passes_on_errors() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_error(ErrorKind::NotFound.into());
+	reader.add_chunk(b"cd".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_to_end_limited(8).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+	assert_eq!(buffer.read_to_end_limited(8).unwrap(), b"abcd");
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn tokens() {
+	let reader = " \r\n first\tsecond  third\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_token().unwrap(), b"first");
+	assert_eq!(buffer.read_token().unwrap(), b"second");
+	assert_eq!(buffer.read_token().unwrap(), b"third");
+	assert_eq!(buffer.read_token().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn token_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"  ".to_vec());
+	reader.add_chunk(b" to".to_vec());
+	reader.add_chunk(b"ken".to_vec());
+	reader.add_chunk(b"s rest".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_token().unwrap(), b"tokens");
+	assert_eq!(buffer.read_bytes(5).unwrap(), b" rest");
+}
+
+#[test]
+fn error_preserves_token() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b" ab".to_vec());
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	reader.add_chunk(b"c".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_token().unwrap_err().kind(), ErrorKind::ConnectionReset);
+	assert_eq!(buffer.read_token().unwrap(), b"abc");
+}
\ No newline at end of file
@@ -1,9 +1,7 @@
 use std::io::ErrorKind;
 
-use read_buffer::DynReadBuffer;
-use utils::ChunkedReader;
-
-pub mod utils;
+use read_buffer::{DynReadBuffer, ReadBuffer, BufferFull};
+use read_buffer::test_util::ChunkedReader;
 
 #[test]
 fn read_all() {
@@ -69,7 +67,7 @@ fn read_chunks() {
 }
 
 fn generate_sequence(length: usize, start: usize)-> Vec<u8> {
-	(start..start + length).into_iter()
+	(start..start + length)
 		.map(|i| i % 255 + 1) // 1 <= x <= 255
 		.map(|i| -> u8 {i.try_into().unwrap()})
 		.collect()
@@ -234,4 +232,66 @@ fn read_chunked_bytes_after_delimiter() {
 		result,
 		[4, 5, 6, 7, 8, 9, 10]
 	);
+}
+
+#[test]
+fn fixed_read_lines() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab\nc".to_vec());
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(b"d\ne\n".to_vec());
+	let mut buffer: ReadBuffer<8> = ReadBuffer::new();
+	
+	let result = buffer.read_until(&mut reader, b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+	
+	let result = buffer.read_until(&mut reader, b'\n').unwrap();
+	assert_eq!(result, b"cd\n");
+	
+	let result = buffer.read_until(&mut reader, b'\n').unwrap();
+	assert_eq!(result, b"e\n");
+	
+	let error = buffer.read_until(&mut reader, b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn fixed_buffer_full() {
+	let mut reader = "abcdef\ngh".as_bytes();
+	let mut buffer: ReadBuffer<4> = ReadBuffer::new();
+	
+	let error = buffer.read_until(&mut reader, b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	let buffer_full = error.get_ref()
+		.and_then(|err| err.downcast_ref::<BufferFull>())
+		.unwrap();
+	assert_eq!(buffer_full.capacity(), 4);
+	
+	let result = buffer.read_from(&mut reader).unwrap();
+	assert_eq!(result, b"abcd");
+	
+	let result = buffer.read_until(&mut reader, b'\n').unwrap();
+	assert_eq!(result, b"ef\n");
+	
+	let result = buffer.read_from(&mut reader).unwrap();
+	assert_eq!(result, b"g");
+	
+	let result = buffer.read_from(&mut reader).unwrap();
+	assert_eq!(result, b"h");
+}
+
+#[test]
+fn fixed_data_after_error() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_error(ErrorKind::NotFound.into());
+	reader.add_chunk(b"c\n".to_vec());
+	let mut buffer: ReadBuffer<8> = ReadBuffer::new();
+	
+	let error = buffer.read_until(&mut reader, b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+	
+	let result = buffer.read_until(&mut reader, b'\n').unwrap();
+	assert_eq!(result, b"abc\n");
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, LineTooLong};
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn within_limit() {
+	let reader = "abc\nd\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_until_limited(b'\n', 4).unwrap(), b"abc\n");
+	assert_eq!(buffer.read_until_limited(b'\n', 4).unwrap(), b"d\n");
+	assert_eq!(buffer.read_until_limited(b'\n', 4).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn too_long() {
+	let reader = "abcd\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_until_limited(b'\n', 4).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	let too_long = error.get_ref().unwrap().downcast_ref::<LineTooLong>().unwrap();
+	assert_eq!(too_long.max_length(), 4);
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"abcd\n");
+}
+
+#[test]
+fn reads_at_most_limit() {
+	let line = vec![b'a'; 10_000];
+	let mut buffer = DynReadBuffer::with_capacity(line.as_slice(), 16);
+	
+	let error = buffer.read_until_limited(b'\n', 100).unwrap_err();
+	
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	assert_eq!(buffer.total_bytes_read(), 100);
+}
+
+#[test]
+fn skip_until() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"first".to_vec());
+	reader.add_chunk(b" line".to_vec());
+	reader.add_chunk(b"\nsecond\nrest".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.skip_until(b'\n').unwrap(), 11);
+	assert_eq!(buffer.skip_until(b'\n').unwrap(), 7);
+	assert_eq!(buffer.skip_until(b'\n').unwrap_err().kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.read_bytes(1).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
\ No newline at end of file
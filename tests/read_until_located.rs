@@ -0,0 +1,45 @@
+use read_buffer::DynReadBuffer;
+
+#[test]
+fn locations() {
+	let reader = "a\nb;c;\n\nd;".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let first = buffer.read_until_located(b';').unwrap();
+	assert_eq!((first.data, first.offset, first.line), (b"a\nb;".as_slice(), 0, 1));
+	
+	let second = buffer.read_until_located(b';').unwrap();
+	assert_eq!((second.data, second.offset, second.line), (b"c;".as_slice(), 4, 2));
+	
+	let third = buffer.read_until_located(b';').unwrap();
+	assert_eq!((third.data, third.offset, third.line), (b"\n\nd;".as_slice(), 6, 2));
+}
+
+#[test]
+fn track_lines_before_other_reads() {
+	let reader = "x\ny\nz\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.track_lines();
+	buffer.read_until(b'\n').unwrap();
+	buffer.read_bytes(2).unwrap();
+	
+	let located = buffer.read_until_located(b'\n').unwrap();
+	assert_eq!((located.offset, located.line), (4, 3));
+}
+
+#[test]
+fn lines_after_rollback() {
+	let reader = "1\n2\n3\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.track_lines();
+	let checkpoint = buffer.checkpoint();
+	buffer.read_bytes(4).unwrap();
+	buffer.restore(checkpoint).unwrap();
+	
+	let located = buffer.read_until_located(b'\n').unwrap();
+	assert_eq!((located.offset, located.line), (0, 1));
+	let located = buffer.read_until_located(b'\n').unwrap();
+	assert_eq!((located.offset, located.line), (2, 2));
+}
\ No newline at end of file
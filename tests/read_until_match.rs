@@ -0,0 +1,42 @@
+#![cfg(feature = "regex")]
+
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use regex_automata::dfa::dense::DFA;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_until_match() {
+	let reader = "a\r\nb\nc\r".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	let delimiter = DFA::new(r"\r?\n").unwrap();
+	
+	assert_eq!(buffer.read_until_match(&delimiter).unwrap(), b"a\r\n");
+	assert_eq!(buffer.read_until_match(&delimiter).unwrap(), b"b\n");
+	assert_eq!(buffer.read_until_match(&delimiter).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(buffer.read_bytes(2).unwrap(), b"c\r");
+}
+
+#[test]
+fn match_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"data</".to_vec());
+	reader.add_chunk(b"bo".to_vec());
+	reader.add_chunk(b"dy>rest".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	let delimiter = DFA::new(r"</[a-z]+>").unwrap();
+	
+	assert_eq!(buffer.read_until_match(&delimiter).unwrap(), b"data</body>");
+	assert_eq!(buffer.read_bytes(4).unwrap(), b"rest");
+}
+
+#[test]
+fn match_at_end_of_file() {
+	let reader = "value;;".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	let delimiter = DFA::new(r";+").unwrap();
+	
+	assert_eq!(buffer.read_until_match(&delimiter).unwrap(), b"value;");
+	assert_eq!(buffer.read_until_match(&delimiter).unwrap(), b";");
+}
\ No newline at end of file
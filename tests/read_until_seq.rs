@@ -0,0 +1,53 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_until_seq() {
+	let reader = b"a--b---c--".as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_until_seq(b"--").unwrap(), b"a--");
+	assert_eq!(buffer.read_until_seq(b"--").unwrap(), b"b--");
+	assert_eq!(buffer.read_until_seq(b"--").unwrap(), b"-c--");
+	assert_eq!(buffer.read_until_seq(b"--").unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn match_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"xxab".to_vec());
+	reader.add_chunk(b"a".to_vec());
+	reader.add_chunk(b"babcy".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_until_seq(b"ababc").unwrap(), b"xxabababc");
+	assert_eq!(buffer.read_bytes(1).unwrap(), b"y");
+}
+
+#[test]
+fn overlapping_prefix() {
+	let reader = b"aaaab".as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_until_seq(b"aab").unwrap(), b"aaaab");
+}
+
+#[test]
+fn empty_delimiter() {
+	let reader = b"abc".as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert!(buffer.read_until_seq(b"").unwrap().is_empty());
+	assert_eq!(buffer.read_bytes(3).unwrap(), b"abc");
+}
+
+#[test]
+fn read_until_str() {
+	let reader = "key: value\nnext".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_until_str(": ").unwrap(), b"key: ");
+	assert_eq!(buffer.read_until_str("\n").unwrap(), b"value\n");
+}
\ No newline at end of file
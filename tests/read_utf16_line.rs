@@ -0,0 +1,85 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, Endianness};
+use read_buffer::test_util::ChunkedReader;
+
+fn encode(text: &str, endianness: Endianness) -> Vec<u8> {
+	text.encode_utf16()
+		.flat_map(|unit| match endianness {
+			Endianness::Little => unit.to_le_bytes(),
+			Endianness::Big => unit.to_be_bytes(),
+		})
+		.collect()
+}
+
+#[test]
+fn read_little_endian() {
+	let data = encode("first line\nsecond line\n", Endianness::Little);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	let result = buffer.read_utf16_line(Endianness::Little).unwrap();
+	assert_eq!(result, "first line\n");
+	
+	let result = buffer.read_utf16_line(Endianness::Little).unwrap();
+	assert_eq!(result, "second line\n");
+}
+
+#[test]
+fn read_big_endian() {
+	let data = encode("äöü\n", Endianness::Big);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	let result = buffer.read_utf16_line(Endianness::Big).unwrap();
+	assert_eq!(result, "äöü\n");
+}
+
+#[test]
+fn read_chunks_split_code_units() {
+	let data = encode("🦀 crab\nrest", Endianness::Little);
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(data[..1].to_vec());
+	reader.add_chunk(data[1..3].to_vec());
+	reader.add_chunk(data[3..8].to_vec());
+	reader.add_chunk(data[8..].to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_utf16_line(Endianness::Little).unwrap();
+	assert_eq!(result, "🦀 crab\n");
+	
+	let result = buffer.read_bytes(8).unwrap();
+	assert_eq!(result, encode("rest", Endianness::Little));
+}
+
+#[test]
+fn line_feed_in_other_byte() {
+	// U+0A00 contains a 0x0a byte but isn't a line feed
+	let data = encode("\u{0a00}\n", Endianness::Big);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	let result = buffer.read_utf16_line(Endianness::Big).unwrap();
+	assert_eq!(result, "\u{0a00}\n");
+}
+
+#[test]
+fn unpaired_surrogate() {
+	let data = [0x00, 0xd8, b'\n', 0x00];
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	let error = buffer.read_utf16_line(Endianness::Little).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	let result = buffer.read_bytes(4).unwrap();
+	assert_eq!(result, data);
+}
+
+#[test]
+fn unexpected_eof() {
+	let data = encode("no line feed", Endianness::Little);
+	let mut buffer = DynReadBuffer::new(data.as_slice());
+	
+	let error = buffer.read_utf16_line(Endianness::Little).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = buffer.read_bytes(data.len()).unwrap();
+	assert_eq!(result, data);
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read() {
+	let reader = "Hello, World!".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "Hello, World!");
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "");
+}
+
+#[test]
+fn split_code_point() {
+	let bytes = "aäb€c".as_bytes();
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(bytes[..2].to_vec()); // 'a' and first half of 'ä'
+	reader.add_chunk(bytes[2..5].to_vec()); // second half of 'ä', 'b' and first byte of '€'
+	reader.add_chunk(bytes[5..].to_vec()); // rest of '€' and 'c'
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "a");
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "äb");
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "€c");
+}
+
+#[test]
+fn only_partial_code_point() {
+	let bytes = "🦀".as_bytes();
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(bytes[..1].to_vec());
+	reader.add_chunk(bytes[1..3].to_vec());
+	reader.add_chunk(bytes[3..].to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "🦀");
+}
+
+#[test]
+fn invalid_data() {
+	let reader = [b'a', b'b', 0xff, b'c'].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "ab");
+	
+	let error = buffer.read_valid_utf8().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(
+		result,
+		[0xff, b'c']
+	);
+}
+
+#[test]
+fn unexpected_eof() {
+	let bytes = "xä".as_bytes();
+	let reader = &bytes[..2];
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "x");
+	
+	let error = buffer.read_valid_utf8().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = buffer.read_bytes(1).unwrap();
+	assert_eq!(
+		result,
+		[bytes[1]]
+	);
+}
+
+#[test]
+fn buffered_data_without_reading() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![b'a', b'b', b'\n', b'c', b'd']);
+	reader.add_error(ErrorKind::NotFound.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+	
+	let result = buffer.read_valid_utf8().unwrap();
+	assert_eq!(result, "cd");
+	
+	let error = buffer.read_valid_utf8().unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
\ No newline at end of file
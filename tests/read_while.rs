@@ -1,8 +1,6 @@
-pub mod utils;
-
 use std::io::ErrorKind;
-use read_buffer::ReadBuffer;
-use crate::utils::{ChunkedReader, ErrorReader};
+use read_buffer::{ReadBuffer, DynReadBuffer};
+use read_buffer::test_util::{ChunkedReader, ErrorReader};
 
 #[test]
 fn read() {
@@ -153,4 +151,66 @@ fn error_result() {
 	
 	let error = buffer.read_while(&mut reader, |_chunk| true).err().unwrap();
 	assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn dyn_read_chunks_conditional() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(vec![3, 0]);
+	reader.add_chunk(vec![4, 5]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_while(None, |chunk| !chunk.contains(&0)).unwrap();
+	assert_eq!(result, [1, 2, 3, 0]);
+	
+	let result = buffer.read_while(None, |_chunk| true).unwrap();
+	assert_eq!(result, [4, 5]);
+	
+	let result = buffer.read_while(None, |_chunk| true).unwrap();
+	assert!(result.is_empty());
+}
+
+#[test]
+fn dyn_read_max_size() {
+	let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_while(Some(4), |_chunk| true).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, [5, 6]);
+}
+
+#[test]
+fn dyn_includes_buffered_data() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 0, 3, 4]);
+	reader.add_chunk(vec![5, 6]);
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_until(0).unwrap();
+	assert_eq!(result, [1, 2, 0]);
+	
+	let result = buffer.read_while(Some(3), |_chunk| true).unwrap();
+	assert_eq!(result, [3, 4, 5]);
+	
+	let result = buffer.read_while(Some(1), |_chunk| true).unwrap();
+	assert_eq!(result, [6]);
+}
+
+#[test]
+fn dyn_error_result() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2]);
+	reader.add_error(ErrorKind::NotFound.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_while(None, |_chunk| true).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::NotFound);
+	
+	let result = buffer.read_while(None, |_chunk| true).unwrap();
+	assert_eq!(result, [1, 2]);
 }
\ No newline at end of file
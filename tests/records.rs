@@ -0,0 +1,61 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+fn collect_records(data: &[u8]) -> Vec<Vec<u8>> {
+	let mut buffer = DynReadBuffer::new(data);
+	let mut records = buffer.records(b'\n');
+	let mut collected = Vec::new();
+	
+	while let Some(record) = records.next_record() {
+		collected.push(record.unwrap().to_vec());
+	}
+	
+	collected
+}
+
+#[test]
+fn terminated_records() {
+	assert_eq!(collect_records(b"a\nbc\n\n"), [b"a\n".to_vec(), b"bc\n".to_vec(), b"\n".to_vec()]);
+}
+
+#[test]
+fn unterminated_last_record() {
+	assert_eq!(collect_records(b"a\nbc"), [b"a\n".to_vec(), b"bc".to_vec()]);
+}
+
+#[test]
+fn no_records() {
+	assert!(collect_records(b"").is_empty());
+}
+
+#[test]
+fn error_continues_record() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	reader.add_chunk(b"c\nd".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut records = buffer.records(b'\n');
+	
+	assert_eq!(records.next_record().unwrap().unwrap_err().kind(), ErrorKind::ConnectionReset);
+	assert_eq!(records.next_record().unwrap().unwrap(), b"abc\n");
+	assert_eq!(records.next_record().unwrap().unwrap(), b"d");
+	assert!(records.next_record().is_none());
+}
+
+#[test]
+fn into_frames() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab\nc".to_vec());
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	reader.add_chunk(b"d\ne".to_vec());
+	let mut frames = DynReadBuffer::new(reader).into_frames(b'\n');
+	
+	assert_eq!(frames.next().unwrap().unwrap(), b"ab\n");
+	assert_eq!(frames.next().unwrap().unwrap_err().kind(), ErrorKind::ConnectionReset);
+	
+	let rest: Vec<_> = frames.map(Result::unwrap).collect();
+	assert_eq!(rest, [b"cd\n".to_vec(), b"e".to_vec()]);
+}
\ No newline at end of file
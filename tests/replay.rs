@@ -0,0 +1,74 @@
+use std::io::{ErrorKind, Read};
+
+use read_buffer::{DynReadBuffer, RecordingReader, ReplayReader};
+use read_buffer::test_util::ChunkedReader;
+
+fn record() -> Vec<u8> {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(b"c\nde".to_vec());
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	
+	let mut recording = Vec::new();
+	let mut buffer = DynReadBuffer::new(RecordingReader::new(reader, &mut recording));
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"abc\n");
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::ConnectionReset);
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	drop(buffer);
+	recording
+}
+
+#[test]
+fn replay_chunks_and_errors() {
+	let recording = record();
+	let mut reader = ReplayReader::new(recording.as_slice());
+	let mut buf = [0; 3];
+	
+	assert_eq!(reader.read(&mut buf).unwrap(), 2);
+	assert_eq!(buf[..2], *b"ab");
+	
+	assert_eq!(reader.read(&mut buf).unwrap_err().kind(), ErrorKind::Interrupted);
+	
+	assert_eq!(reader.read(&mut buf).unwrap(), 3);
+	assert_eq!(buf, *b"c\nd");
+	
+	assert_eq!(reader.read(&mut buf).unwrap(), 1);
+	assert_eq!(buf[..1], *b"e");
+	
+	assert_eq!(reader.read(&mut buf).unwrap_err().kind(), ErrorKind::ConnectionReset);
+	
+	assert_eq!(reader.read(&mut buf).unwrap(), 0);
+	assert_eq!(reader.read(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn replay_through_buffer() {
+	let recording = record();
+	let mut buffer = DynReadBuffer::new(ReplayReader::new(recording.as_slice()));
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"abc\n");
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::ConnectionReset);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, b"de");
+}
+
+#[test]
+fn truncated_recording() {
+	let recording = record();
+	let mut reader = ReplayReader::new(&recording[..4]);
+	
+	let error = reader.read(&mut [0; 8]).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+#![cfg(all(feature = "secret", any(unix, windows)))]
+
+use read_buffer::SecretReadBuffer;
+
+#[test]
+fn read_secret() {
+	let reader = "user\nhunter2\n".as_bytes();
+	let mut buffer = SecretReadBuffer::new(reader).unwrap();
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"user\n");
+	assert_eq!(buffer.read_bytes(7).unwrap(), b"hunter2");
+}
+
+#[test]
+fn grow_secret() {
+	let reader = [7; 20000];
+	let mut buffer = SecretReadBuffer::with_capacity(reader.as_slice(), 16).unwrap();
+	
+	assert_eq!(buffer.read_bytes(20000).unwrap(), [7; 20000]);
+}
+
+#[test]
+fn debug_is_redacted() {
+	let reader = "hunter2\n".as_bytes();
+	let mut buffer = SecretReadBuffer::new(reader).unwrap();
+	
+	assert_eq!(buffer.lookahead(7).unwrap(), b"hunter2");
+	
+	let debug = format!("{buffer:?}");
+	assert!(debug.starts_with("SecretReadBuffer(DynReadBuffer {"));
+	assert!(!debug.contains("104, 117"));
+}
\ No newline at end of file
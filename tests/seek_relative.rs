@@ -0,0 +1,73 @@
+use std::io::{self, Cursor, ErrorKind, Read, Seek, SeekFrom};
+
+use read_buffer::DynReadBuffer;
+
+/// Counts how often it is seeked.
+struct CountingSeeker {
+	inner: Cursor<Vec<u8>>,
+	seeks: usize,
+}
+
+impl Read for CountingSeeker {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.inner.read(buf)
+	}
+}
+
+impl Seek for CountingSeeker {
+	fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+		self.seeks += 1;
+		self.inner.seek(position)
+	}
+}
+
+fn counting_seeker(data: &[u8]) -> CountingSeeker {
+	CountingSeeker {
+		inner: Cursor::new(data.to_vec()),
+		seeks: 0,
+	}
+}
+
+#[test]
+fn seek_within_buffer() {
+	let data: Vec<u8> = (0..20).collect();
+	let mut reader = counting_seeker(&data);
+	let mut buffer = DynReadBuffer::new(&mut reader);
+	
+	assert_eq!(buffer.read_bytes(8).unwrap(), [0, 1, 2, 3, 4, 5, 6, 7]);
+	buffer.seek_relative(-5).unwrap();
+	assert_eq!(buffer.read_bytes(3).unwrap(), [3, 4, 5]);
+	buffer.seek_relative(1).unwrap();
+	assert_eq!(buffer.read_bytes(1).unwrap(), [7]);
+	buffer.seek_relative(-8).unwrap();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [0, 1]);
+	drop(buffer);
+	
+	assert_eq!(reader.seeks, 0);
+}
+
+#[test]
+fn seek_past_buffer() {
+	let data: Vec<u8> = (0..100).collect();
+	let mut reader = counting_seeker(&data);
+	let mut buffer = DynReadBuffer::with_capacity(&mut reader, 16);
+	
+	assert_eq!(buffer.read_bytes(4).unwrap(), [0, 1, 2, 3]);
+	buffer.seek_relative(50).unwrap();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [54, 55]);
+	buffer.seek_relative(-30).unwrap();
+	assert_eq!(buffer.read_bytes(2).unwrap(), [26, 27]);
+	drop(buffer);
+	
+	assert_eq!(reader.seeks, 2);
+}
+
+#[test]
+fn seek_before_start() {
+	let mut buffer = DynReadBuffer::new(Cursor::new(vec![1, 2, 3]));
+	
+	assert_eq!(buffer.read_bytes(1).unwrap(), [1]);
+	let error = buffer.seek_relative(-10).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidInput);
+	assert_eq!(buffer.read_bytes(2).unwrap(), [2, 3]);
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use read_buffer::{DynReadBuffer, ShrinkPolicy};
+
+fn capacity<R: std::io::Read>(buffer: &DynReadBuffer<R>) -> usize {
+	let debug = format!("{buffer:?}");
+	let capacity = debug.split("capacity: ").nth(1).unwrap();
+	capacity.split(',').next().unwrap().parse().unwrap()
+}
+
+#[test]
+fn shrink_to() {
+	let reader: Vec<u8> = (0..=255).collect();
+	let mut buffer = DynReadBuffer::new(reader.as_slice());
+	
+	assert_eq!(buffer.read_bytes(200).unwrap().len(), 200);
+	buffer.lookahead(20).unwrap();
+	assert!(capacity(&buffer) >= 200);
+	
+	buffer.shrink_to(8);
+	assert!(capacity(&buffer) < 200);
+	assert!(capacity(&buffer) >= 20);
+	assert_eq!(buffer.read_bytes(20).unwrap(), &reader[200..220]);
+}
+
+#[test]
+fn shrinks_after_idle_frames() {
+	let reader = [3; 4096].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	buffer.set_shrink_policy(Some(ShrinkPolicy {
+		high: 1024,
+		low: 16,
+		operations: 3,
+	}));
+	
+	assert_eq!(buffer.read_bytes(2048).unwrap(), [3; 2048]);
+	assert!(capacity(&buffer) >= 2048);
+	
+	for _ in 0..2 {
+		assert_eq!(buffer.read_bytes(4).unwrap(), [3; 4]);
+		assert!(capacity(&buffer) >= 2048);
+	}
+	
+	assert_eq!(buffer.read_bytes(4).unwrap(), [3; 4]);
+	assert_eq!(capacity(&buffer), 16);
+}
+
+#[test]
+fn large_frames_prevent_shrinking() {
+	let reader = [3; 4096].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	buffer.set_shrink_policy(Some(ShrinkPolicy {
+		high: 1024,
+		low: 16,
+		operations: 2,
+	}));
+	
+	assert_eq!(buffer.read_bytes(2048).unwrap(), [3; 2048]);
+	assert_eq!(buffer.read_bytes(4).unwrap(), [3; 4]);
+	assert_eq!(buffer.read_bytes(100).unwrap(), [3; 100]);
+	assert_eq!(buffer.read_bytes(4).unwrap(), [3; 4]);
+	assert!(capacity(&buffer) >= 2048);
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn fill_externally() {
+	let reader = "ef\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.reserve(6);
+	let spare = buffer.spare_capacity_mut();
+	assert!(spare.len() >= 6);
+	spare[..5].copy_from_slice(b"ab\ncd");
+	// SAFETY: 5 bytes were written to the spare capacity
+	unsafe {
+		buffer.assume_filled(5);
+	}
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"cdef\n");
+	
+	let error = buffer.read_bytes(1).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[should_panic]
+fn assume_too_much() {
+	let reader = [].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.reserve(4);
+	let length = buffer.spare_capacity_mut().len();
+	// SAFETY: this panics before anything is marked as filled
+	unsafe {
+		buffer.assume_filled(length + 1);
+	}
+}
+
+#[test]
+fn pending_fill() {
+	let reader = "ef\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let fill = buffer.begin_fill(6);
+	assert!(fill.len() >= 6);
+	// SAFETY: the pointer is valid for fill.len() bytes
+	unsafe {
+		fill.as_mut_ptr().copy_from_nonoverlapping(b"ab\ncd".as_ptr(), 5);
+		fill.assume_filled(5);
+	}
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"cdef\n");
+}
+
+#[test]
+fn pending_fill_dropped() {
+	let reader = "ab\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	{
+		let fill = buffer.begin_fill(4);
+		// SAFETY: the pointer is valid for fill.len() bytes
+		unsafe {
+			fill.as_mut_ptr().write_bytes(b'x', 4);
+		}
+	}
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+}
+
+#[test]
+fn consumed_data_not_exposed() {
+	let mut reader = ChunkedReader::new();
+	
+	for chunk in b"user=admin\npassword=hunter2\nABCDEFGH".chunks(6) {
+		reader.add_chunk(chunk.to_vec());
+	}
+	
+	let mut buffer = DynReadBuffer::with_capacity(reader, 24);
+	buffer.set_read_chunk_size(6);
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"user=admin\n");
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"password=hunter2\n");
+	buffer.reserve(16);
+	
+	let spare = buffer.spare_capacity_mut();
+	assert!(spare.len() >= 16);
+	assert!(spare.iter().all(|&byte| byte == 0));
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn spawn_frames() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"a\nb".to_vec());
+	reader.add_chunk(b"c\n\nd".to_vec());
+	let buffer = DynReadBuffer::new(reader);
+	
+	let frames: Vec<_> = buffer.spawn_frames(b'\n')
+		.into_iter()
+		.map(|frame| frame.unwrap())
+		.collect();
+	
+	assert_eq!(frames, [b"a\n".to_vec(), b"bc\n".to_vec(), b"\n".to_vec(), b"d".to_vec()]);
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn spawn_frames_error() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ok\npartial".to_vec());
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	reader.add_chunk(b"\nnever\n".to_vec());
+	let buffer = DynReadBuffer::new(reader);
+	
+	let receiver = buffer.spawn_frames(b'\n');
+	
+	assert_eq!(receiver.recv().unwrap().unwrap(), b"ok\n");
+	assert_eq!(receiver.recv().unwrap().unwrap_err().kind(), ErrorKind::ConnectionReset);
+	assert!(receiver.recv().is_err());
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn many_frames() {
+	let data = "line\n".repeat(1000);
+	let buffer = DynReadBuffer::new(std::io::Cursor::new(data.into_bytes()));
+	
+	assert_eq!(buffer.spawn_frames(b'\n').into_iter().count(), 1000);
+}
\ No newline at end of file
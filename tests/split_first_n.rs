@@ -0,0 +1,28 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+
+#[test]
+fn header_then_body() {
+	let reader = [2, b'h', b'i', 1, b'!'].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let (length, body) = buffer.split_first_n(1, |header| header[0]).unwrap();
+	assert_eq!(body.read_bytes(length.into()).unwrap(), b"hi");
+	
+	let (length, body) = buffer.split_first_n(1, |header| header[0]).unwrap();
+	assert_eq!(body.read_bytes(length.into()).unwrap(), b"!");
+}
+
+#[test]
+fn incomplete_header() {
+	let reader = [1, 2].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut parsed = false;
+	
+	let result = buffer.split_first_n(4, |_| parsed = true);
+	
+	assert!(matches!(result, Err(err) if err.kind() == ErrorKind::UnexpectedEof));
+	assert!(!parsed);
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+}
\ No newline at end of file
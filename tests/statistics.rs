@@ -0,0 +1,48 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, ReadBuffer};
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn dyn_read_buffer_statistics() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab\ncd".to_vec());
+	reader.add_error(ErrorKind::Interrupted.into());
+	reader.add_chunk(b"e\nf".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.total_bytes_read(), 0);
+	assert_eq!(buffer.read_calls(), 0);
+	assert_eq!(buffer.frames_returned(), 0);
+	
+	buffer.read_until(b'\n').unwrap();
+	buffer.read_until(b'\n').unwrap();
+	buffer.read_char().unwrap();
+	
+	assert_eq!(buffer.total_bytes_read(), 8);
+	assert_eq!(buffer.read_calls(), 3);
+	assert_eq!(buffer.frames_returned(), 3);
+	
+	let error = buffer.read_bytes(1).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	assert_eq!(buffer.total_bytes_read(), 8);
+	assert_eq!(buffer.read_calls(), 4);
+	assert_eq!(buffer.frames_returned(), 3);
+}
+
+#[test]
+fn read_buffer_statistics() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 3]);
+	reader.add_chunk(vec![4, 5]);
+	reader.add_chunk(vec![6]);
+	let mut buffer: ReadBuffer<8> = ReadBuffer::new();
+	
+	buffer.read_from(&mut reader).unwrap();
+	buffer.read_while(&mut reader, |_| true).unwrap();
+	
+	assert_eq!(buffer.total_bytes_read(), 6);
+	assert_eq!(buffer.read_calls(), 4);
+	assert_eq!(buffer.frames_returned(), 2);
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::thread;
+
+use read_buffer::{DynReadBuffer, SyncDynReadBuffer};
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires threads")]
+fn shared_between_threads() {
+	let data: Vec<u8> = (20..120u8).flat_map(|i| [i, b'\n']).collect();
+	let buffer = Arc::new(SyncDynReadBuffer::new(data.as_slice()));
+	
+	let mut lines: Vec<Vec<u8>> = thread::scope(|scope| {
+		let handles: Vec<_> = (0..4).map(|_| {
+			let buffer = Arc::clone(&buffer);
+			scope.spawn(move || {
+				(0..25).map(|_| buffer.read_until(b'\n').unwrap()).collect::<Vec<_>>()
+			})
+		}).collect();
+		
+		handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+	});
+	
+	lines.sort();
+	let expected: Vec<Vec<u8>> = (20..120u8).map(|i| vec![i, b'\n']).collect();
+	assert_eq!(lines, expected);
+}
+
+#[test]
+fn lock_for_several_reads() {
+	let reader = [1, 2, 3, 4, 5].as_slice();
+	let buffer = SyncDynReadBuffer::from(DynReadBuffer::new(reader));
+	
+	{
+		let mut guard = buffer.lock();
+		assert_eq!(guard.read_bytes(1).unwrap(), [1]);
+		assert_eq!(guard.read_bytes(2).unwrap(), [2, 3]);
+	}
+	
+	assert_eq!(buffer.read_array::<1>().unwrap(), [4]);
+	
+	let error = buffer.read_bytes(2).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let mut buffer = buffer.into_inner();
+	assert_eq!(buffer.read_bytes(1).unwrap(), [5]);
+}
\ No newline at end of file
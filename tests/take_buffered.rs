@@ -0,0 +1,27 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn take_buffered_after_error() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(vec![1, 2, 3]);
+	reader.add_error(ErrorKind::ConnectionReset.into());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+	assert_eq!(buffer.read_bytes(4).unwrap_err().kind(), ErrorKind::ConnectionReset);
+	
+	assert_eq!(buffer.take_buffered(), [3]);
+	assert!(buffer.available().is_empty());
+}
+
+#[test]
+fn take_buffered_empty() {
+	let reader = [1, 2].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	assert!(buffer.take_buffered().is_empty());
+	assert_eq!(buffer.read_bytes(2).unwrap(), [1, 2]);
+}
\ No newline at end of file
@@ -0,0 +1,95 @@
+use std::io::ErrorKind;
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+#[test]
+fn read_bytes_within_scope() {
+	let reader = [1, 2, 3, 4, 5].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut scope = buffer.take_scope(3);
+	
+	let result = scope.read_bytes(2).unwrap();
+	assert_eq!(result, [1, 2]);
+	assert_eq!(scope.remaining(), 1);
+	
+	let error = scope.read_bytes(2).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = scope.read_bytes(1).unwrap();
+	assert_eq!(result, [3]);
+	assert_eq!(scope.remaining(), 0);
+	
+	let result = buffer.read_bytes(2).unwrap();
+	assert_eq!(result, [4, 5]);
+}
+
+#[test]
+fn read_until_across_chunks() {
+	let mut reader = ChunkedReader::new();
+	reader.add_chunk(b"ab".to_vec());
+	reader.add_chunk(b"c\nd".to_vec());
+	reader.add_chunk(b"e\nf\n".to_vec());
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut scope = buffer.take_scope(6);
+	
+	let result = scope.read_until(b'\n').unwrap();
+	assert_eq!(result, b"abc\n");
+	
+	let error = scope.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = scope.read_bytes(2).unwrap();
+	assert_eq!(result, b"de");
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"\n");
+}
+
+#[test]
+fn delimiter_past_scope() {
+	let reader = "abc\ndef\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut scope = buffer.take_scope(3);
+	
+	let error = scope.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = scope.read_bytes(3).unwrap();
+	assert_eq!(result, b"abc");
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"\n");
+}
+
+#[test]
+fn delimiter_at_end_of_scope() {
+	let reader = "ab\ncd".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut scope = buffer.take_scope(3);
+	
+	let result = scope.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+	assert_eq!(scope.remaining(), 0);
+}
+
+#[test]
+fn unexpected_eof_within_scope() {
+	let reader = "ab".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let mut scope = buffer.take_scope(10);
+	
+	let error = scope.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let error = scope.read_bytes(3).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	let result = scope.read_bytes(2).unwrap();
+	assert_eq!(result, b"ab");
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use read_buffer::{DynReadBuffer, TimedRead};
+
+struct SlowReader {
+	data: Vec<u8>,
+	delay: Duration,
+}
+
+impl Read for SlowReader {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		thread::sleep(self.delay);
+		
+		if self.data.is_empty() || buf.is_empty() {
+			return Ok(0);
+		}
+		
+		buf[0] = self.data.remove(0);
+		Ok(1)
+	}
+}
+
+impl TimedRead for SlowReader {}
+
+fn connected_pair() -> (TcpStream, TcpStream) {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+	let (server, _) = listener.accept().unwrap();
+	(client, server)
+}
+
+#[test]
+fn read_within_timeout() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_bytes_timeout(3, Duration::from_secs(1)).unwrap();
+	assert_eq!(result, [1, 2, 3]);
+	
+	let error = buffer.read_bytes_timeout(2, Duration::from_secs(1)).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn deadline_between_reads() {
+	let reader = SlowReader {
+		data: b"abc\ndef".to_vec(),
+		delay: Duration::from_millis(30),
+	};
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let error = buffer.read_until_timeout(b'\n', Duration::from_millis(50)).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::TimedOut);
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"abc\n");
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires sockets")]
+fn socket_timeout() {
+	let (client, mut server) = connected_pair();
+	let mut buffer = DynReadBuffer::new(client);
+	
+	server.write_all(&[1, 2]).unwrap();
+	
+	let start = Instant::now();
+	let error = buffer.read_bytes_timeout(4, Duration::from_millis(50)).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::TimedOut);
+	assert!(start.elapsed() < Duration::from_secs(5));
+	
+	server.write_all(&[3, 4]).unwrap();
+	
+	let result = buffer.read_bytes(4).unwrap();
+	assert_eq!(result, [1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg_attr(target_family = "wasm", ignore = "requires sockets")]
+fn socket_timeout_restored() {
+	let (client, mut server) = connected_pair();
+	client.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+	let mut buffer = DynReadBuffer::new(&client);
+	
+	server.write_all(b"line\n").unwrap();
+	
+	let result = buffer.read_until_timeout(b'\n', Duration::from_millis(50)).unwrap();
+	assert_eq!(result, b"line\n");
+	
+	let error = buffer.read_until_timeout(b'\n', Duration::from_millis(50)).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::TimedOut);
+	
+	assert_eq!(client.read_timeout().unwrap(), Some(Duration::from_secs(10)));
+}
+
+#[test]
+fn buffered_data_with_zero_timeout() {
+	let reader = "ab\ncd".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.read_until(b'\n').unwrap();
+	assert_eq!(result, b"ab\n");
+	
+	let result = buffer.read_bytes_timeout(2, Duration::ZERO).unwrap();
+	assert_eq!(result, b"cd");
+	
+	let error = buffer.read_bytes_timeout(1, Duration::ZERO).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::TimedOut);
+}
\ No newline at end of file
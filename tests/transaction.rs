@@ -0,0 +1,113 @@
+use std::io::{self, ErrorKind};
+
+use read_buffer::DynReadBuffer;
+use read_buffer::test_util::ChunkedReader;
+
+fn invalid() -> io::Error {
+	ErrorKind::InvalidData.into()
+}
+
+#[test]
+fn commit() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result = buffer.transaction(|txn| Ok::<_, io::Error>(txn.read_bytes(2)?.to_vec()));
+	
+	assert_eq!(result.unwrap(), [1, 2]);
+	assert_eq!(buffer.read_bytes(2).unwrap(), [3, 4]);
+}
+
+#[test]
+fn rollback() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: Result<(), _> = buffer.transaction(|txn| {
+		txn.read_bytes(1)?;
+		txn.read_bytes(2)?;
+		Err(invalid())
+	});
+	
+	assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+	assert_eq!(buffer.read_bytes(4).unwrap(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn rollback_after_growth() {
+	let mut reader = ChunkedReader::new();
+	
+	for chunk in (0..100u8).collect::<Vec<_>>().chunks(3) {
+		reader.add_chunk(chunk.to_vec());
+	}
+	
+	let mut buffer = DynReadBuffer::with_capacity(reader, 4);
+	assert_eq!(buffer.read_bytes(2).unwrap(), [0, 1]);
+	
+	let result: Result<(), _> = buffer.transaction(|txn| {
+		for _ in 0..9 {
+			txn.read_bytes(10)?;
+		}
+		
+		Err(invalid())
+	});
+	
+	assert!(result.is_err());
+	assert_eq!(buffer.read_bytes(98).unwrap(), (2..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn alternatives() {
+	let reader = "B:42\n".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let parsed = buffer.transaction(|txn| match txn.read_bytes(2)? {
+			b"A:" => Ok('A'),
+			_ => Err(invalid()),
+		})
+		.or_else(|_| buffer.transaction(|txn| match txn.read_bytes(2)? {
+			b"B:" => Ok('B'),
+			_ => Err(invalid()),
+		}));
+	
+	assert_eq!(parsed.unwrap(), 'B');
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"42\n");
+}
+
+#[test]
+fn nested() {
+	let reader = [1, 2, 3, 4, 5, 6].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	let result: Result<(), _> = buffer.transaction(|txn| {
+		assert_eq!(txn.read_bytes(1)?, [1]);
+		
+		let inner: Result<(), _> = txn.transaction(|txn| {
+			txn.read_bytes(3)?;
+			Err(invalid())
+		});
+		assert!(inner.is_err());
+		
+		assert_eq!(txn.read_bytes(2)?, [2, 3]);
+		Err(invalid())
+	});
+	
+	assert!(result.is_err());
+	assert_eq!(buffer.read_bytes(6).unwrap(), [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn independent_of_mark() {
+	let reader = [1, 2, 3, 4].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.mark();
+	let result: Result<(), _> = buffer.transaction(|txn| {
+		txn.read_bytes(2)?;
+		txn.clear_mark();
+		Err(invalid())
+	});
+	
+	assert!(result.is_err());
+	assert_eq!(buffer.read_bytes(4).unwrap(), [1, 2, 3, 4]);
+}
\ No newline at end of file
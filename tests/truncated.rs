@@ -0,0 +1,58 @@
+use std::io::ErrorKind;
+
+use read_buffer::{DynReadBuffer, Truncated};
+
+fn truncated(error: &std::io::Error) -> Truncated {
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	*error.get_ref().unwrap().downcast_ref::<Truncated>().unwrap()
+}
+
+#[test]
+fn read_bytes() {
+	let reader = [1, 2, 3].as_slice();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.read_bytes(1).unwrap();
+	let error = buffer.read_bytes(5).unwrap_err();
+	let truncated = truncated(&error);
+	
+	assert_eq!(truncated.operation(), "read_bytes");
+	assert_eq!(truncated.requested(), Some(5));
+	assert_eq!(truncated.buffered(), 2);
+	assert_eq!(truncated.offset(), 1);
+	assert_eq!(error.to_string(), "unexpected end of file in read_bytes at offset 1: 2 of 5 bytes buffered");
+}
+
+#[test]
+fn read_until() {
+	let reader = "line\nrest".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	
+	buffer.read_until(b'\n').unwrap();
+	let error = buffer.read_until(b'\n').unwrap_err();
+	let truncated = truncated(&error);
+	
+	assert_eq!(truncated.operation(), "read_until");
+	assert_eq!(truncated.requested(), None);
+	assert_eq!(truncated.buffered(), 4);
+	assert_eq!(truncated.offset(), 5);
+}
+
+#[test]
+fn scope() {
+	let reader = "a,bc".as_bytes();
+	let mut buffer = DynReadBuffer::new(reader);
+	let mut scope = buffer.take_scope(3);
+	
+	let error = scope.read_bytes(4).unwrap_err();
+	assert_eq!(truncated(&error).operation(), "read_bytes");
+	assert_eq!(truncated(&error).requested(), Some(4));
+	
+	scope.read_until(b',').unwrap();
+	let error = scope.read_until(b',').unwrap_err();
+	let truncated = truncated(&error);
+	
+	assert_eq!(truncated.operation(), "read_until");
+	assert_eq!(truncated.requested(), None);
+	assert_eq!(truncated.offset(), 2);
+}
\ No newline at end of file
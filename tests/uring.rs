@@ -0,0 +1,27 @@
+#![cfg(all(feature = "io-uring", target_os = "linux"))]
+
+use std::fs::{self, File};
+use std::io::ErrorKind;
+
+use read_buffer::UringReadBuffer;
+
+#[test]
+fn read_file() {
+	let path = std::env::temp_dir().join(format!("read_buffer_{}_uring", std::process::id()));
+	fs::write(&path, b"first line\nsecond line\nrest").unwrap();
+	let file = File::open(&path).unwrap();
+	
+	let mut buffer = match UringReadBuffer::with_uring(file) {
+		Ok(buffer) => buffer,
+		// io_uring may be unavailable, for example inside a sandbox
+		Err(_) => return fs::remove_file(path).unwrap(),
+	};
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"first line\n");
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"second line\n");
+	
+	let error = buffer.read_until(b'\n').unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+	
+	fs::remove_file(path).unwrap();
+}
\ No newline at end of file
@@ -1,5 +0,0 @@
-mod error_reader;
-mod chunked_reader;
-
-pub use error_reader::ErrorReader;
-pub use chunked_reader::ChunkedReader;
\ No newline at end of file
@@ -0,0 +1,57 @@
+#![cfg(all(feature = "zeroize", feature = "allocator-api2"))]
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
+
+use std::alloc::Layout;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+use read_buffer::DynReadBuffer;
+
+/// Hands out zeroed memory and counts how many deallocated bytes weren't wiped.
+#[derive(Clone, Default)]
+struct CheckingAllocator {
+	leftover: Arc<AtomicUsize>,
+	deallocations: Arc<AtomicUsize>,
+}
+
+unsafe impl Allocator for CheckingAllocator {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		Global.allocate_zeroed(layout)
+	}
+	
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		let memory = std::slice::from_raw_parts(ptr.as_ptr(), layout.size());
+		let leftover = memory.iter().filter(|byte| **byte != 0).count();
+		self.leftover.fetch_add(leftover, Ordering::Relaxed);
+		self.deallocations.fetch_add(1, Ordering::Relaxed);
+		Global.deallocate(ptr, layout)
+	}
+}
+
+#[test]
+fn wipes_on_drop() {
+	let allocator = CheckingAllocator::default();
+	let reader = "secret\n".as_bytes();
+	let mut buffer = DynReadBuffer::with_capacity_in(reader, 16, allocator.clone());
+	
+	assert_eq!(buffer.read_until(b'\n').unwrap(), b"secret\n");
+	drop(buffer);
+	
+	assert!(allocator.deallocations.load(Ordering::Relaxed) >= 1);
+	assert_eq!(allocator.leftover.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn wipes_when_growing() {
+	let allocator = CheckingAllocator::default();
+	let reader = [7; 1000];
+	let mut buffer = DynReadBuffer::with_capacity_in(reader.as_slice(), 16, allocator.clone());
+	
+	assert_eq!(buffer.read_bytes(10).unwrap(), [7; 10]);
+	assert_eq!(buffer.read_bytes(990).unwrap(), [7; 990]);
+	
+	assert!(allocator.deallocations.load(Ordering::Relaxed) >= 1);
+	assert_eq!(allocator.leftover.load(Ordering::Relaxed), 0);
+}
\ No newline at end of file